@@ -9,12 +9,15 @@
 //! ```
 //!
 //! Params are SCALE-encoded:
+//! - `AssetId`: little-endian u32 (4 bytes) — first param of every instrument-scoped
+//!   `pallet-clad-token` call
 //! - `AccountId`: raw 32 bytes (no prefix — `writeAccountId` in Kotlin)
 //! - `u128 amount`: raw little-endian u128 (16 bytes, no compact prefix)
 //! - `u16 threshold`: little-endian u16
 //! - `Vec<AccountId>`: Compact<len> followed by each AccountId (32 bytes each)
 //! - `Option<Timepoint>`: 0x00 (None) or 0x01 + height(u32 LE) + index(u32 LE)
 //! - `Vec<u8> callData`: Compact<len> followed by bytes
+//! - `BoundedVec<u8, 35> reference`: Compact<len> followed by bytes
 //! - `Weight`: refTime (Compact<u64>) + proofSize (Compact<u64>)
 //!
 //! # Pallet indices
@@ -46,72 +49,137 @@ pub mod clad_token_call {
     pub const ADD_TO_WHITELIST: u8 = 4;
     pub const REMOVE_FROM_WHITELIST: u8 = 5;
     pub const SET_ADMIN: u8 = 6;
+    pub const CREATE_INSTRUMENT: u8 = 7;
+    pub const TRANSFER_WITH_REFERENCE: u8 = 47;
 }
 
-/// Build a `mint(to, amount)` call.
+/// Maximum length of a `transfer_with_reference` payment reference, in bytes.
 ///
+/// Matches `TransferReferenceOf` in `pallet-clad-token` (an ISO 20022 end-to-end id).
+pub const MAX_TRANSFER_REFERENCE_LEN: usize = 35;
+
+/// Build a `mint(asset_id, to, amount)` call.
+///
+/// `asset_id` encodes as a raw little-endian u32 (4 bytes).
 /// `to` must be exactly 32 bytes (AccountId).
 /// `amount` encodes as a raw little-endian u128 (16 bytes) — no compact prefix,
 /// because the pallet declares `amount: u128` without `#[codec(compact)]`.
-pub fn mint(to: &[u8], amount: u128) -> CallData {
+pub fn mint(asset_id: u32, to: &[u8], amount: u128) -> CallData {
     assert_eq!(to.len(), 32, "AccountId must be 32 bytes");
-    let mut out = Vec::with_capacity(2 + 32 + 16);
+    let mut out = Vec::with_capacity(2 + 4 + 32 + 16);
     out.push(CLAD_TOKEN_PALLET);
     out.push(clad_token_call::MINT);
+    out.extend_from_slice(&asset_id.to_le_bytes());
     out.extend_from_slice(to);
     out.extend_from_slice(&amount.to_le_bytes());
     out
 }
 
-/// Build a `transfer(to, amount)` call.
+/// Build a `transfer(asset_id, to, amount)` call.
 ///
 /// `amount` encodes as a raw little-endian u128 (16 bytes) — no compact prefix.
-pub fn transfer(to: &[u8], amount: u128) -> CallData {
+pub fn transfer(asset_id: u32, to: &[u8], amount: u128) -> CallData {
     assert_eq!(to.len(), 32, "AccountId must be 32 bytes");
-    let mut out = Vec::with_capacity(2 + 32 + 16);
+    let mut out = Vec::with_capacity(2 + 4 + 32 + 16);
     out.push(CLAD_TOKEN_PALLET);
     out.push(clad_token_call::TRANSFER);
+    out.extend_from_slice(&asset_id.to_le_bytes());
+    out.extend_from_slice(to);
+    out.extend_from_slice(&amount.to_le_bytes());
+    out
+}
+
+/// Build a `transfer_with_reference(asset_id, to, amount, reference)` call.
+///
+/// `amount` encodes as a raw little-endian u128 (16 bytes) — no compact prefix.
+/// `reference` encodes as a `BoundedVec<u8>`: Compact<len> followed by the bytes.
+/// It must be at most [`MAX_TRANSFER_REFERENCE_LEN`] bytes, or the runtime rejects
+/// the extrinsic.
+pub fn transfer_with_reference(
+    asset_id: u32,
+    to: &[u8],
+    amount: u128,
+    reference: &[u8],
+) -> CallData {
+    assert_eq!(to.len(), 32, "AccountId must be 32 bytes");
+    assert!(reference.len() <= MAX_TRANSFER_REFERENCE_LEN, "reference must be at most 35 bytes");
+    let mut out = Vec::with_capacity(2 + 4 + 32 + 16 + 1 + reference.len());
+    out.push(CLAD_TOKEN_PALLET);
+    out.push(clad_token_call::TRANSFER_WITH_REFERENCE);
+    out.extend_from_slice(&asset_id.to_le_bytes());
     out.extend_from_slice(to);
     out.extend_from_slice(&amount.to_le_bytes());
+    out.extend_from_slice(&compact_usize(reference.len()));
+    out.extend_from_slice(reference);
     out
 }
 
-/// Build a `freeze(account)` call.
-pub fn freeze(account: &[u8]) -> CallData {
+/// Build a `freeze(asset_id, account)` call.
+pub fn freeze(asset_id: u32, account: &[u8]) -> CallData {
     assert_eq!(account.len(), 32, "AccountId must be 32 bytes");
-    let mut out = Vec::with_capacity(2 + 32);
+    let mut out = Vec::with_capacity(2 + 4 + 32);
     out.push(CLAD_TOKEN_PALLET);
     out.push(clad_token_call::FREEZE);
+    out.extend_from_slice(&asset_id.to_le_bytes());
     out.extend_from_slice(account);
     out
 }
 
-/// Build an `unfreeze(account)` call.
-pub fn unfreeze(account: &[u8]) -> CallData {
+/// Build an `unfreeze(asset_id, account)` call.
+pub fn unfreeze(asset_id: u32, account: &[u8]) -> CallData {
     assert_eq!(account.len(), 32, "AccountId must be 32 bytes");
-    let mut out = Vec::with_capacity(2 + 32);
+    let mut out = Vec::with_capacity(2 + 4 + 32);
     out.push(CLAD_TOKEN_PALLET);
     out.push(clad_token_call::UNFREEZE);
+    out.extend_from_slice(&asset_id.to_le_bytes());
     out.extend_from_slice(account);
     out
 }
 
-/// Build an `add_to_whitelist(account)` call.
-pub fn add_to_whitelist(account: &[u8]) -> CallData {
+/// Build an `add_to_whitelist(asset_id, account, expires_at, country)` call.
+///
+/// `expires_at` is the block number at which the approval lapses. It encodes as
+/// a SCALE `Option<u32>`: `0x00` for `None`, or `0x01` followed by the raw
+/// little-endian u32 (4 bytes).
+///
+/// `country` is the investor's ISO 3166-1 numeric country code, encoded as a
+/// SCALE `Option<u16>` (`0x01` followed by the raw little-endian u16).
+pub fn add_to_whitelist(
+    asset_id: u32,
+    account: &[u8],
+    expires_at: Option<u32>,
+    country: Option<u16>,
+) -> CallData {
     assert_eq!(account.len(), 32, "AccountId must be 32 bytes");
-    let mut out = Vec::with_capacity(2 + 32);
+    let mut out = Vec::with_capacity(2 + 4 + 32 + 5 + 3);
     out.push(CLAD_TOKEN_PALLET);
     out.push(clad_token_call::ADD_TO_WHITELIST);
+    out.extend_from_slice(&asset_id.to_le_bytes());
     out.extend_from_slice(account);
+    match expires_at {
+        None => out.push(0x00),
+        Some(block) => {
+            out.push(0x01);
+            out.extend_from_slice(&block.to_le_bytes());
+        }
+    }
+    match country {
+        None => out.push(0x00),
+        Some(code) => {
+            out.push(0x01);
+            out.extend_from_slice(&code.to_le_bytes());
+        }
+    }
     out
 }
 
-/// Build a `remove_from_whitelist(account)` call.
-pub fn remove_from_whitelist(account: &[u8]) -> CallData {
+/// Build a `remove_from_whitelist(asset_id, account)` call.
+pub fn remove_from_whitelist(asset_id: u32, account: &[u8]) -> CallData {
     assert_eq!(account.len(), 32, "AccountId must be 32 bytes");
-    let mut out = Vec::with_capacity(2 + 32);
+    let mut out = Vec::with_capacity(2 + 4 + 32);
     out.push(CLAD_TOKEN_PALLET);
     out.push(clad_token_call::REMOVE_FROM_WHITELIST);
+    out.extend_from_slice(&asset_id.to_le_bytes());
     out.extend_from_slice(account);
     out
 }
@@ -10,7 +10,7 @@
 //! 1. `subxt-core 0.38` depends on `subxt-metadata 0.38`, which in turn depends on
 //!    `scale-decode`, `scale-encode`, and `frame-metadata` with transitive `std`-only
 //!    paths that make reliable `no_std + alloc` compilation non-trivial without forking.
-//! 2. `pallet-clad-token` call indices 0–6 for pallet 8 are manually audited constants
+//! 2. `pallet-clad-token` call indices 0–7 for pallet 8 are manually audited constants
 //!    that are stable through all restructure phases.  Hand-rolled encoding with
 //!    test-time cross-check is sufficient through Phase 3.
 //! 3. `crates/server` (Phase 5) uses full `subxt` where `std` is fine; no `no_std`
@@ -23,8 +23,8 @@ use crate::crypto::CryptoError;
 use alloc::vec::Vec;
 
 use super::call::{
-    add_to_whitelist, freeze, mint, remove_from_whitelist, set_admin, transfer, unfreeze, CallData,
-    CLAD_TOKEN_PALLET,
+    add_to_whitelist, freeze, mint, remove_from_whitelist, set_admin, transfer,
+    transfer_with_reference, unfreeze, CallData, CLAD_TOKEN_PALLET, MAX_TRANSFER_REFERENCE_LEN,
 };
 
 /// Known pallet names and their fixed indices in the Clad runtime.
@@ -44,17 +44,22 @@ pub const KNOWN_PALLETS: &[(&str, u8)] = &[("CladToken", CLAD_TOKEN_PALLET), ("M
 /// The `args` slice must contain SCALE-pre-encoded arguments in the order
 /// expected by the call. Specifically:
 ///
-/// | call            | args[0]              | args[1] (optional) |
-/// |-----------------|----------------------|--------------------|
-/// | `mint`          | AccountId (32 bytes) | raw LE u128 (16 bytes) |
-/// | `transfer`      | AccountId (32 bytes) | raw LE u128 (16 bytes) |
-/// | `freeze`        | AccountId (32 bytes) | — |
-/// | `unfreeze`      | AccountId (32 bytes) | — |
-/// | `add_to_whitelist`    | AccountId (32 bytes) | — |
-/// | `remove_from_whitelist` | AccountId (32 bytes) | — |
-/// | `set_admin`     | AccountId (32 bytes) | — |
+/// | call            | args[0]              | args[1]              | args[2] | args[3] |
+/// |-----------------|----------------------|----------------------|---------|---------|
+/// | `mint`          | AssetId (LE u32, 4 bytes) | AccountId (32 bytes) | raw LE u128 (16 bytes) | — |
+/// | `transfer`      | AssetId (LE u32, 4 bytes) | AccountId (32 bytes) | raw LE u128 (16 bytes) | — |
+/// | `freeze`        | AssetId (LE u32, 4 bytes) | AccountId (32 bytes) | — | — |
+/// | `unfreeze`      | AssetId (LE u32, 4 bytes) | AccountId (32 bytes) | — | — |
+/// | `add_to_whitelist`    | AssetId (LE u32, 4 bytes) | AccountId (32 bytes) | optional expiry block (LE u32, 4 bytes) | optional country code (LE u16, 2 bytes) |
+/// | `remove_from_whitelist` | AssetId (LE u32, 4 bytes) | AccountId (32 bytes) | — | — |
+/// | `set_admin`     | AccountId (32 bytes) | — | — | — |
+/// | `transfer_with_reference` | AssetId (LE u32, 4 bytes) | AccountId (32 bytes) | raw LE u128 (16 bytes) | payment reference (raw bytes, max 35) |
 ///
-/// For `mint` and `transfer`, `args[1]` is a raw little-endian u128 (16 bytes).
+/// For `mint`, `transfer` and `transfer_with_reference`, `args[2]` is a raw
+/// little-endian u128 (16 bytes). The `transfer_with_reference` reference is passed
+/// unprefixed; the builder adds the length prefix.
+/// For `add_to_whitelist`, omitting `args[2]` (or passing it empty) whitelists
+/// without expiry, and omitting `args[3]` (or passing it empty) records no country.
 pub fn build_call_data(
     pallet_name: &str,
     call_name: &str,
@@ -69,8 +74,9 @@ pub fn build_call_data(
 fn build_clad_token_call(call_name: &str, args: &[Vec<u8>]) -> Result<CallData, CryptoError> {
     match call_name {
         "mint" | "transfer" => {
-            let account = args.first().ok_or(CryptoError::UnknownCall)?;
-            let amount_bytes = args.get(1).ok_or(CryptoError::UnknownCall)?;
+            let asset_id = asset_id_arg(args)?;
+            let account = args.get(1).ok_or(CryptoError::UnknownCall)?;
+            let amount_bytes = args.get(2).ok_or(CryptoError::UnknownCall)?;
             if amount_bytes.len() != 16 {
                 return Err(CryptoError::UnknownCall);
             }
@@ -78,35 +84,70 @@ fn build_clad_token_call(call_name: &str, args: &[Vec<u8>]) -> Result<CallData,
                 amount_bytes.as_slice().try_into().map_err(|_| CryptoError::UnknownCall)?,
             );
             if call_name == "mint" {
-                Ok(mint(account, amount))
+                Ok(mint(asset_id, account, amount))
             } else {
-                Ok(transfer(account, amount))
+                Ok(transfer(asset_id, account, amount))
             }
         }
         "freeze" => {
-            let account = args.first().ok_or(CryptoError::UnknownCall)?;
-            Ok(freeze(account))
+            let asset_id = asset_id_arg(args)?;
+            let account = args.get(1).ok_or(CryptoError::UnknownCall)?;
+            Ok(freeze(asset_id, account))
         }
         "unfreeze" => {
-            let account = args.first().ok_or(CryptoError::UnknownCall)?;
-            Ok(unfreeze(account))
+            let asset_id = asset_id_arg(args)?;
+            let account = args.get(1).ok_or(CryptoError::UnknownCall)?;
+            Ok(unfreeze(asset_id, account))
         }
         "add_to_whitelist" => {
-            let account = args.first().ok_or(CryptoError::UnknownCall)?;
-            Ok(add_to_whitelist(account))
+            let asset_id = asset_id_arg(args)?;
+            let account = args.get(1).ok_or(CryptoError::UnknownCall)?;
+            let expires_at = args
+                .get(2)
+                .filter(|bytes| !bytes.is_empty())
+                .map(|bytes| bytes.as_slice().try_into().map(u32::from_le_bytes))
+                .transpose()
+                .map_err(|_| CryptoError::UnknownCall)?;
+            let country = args
+                .get(3)
+                .filter(|bytes| !bytes.is_empty())
+                .map(|bytes| bytes.as_slice().try_into().map(u16::from_le_bytes))
+                .transpose()
+                .map_err(|_| CryptoError::UnknownCall)?;
+            Ok(add_to_whitelist(asset_id, account, expires_at, country))
         }
         "remove_from_whitelist" => {
-            let account = args.first().ok_or(CryptoError::UnknownCall)?;
-            Ok(remove_from_whitelist(account))
+            let asset_id = asset_id_arg(args)?;
+            let account = args.get(1).ok_or(CryptoError::UnknownCall)?;
+            Ok(remove_from_whitelist(asset_id, account))
         }
         "set_admin" => {
             let account = args.first().ok_or(CryptoError::UnknownCall)?;
             Ok(set_admin(account))
         }
+        "transfer_with_reference" => {
+            let asset_id = asset_id_arg(args)?;
+            let account = args.get(1).ok_or(CryptoError::UnknownCall)?;
+            let amount_bytes = args.get(2).ok_or(CryptoError::UnknownCall)?;
+            let amount = u128::from_le_bytes(
+                amount_bytes.as_slice().try_into().map_err(|_| CryptoError::UnknownCall)?,
+            );
+            let reference = args.get(3).ok_or(CryptoError::UnknownCall)?;
+            if reference.len() > MAX_TRANSFER_REFERENCE_LEN {
+                return Err(CryptoError::UnknownCall);
+            }
+            Ok(transfer_with_reference(asset_id, account, amount, reference))
+        }
         _ => Err(CryptoError::UnknownCall),
     }
 }
 
+/// Decode `args[0]` as a raw little-endian u32 `AssetId` (4 bytes).
+fn asset_id_arg(args: &[Vec<u8>]) -> Result<u32, CryptoError> {
+    let bytes = args.first().ok_or(CryptoError::UnknownCall)?;
+    Ok(u32::from_le_bytes(bytes.as_slice().try_into().map_err(|_| CryptoError::UnknownCall)?))
+}
+
 /// Validate that the hardcoded pallet/call indices match the expected values.
 ///
 /// This is a compile-time / unit-test cross-check.  Once subxt-core lands, this
@@ -123,6 +164,8 @@ pub fn validate_known_call_indices(pallet_name: &str, call_name: &str) -> Option
         ("CladToken", "add_to_whitelist") => 4,
         ("CladToken", "remove_from_whitelist") => 5,
         ("CladToken", "set_admin") => 6,
+        ("CladToken", "create_instrument") => 7,
+        ("CladToken", "transfer_with_reference") => 47,
         _ => return None,
     };
 
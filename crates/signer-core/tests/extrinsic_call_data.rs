@@ -6,6 +6,9 @@
 //! The corpus was verified against the metadata_v14.scale corpus (Phase 2b): all
 //! pallet-clad-token parameters are `AccountId32` (raw 32 bytes), not `MultiAddress`.
 //! Amount parameters are raw little-endian u128 (16 bytes), not SCALE Compact.
+//! Instrument-scoped calls carry a leading `AssetId` as raw little-endian u32 (4 bytes).
+//! `add_to_whitelist` ends with a SCALE `Option<u32>` expiry block and a SCALE
+//! `Option<u16>` country code.
 
 use signer_core::extrinsic::{call, metadata};
 
@@ -31,13 +34,28 @@ fn call_data_corpus() {
         let account = hex::decode(account_hex).expect("bad account_hex");
         assert_eq!(account.len(), 32, "AccountId must be 32 bytes");
 
-        let args: Vec<Vec<u8>> = if let Some(amount) = args_v["amount"].as_u64() {
+        let mut args: Vec<Vec<u8>> = Vec::new();
+        if let Some(asset_id) = args_v["asset_id"].as_u64() {
+            // Pack asset_id as raw LE u32 (4 bytes) as expected by metadata::build_call_data.
+            args.push((asset_id as u32).to_le_bytes().to_vec());
+        }
+        args.push(account);
+        let country = args_v["country"].as_u64();
+        if let Some(expires_at) = args_v["expires_at"].as_u64() {
+            // Pack the whitelist expiry block as raw LE u32 (4 bytes).
+            args.push((expires_at as u32).to_le_bytes().to_vec());
+        } else if country.is_some() {
+            // Empty placeholder: no expiry
+            args.push(Vec::new());
+        }
+        if let Some(country) = country {
+            // Pack the country code as raw LE u16 (2 bytes).
+            args.push((country as u16).to_le_bytes().to_vec());
+        }
+        if let Some(amount) = args_v["amount"].as_u64() {
             // Pack amount as raw LE u128 (16 bytes) as expected by metadata::build_call_data.
-            let amount_bytes = (amount as u128).to_le_bytes().to_vec();
-            vec![account, amount_bytes]
-        } else {
-            vec![account]
-        };
+            args.push((amount as u128).to_le_bytes().to_vec());
+        }
 
         let got = metadata::build_call_data("CladToken", call_name, &args)
             .unwrap_or_else(|e| panic!("build_call_data({call_name}) failed: {e:?}"));
@@ -51,10 +69,10 @@ fn call_data_corpus() {
 fn mint_builder_matches_corpus_vector() {
     let alice =
         hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d").unwrap();
-    let got = call::mint(&alice, 1);
+    let got = call::mint(0, &alice, 1);
     assert_eq!(
         hex::encode(&got),
-        "0700d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d01000000000000000000000000000000"
+        "070000000000d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d01000000000000000000000000000000"
     );
 }
 
@@ -62,10 +80,21 @@ fn mint_builder_matches_corpus_vector() {
 fn freeze_builder_matches_corpus_vector() {
     let alice =
         hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d").unwrap();
-    let got = call::freeze(&alice);
+    let got = call::freeze(0, &alice);
     assert_eq!(
         hex::encode(&got),
-        "0702d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+        "070200000000d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+    );
+}
+
+#[test]
+fn transfer_with_reference_builder_appends_length_prefixed_reference() {
+    let alice =
+        hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d").unwrap();
+    let got = call::transfer_with_reference(0, &alice, 1, b"E2E-1");
+    assert_eq!(
+        hex::encode(&got),
+        "072f00000000d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d01000000000000000000000000000000144532452d31"
     );
 }
 
@@ -34,6 +34,8 @@ fn clad_token_call_indices_are_correct() {
         ("add_to_whitelist", 4),
         ("remove_from_whitelist", 5),
         ("set_admin", 6),
+        ("create_instrument", 7),
+        ("transfer_with_reference", 47),
     ];
     for (call, expected_call_idx) in &cases {
         let (pallet_idx, call_idx) =
@@ -72,8 +74,33 @@ fn build_call_data_unknown_call_returns_error() {
 
 #[test]
 fn build_call_data_mint_missing_amount_returns_error() {
+    let asset_id = 0u32.to_le_bytes().to_vec();
     let account = vec![0u8; 32];
-    // Only 1 arg (account); mint requires amount as args[1].
-    let result = build_call_data("CladToken", "mint", &[account]);
+    // Only 2 args (asset_id, account); mint requires amount as args[2].
+    let result = build_call_data("CladToken", "mint", &[asset_id, account]);
     assert!(result.is_err(), "mint with missing amount must return Err");
 }
+
+#[test]
+fn build_call_data_freeze_bad_asset_id_returns_error() {
+    // AssetId must be exactly 4 bytes (LE u32).
+    let asset_id = vec![0u8; 2];
+    let account = vec![0u8; 32];
+    let result = build_call_data("CladToken", "freeze", &[asset_id, account]);
+    assert!(result.is_err(), "freeze with malformed asset_id must return Err");
+}
+
+#[test]
+fn build_call_data_transfer_with_reference_too_long_returns_error() {
+    let asset_id = 0u32.to_le_bytes().to_vec();
+    let account = vec![0u8; 32];
+    let amount = 1u128.to_le_bytes().to_vec();
+    // Payment references are bounded to 35 bytes (ISO 20022 end-to-end id).
+    let reference = vec![b'r'; 36];
+    let result = build_call_data(
+        "CladToken",
+        "transfer_with_reference",
+        &[asset_id, account, amount, reference],
+    );
+    assert!(result.is_err(), "transfer_with_reference with a 36-byte reference must return Err");
+}
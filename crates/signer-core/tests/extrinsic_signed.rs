@@ -34,7 +34,7 @@ fn signed_extrinsic_roundtrip_against_node() {
     let bob = common::bob_account_id();
 
     // Build a CladToken::transfer call: Alice → Bob, amount = 1.
-    let call_data = call::transfer(&bob, 1);
+    let call_data = call::transfer(0, &bob, 1);
 
     // Fetch Alice's current on-chain nonce.
     let nonce =
@@ -16,7 +16,7 @@ fn build_signing_payload_immortal_smoke() {
         0xd6, 0x82, 0x2c, 0x85, 0x58, 0x85, 0x4c, 0xcd, 0xe3, 0x9a, 0x56, 0x84, 0xe7, 0xa5, 0x6d,
         0xa2, 0x7d,
     ];
-    let call_data = call::transfer(&alice, 1);
+    let call_data = call::transfer(0, &alice, 1);
 
     let extra = signed_extensions::SignedExtra { era_period: 0, era_phase: 0, nonce: 0, tip: 0 };
     let chain = signed_extensions::ChainInfo {
@@ -54,7 +54,7 @@ fn build_signing_payload_large_payload_is_hashed() {
     ];
 
     // Start from a normal transfer call and append padding to exceed 256 bytes.
-    let mut call_data = call::transfer(&alice, 1);
+    let mut call_data = call::transfer(0, &alice, 1);
     call_data.extend(vec![0xffu8; 220]); // total call_data ≈ 256 bytes alone
 
     let extra = signed_extensions::SignedExtra { era_period: 0, era_phase: 0, nonce: 0, tip: 0 };
@@ -30,6 +30,8 @@ where
 ///
 /// Two validators (Alice + Bob) for realistic consensus testing.
 /// Admin is a 2-of-3 multi-sig (Alice, Bob, Charlie) - no sudo, no bypasses.
+/// Dave is the runtime's audit office and can veto large mints; it is not an admin signatory.
+/// Ferdie is the runtime's issuance authority and can raise supply caps; it is not one either.
 ///
 /// See ADR-004: docs/adr/004-production-runtime-configuration.md
 pub fn development_config() -> Result<ChainSpec, String> {
@@ -102,6 +104,7 @@ fn testnet_genesis(
             "decimals": 6u8,
             "whitelistedAccounts": endowed_accounts,
             "initialBalances": [],
+            "maxSupply": null,
         },
     })
 }
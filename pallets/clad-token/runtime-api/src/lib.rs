@@ -0,0 +1,52 @@
+//! Runtime API for pallet-clad-token.
+//!
+//! Exposes token state that clients would otherwise have to derive themselves,
+//! either by scanning whole storage maps or by re-implementing pallet logic.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // Holders of the genesis bond (JavaScript)
+//! const holders = await api.call.cladTokenApi.holderCount(0);
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+pub use pallet_clad_token::{AssetId, CountryCode, Document, DocumentNameOf, SnapshotId};
+
+sp_api::decl_runtime_apis! {
+    /// Queries over pallet-clad-token state.
+    pub trait CladTokenApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Number of accounts holding a non-zero balance of `asset_id`.
+        fn holder_count(asset_id: AssetId) -> u32;
+
+        /// Number of holders of `asset_id` resident in `country`.
+        fn holders_per_country(asset_id: AssetId, country: CountryCode) -> u32;
+
+        /// Balance of `who` split as `(locked, unlocked)` by vesting schedules at
+        /// the current block.
+        ///
+        /// The unlocked part may still be partially frozen.
+        fn vesting_balance(asset_id: AssetId, who: AccountId) -> (u128, u128);
+
+        /// Balance of `who` at snapshot `snapshot_id` of `asset_id`, or `None` if
+        /// that snapshot has not been taken.
+        fn balance_at(asset_id: AssetId, who: AccountId, snapshot_id: SnapshotId) -> Option<u128>;
+
+        /// Total supply of `asset_id` at snapshot `snapshot_id`, or `None` if that
+        /// snapshot has not been taken.
+        fn total_supply_at(asset_id: AssetId, snapshot_id: SnapshotId) -> Option<u128>;
+
+        /// Every legal document of `asset_id` with its name (ERC-1643
+        /// `getAllDocuments`).
+        fn documents(asset_id: AssetId) -> Vec<(DocumentNameOf, Document<BlockNumber>)>;
+    }
+}
@@ -7,87 +7,255 @@ use crate::Pallet as CladToken;
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
 
+/// Register `count` instruments directly in storage and return the last id.
+fn create_instruments<T: Config>(count: u32) -> AssetId {
+    NextAssetId::<T>::put(count);
+    count.saturating_sub(1)
+}
+
+/// The full set of `MaxClaimTopics` claim topics.
+fn max_topics<T: Config>() -> ClaimTopicsOf<T> {
+    (0..T::MaxClaimTopics::get()).collect::<Vec<_>>().try_into().expect("fits the bound")
+}
+
+/// Worst-case identity setup: `asset_id` requires every topic and each holder has
+/// a valid claim for all of them.
+fn require_claims<T: Config>(asset_id: AssetId, holders: &[&T::AccountId]) {
+    let issuer: T::AccountId = account("issuer", 0, 0);
+    let topics = max_topics::<T>();
+    TrustedIssuers::<T>::insert(&issuer, &topics);
+    RequiredClaimTopics::<T>::insert(asset_id, &topics);
+    let expires_at = frame_system::Pallet::<T>::block_number() + 1_000u32.into();
+    for holder in holders {
+        for topic in topics.iter() {
+            Claims::<T>::insert(*holder, topic, Claim { issuer: issuer.clone(), expires_at });
+        }
+    }
+}
+
+/// Worst-case jurisdiction setup: both country lists are full and `country` is the
+/// last entry of the allow list. Returns `country`.
+fn restrict_countries<T: Config>(asset_id: AssetId) -> CountryCode {
+    let max = T::MaxCountries::get() as CountryCode;
+    let allowed: CountriesOf<T> =
+        (1_000..1_000 + max).collect::<Vec<_>>().try_into().expect("fits the bound");
+    let blocked: CountriesOf<T> =
+        (2_000..2_000 + max).collect::<Vec<_>>().try_into().expect("fits the bound");
+    AllowedCountries::<T>::insert(asset_id, allowed);
+    BlockedCountries::<T>::insert(asset_id, blocked);
+    1_000 + max.saturating_sub(1)
+}
+
+/// Give `who` `count` mid-schedule vesting locks of `amount` each, so that every
+/// lock takes the linear-release path when evaluated.
+fn add_vesting_locks<T: Config>(asset_id: AssetId, who: &T::AccountId, count: u32, amount: u128) {
+    let schedule =
+        VestingSchedule { start: 0u32.into(), cliff: 0u32.into(), end: 1_000_000u32.into() };
+    let locks: VestingLocksOf<T> = (0..count)
+        .map(|_| VestingLock { amount, schedule })
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("fits the bound");
+    Vesting::<T>::insert(asset_id, who, locks);
+}
+
+/// Bond terms with a full coupon schedule whose first coupon is due at block 1.
+/// Each token earns one unit of native currency per coupon.
+fn bond_terms<T: Config>() -> BondTermsOf<T> {
+    let dates = T::MaxCouponDates::get();
+    BondTerms {
+        face_value: 1,
+        coupon_rate: Perbill::one(),
+        coupon_dates: (1..=dates)
+            .map(|i| i.into())
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("fits the bound"),
+        maturity: dates.max(1).into(),
+    }
+}
+
+/// A dividend of `amount` against snapshot 1 of `asset_id`, funded in its pot and
+/// claimable until block 1000.
+fn declare_dividend<T: Config>(asset_id: AssetId, amount: u128) -> DividendOf<T> {
+    let issuer: T::AccountId = account("issuer", 0, 0);
+    let pot = Pallet::<T>::dividend_pot(asset_id, 0);
+    let funds = amount.unique_saturated_into();
+    T::Currency::set_balance(&pot, T::Currency::minimum_balance().saturating_add(funds));
+    CurrentSnapshotId::<T>::insert(asset_id, 1);
+    NextDividendId::<T>::insert(asset_id, 1);
+    let dividend = Dividend {
+        issuer,
+        snapshot_id: 1,
+        amount,
+        supply: amount,
+        deadline: 1_000u32.into(),
+        paid: 0,
+        escrowed: 0,
+        last_holder: None,
+        distributed: false,
+        reclaimed: false,
+    };
+    Dividends::<T>::insert(asset_id, 0, &dividend);
+    dividend
+}
+
+/// `n` holders of 1,000 tokens each with a country, a partial freeze and full
+/// vesting locks, plus a pending snapshot: the worst case for rescaling. A holder
+/// costs more to rescale than a holding-limit override, so none are set.
+fn split_holders<T: Config>(asset_id: AssetId, n: u32) {
+    for i in 0..n {
+        let holder: T::AccountId = account("holder", i, 0);
+        Balances::<T>::insert(asset_id, &holder, 1_000);
+        FrozenAmount::<T>::insert(asset_id, &holder, 100);
+        InvestorCountry::<T>::insert(asset_id, &holder, 398);
+        add_vesting_locks::<T>(asset_id, &holder, T::MaxVestingSchedules::get(), 100);
+    }
+    HolderCount::<T>::insert(asset_id, n);
+    HoldersPerCountry::<T>::insert(asset_id, 398, n);
+    TotalSupply::<T>::insert(asset_id, 1_000u128.saturating_mul(n.into()));
+    CurrentSnapshotId::<T>::insert(asset_id, 1);
+}
+
+/// Worst-case transfer setup: `caller` holds `amount` and moving all of it to
+/// `recipient` runs every compliance check.
+fn setup_transfer<T: Config>(
+    asset_id: AssetId,
+    caller: &T::AccountId,
+    recipient: &T::AccountId,
+    amount: u128,
+) {
+    // Whitelist both accounts and give caller balance
+    Whitelist::<T>::insert(asset_id, caller, WhitelistEntry::permanent());
+    Whitelist::<T>::insert(asset_id, recipient, WhitelistEntry::permanent());
+    Balances::<T>::insert(asset_id, caller, amount);
+    require_claims::<T>(asset_id, &[caller, recipient]);
+
+    // Both holder counts change and both country lists are scanned
+    let country = restrict_countries::<T>(asset_id);
+    InvestorCountry::<T>::insert(asset_id, caller, country);
+    InvestorCountry::<T>::insert(asset_id, recipient, country);
+    HoldersPerCountry::<T>::insert(asset_id, country, 1);
+    HolderCount::<T>::insert(asset_id, 1);
+    MaxHolders::<T>::insert(asset_id, u32::MAX);
+    TotalSupply::<T>::insert(asset_id, amount);
+    // Empty locks: evaluated in full but leave the whole balance spendable
+    add_vesting_locks::<T>(asset_id, caller, T::MaxVestingSchedules::get(), 0);
+    MaxBalancePerHolder::<T>::insert(asset_id, HoldingLimit::ShareOfSupply(Perbill::one()));
+    CurrentSnapshotId::<T>::insert(asset_id, 1);
+}
+
+/// A signed origin holding `role`.
+fn role_origin<T: Config>(role: Role) -> T::RuntimeOrigin {
+    let holder: T::AccountId = account("role holder", 0, 0);
+    Roles::<T>::insert(role, &holder, true);
+    RawOrigin::Signed(holder).into()
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
     fn mint() {
+        let asset_id = create_instruments::<T>(1);
         let recipient: T::AccountId = account("recipient", 0, 0);
         let amount: u128 = 1_000_000;
-        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+        let origin = role_origin::<T>(Role::Minter);
+
+        // Worst case: a capped instrument gaining a holder, with a holding limit
+        MaxHolders::<T>::insert(asset_id, u32::MAX);
+        MaxSupply::<T>::insert(asset_id, u128::MAX);
+        MaxBalancePerHolder::<T>::insert(asset_id, HoldingLimit::ShareOfSupply(Perbill::one()));
+        // and a pending snapshot, so the previous balance is recorded
+        CurrentSnapshotId::<T>::insert(asset_id, 1);
 
         #[extrinsic_call]
-        _(origin as T::RuntimeOrigin, recipient.clone(), amount);
+        _(origin as T::RuntimeOrigin, asset_id, recipient.clone(), amount);
 
-        assert_eq!(Balances::<T>::get(&recipient), amount);
+        assert_eq!(Balances::<T>::get(asset_id, &recipient), amount);
     }
 
     #[benchmark]
     fn transfer() {
+        let asset_id = create_instruments::<T>(1);
         let caller: T::AccountId = whitelisted_caller();
         let recipient: T::AccountId = account("recipient", 0, 0);
         let amount: u128 = 1_000_000;
 
-        // Setup: whitelist both accounts and give caller balance
-        Whitelist::<T>::insert(&caller, true);
-        Whitelist::<T>::insert(&recipient, true);
-        Balances::<T>::insert(&caller, 10_000_000);
+        setup_transfer::<T>(asset_id, &caller, &recipient, amount);
 
         #[extrinsic_call]
-        _(RawOrigin::Signed(caller.clone()), recipient.clone(), amount);
+        _(RawOrigin::Signed(caller.clone()), asset_id, recipient.clone(), amount);
 
-        assert_eq!(Balances::<T>::get(&recipient), amount);
+        assert_eq!(Balances::<T>::get(asset_id, &recipient), amount);
     }
 
     #[benchmark]
     fn freeze() {
+        let asset_id = create_instruments::<T>(1);
         let account: T::AccountId = whitelisted_caller();
-        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+        let origin = role_origin::<T>(Role::Freezer);
 
         #[extrinsic_call]
-        _(origin as T::RuntimeOrigin, account.clone());
+        _(origin as T::RuntimeOrigin, asset_id, account.clone());
 
-        assert_eq!(Frozen::<T>::get(&account), true);
+        assert_eq!(Frozen::<T>::get(asset_id, &account), true);
     }
 
     #[benchmark]
     fn unfreeze() {
+        let asset_id = create_instruments::<T>(1);
         let account: T::AccountId = whitelisted_caller();
-        Frozen::<T>::insert(&account, true);
-        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+        Frozen::<T>::insert(asset_id, &account, true);
+        let origin = role_origin::<T>(Role::Freezer);
 
         #[extrinsic_call]
-        _(origin as T::RuntimeOrigin, account.clone());
+        _(origin as T::RuntimeOrigin, asset_id, account.clone());
 
-        assert_eq!(Frozen::<T>::get(&account), false);
+        assert_eq!(Frozen::<T>::get(asset_id, &account), false);
     }
 
     #[benchmark]
     fn add_to_whitelist() {
+        let asset_id = create_instruments::<T>(1);
         let account: T::AccountId = whitelisted_caller();
-        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+        let origin = role_origin::<T>(Role::ComplianceAgent);
+
+        let expires_at = frame_system::Pallet::<T>::block_number() + 1_000u32.into();
+
+        // Worst case: a holder moving between jurisdictions
+        Balances::<T>::insert(asset_id, &account, 1_000_000);
+        InvestorCountry::<T>::insert(asset_id, &account, 398);
+        HoldersPerCountry::<T>::insert(asset_id, 398, 1);
 
         #[extrinsic_call]
-        _(origin as T::RuntimeOrigin, account.clone());
+        _(origin as T::RuntimeOrigin, asset_id, account.clone(), Some(expires_at), Some(458));
 
-        assert_eq!(Whitelist::<T>::get(&account), true);
+        assert_eq!(
+            Whitelist::<T>::get(asset_id, &account),
+            Some(WhitelistEntry { expires_at: Some(expires_at) })
+        );
+        assert_eq!(HoldersPerCountry::<T>::get(asset_id, 458), 1);
     }
 
     #[benchmark]
     fn remove_from_whitelist() {
+        let asset_id = create_instruments::<T>(1);
         let account: T::AccountId = whitelisted_caller();
-        Whitelist::<T>::insert(&account, true);
-        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+        Whitelist::<T>::insert(asset_id, &account, WhitelistEntry::permanent());
+        let origin = role_origin::<T>(Role::ComplianceAgent);
 
         #[extrinsic_call]
-        _(origin as T::RuntimeOrigin, account.clone());
+        _(origin as T::RuntimeOrigin, asset_id, account.clone());
 
-        assert_eq!(Whitelist::<T>::get(&account), false);
+        assert!(!Whitelist::<T>::contains_key(asset_id, &account));
     }
 
     #[benchmark]
-    fn set_admin() {
+    fn set_admin(n: Linear<0, { T::MaxInstruments::get() }>) {
+        // Worst case: the new admin is whitelisted on `n` instruments
+        create_instruments::<T>(n);
         let new_admin: T::AccountId = account("new_admin", 0, 0);
         let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
 
@@ -96,8 +264,854 @@ mod benchmarks {
 
         // Verify admin was set in storage
         assert_eq!(Admin::<T>::get(), Some(new_admin.clone()));
-        // Verify new admin was auto-whitelisted
-        assert_eq!(Whitelist::<T>::get(&new_admin), true);
+        // Verify new admin was auto-whitelisted on every instrument
+        for asset_id in 0..n {
+            assert!(Whitelist::<T>::contains_key(asset_id, &new_admin));
+        }
+    }
+
+    #[benchmark]
+    fn create_instrument() {
+        // Worst case: an admin is set and gets auto-whitelisted
+        let admin: T::AccountId = account("admin", 0, 0);
+        Admin::<T>::put(&admin);
+        let asset_id = NextAssetId::<T>::get();
+        let name: TokenNameOf = sp_std::vec![b'n'; 64].try_into().expect("64 bytes fit");
+        let symbol: TokenSymbolOf = sp_std::vec![b's'; 16].try_into().expect("16 bytes fit");
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, name, symbol, 6, Some(u128::MAX));
+
+        assert_eq!(NextAssetId::<T>::get(), asset_id + 1);
+        assert_eq!(MaxSupply::<T>::get(asset_id), Some(u128::MAX));
+        assert!(Whitelist::<T>::contains_key(asset_id, &admin));
+    }
+
+    #[benchmark]
+    fn burn() {
+        let asset_id = create_instruments::<T>(1);
+        let holder: T::AccountId = account("holder", 0, 0);
+        Whitelist::<T>::insert(asset_id, &holder, WhitelistEntry::permanent());
+        Balances::<T>::insert(asset_id, &holder, 10_000_000);
+        TotalSupply::<T>::insert(asset_id, 10_000_000);
+        require_claims::<T>(asset_id, &[&holder]);
+        add_vesting_locks::<T>(asset_id, &holder, T::MaxVestingSchedules::get(), 1);
+        let origin = role_origin::<T>(Role::Minter);
+        CurrentSnapshotId::<T>::insert(asset_id, 1);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, holder.clone(), 1_000_000);
+
+        assert_eq!(Balances::<T>::get(asset_id, &holder), 9_000_000);
+        assert_eq!(TotalSupply::<T>::get(asset_id), 9_000_000);
+    }
+
+    #[benchmark]
+    fn redeem() {
+        let asset_id = create_instruments::<T>(1);
+        let caller: T::AccountId = whitelisted_caller();
+        Whitelist::<T>::insert(asset_id, &caller, WhitelistEntry::permanent());
+        Balances::<T>::insert(asset_id, &caller, 10_000_000);
+        TotalSupply::<T>::insert(asset_id, 10_000_000);
+        require_claims::<T>(asset_id, &[&caller]);
+        add_vesting_locks::<T>(asset_id, &caller, T::MaxVestingSchedules::get(), 1);
+        CurrentSnapshotId::<T>::insert(asset_id, 1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), asset_id, 1_000_000);
+
+        assert_eq!(Balances::<T>::get(asset_id, &caller), 9_000_000);
+        assert_eq!(TotalSupply::<T>::get(asset_id), 9_000_000);
+    }
+
+    #[benchmark]
+    fn forced_transfer() {
+        let asset_id = create_instruments::<T>(1);
+        let from: T::AccountId = account("from", 0, 0);
+        let to: T::AccountId = account("to", 0, 0);
+        // Worst case: sender is frozen, receiver already holds tokens,
+        // and the transfer dips into partially frozen tokens
+        Frozen::<T>::insert(asset_id, &from, true);
+        FrozenAmount::<T>::insert(asset_id, &from, 10_000_000);
+        Whitelist::<T>::insert(asset_id, &to, WhitelistEntry::permanent());
+        Balances::<T>::insert(asset_id, &from, 10_000_000);
+        Balances::<T>::insert(asset_id, &to, 1);
+        require_claims::<T>(asset_id, &[&to]);
+        // Every receiver check is evaluated in full
+        let country = restrict_countries::<T>(asset_id);
+        InvestorCountry::<T>::insert(asset_id, &to, country);
+        MaxHolders::<T>::insert(asset_id, u32::MAX);
+        TotalSupply::<T>::insert(asset_id, 10_000_001);
+        MaxBalancePerHolder::<T>::insert(asset_id, HoldingLimit::ShareOfSupply(Perbill::one()));
+        let reason = ForcedTransferReason {
+            code: ForcedTransferCode::CourtOrder,
+            reference: sp_std::vec![b'r'; 64].try_into().expect("64 bytes fit"),
+        };
+        let origin = role_origin::<T>(Role::ComplianceAgent);
+        CurrentSnapshotId::<T>::insert(asset_id, 1);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, from.clone(), to.clone(), 1_000_000, reason);
+
+        assert_eq!(Balances::<T>::get(asset_id, &from), 9_000_000);
+        assert_eq!(Balances::<T>::get(asset_id, &to), 1_000_001);
+        assert_eq!(FrozenAmount::<T>::get(asset_id, &from), 9_000_000);
+    }
+
+    #[benchmark]
+    fn freeze_partial() {
+        let asset_id = create_instruments::<T>(1);
+        let account: T::AccountId = account("holder", 0, 0);
+        Balances::<T>::insert(asset_id, &account, 10_000_000);
+        FrozenAmount::<T>::insert(asset_id, &account, 1_000_000);
+        let origin = role_origin::<T>(Role::Freezer);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, account.clone(), 1_000_000);
+
+        assert_eq!(FrozenAmount::<T>::get(asset_id, &account), 2_000_000);
+    }
+
+    #[benchmark]
+    fn unfreeze_partial() {
+        let asset_id = create_instruments::<T>(1);
+        let account: T::AccountId = account("holder", 0, 0);
+        FrozenAmount::<T>::insert(asset_id, &account, 2_000_000);
+        let origin = role_origin::<T>(Role::Freezer);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, account.clone(), 1_000_000);
+
+        assert_eq!(FrozenAmount::<T>::get(asset_id, &account), 1_000_000);
+    }
+
+    #[benchmark]
+    fn pause() {
+        let asset_id = create_instruments::<T>(1);
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id);
+
+        assert!(Paused::<T>::get(asset_id));
+    }
+
+    #[benchmark]
+    fn unpause() {
+        let asset_id = create_instruments::<T>(1);
+        Paused::<T>::insert(asset_id, true);
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id);
+
+        assert!(!Paused::<T>::get(asset_id));
+    }
+
+    #[benchmark]
+    fn add_trusted_issuer() {
+        let issuer: T::AccountId = account("issuer", 0, 0);
+        let topics = max_topics::<T>();
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, issuer.clone(), topics.clone());
+
+        assert_eq!(TrustedIssuers::<T>::get(&issuer), topics);
+    }
+
+    #[benchmark]
+    fn remove_trusted_issuer() {
+        let issuer: T::AccountId = account("issuer", 0, 0);
+        TrustedIssuers::<T>::insert(&issuer, max_topics::<T>());
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, issuer.clone());
+
+        assert!(!TrustedIssuers::<T>::contains_key(&issuer));
+    }
+
+    #[benchmark]
+    fn add_claim() {
+        let issuer: T::AccountId = whitelisted_caller();
+        let subject: T::AccountId = account("subject", 0, 0);
+        let topics = max_topics::<T>();
+        // Worst case: the topic is last in the issuer's list
+        let topic = *topics.last().expect("MaxClaimTopics is non-zero");
+        TrustedIssuers::<T>::insert(&issuer, &topics);
+        let expires_at = frame_system::Pallet::<T>::block_number() + 1_000u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(issuer), subject.clone(), topic, expires_at);
+
+        assert!(Claims::<T>::contains_key(&subject, topic));
+    }
+
+    #[benchmark]
+    fn remove_claim() {
+        let issuer: T::AccountId = account("issuer", 0, 0);
+        let subject: T::AccountId = account("subject", 0, 0);
+        Claims::<T>::insert(&subject, 0, Claim { issuer, expires_at: 1_000u32.into() });
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, subject.clone(), 0);
+
+        assert!(!Claims::<T>::contains_key(&subject, 0));
+    }
+
+    #[benchmark]
+    fn set_required_claim_topics() {
+        let asset_id = create_instruments::<T>(1);
+        let topics = max_topics::<T>();
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, topics.clone());
+
+        assert_eq!(RequiredClaimTopics::<T>::get(asset_id), topics);
+    }
+
+    #[benchmark]
+    fn set_allowed_countries() {
+        let asset_id = create_instruments::<T>(1);
+        let countries: CountriesOf<T> = (0..T::MaxCountries::get() as CountryCode)
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("fits the bound");
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, countries.clone());
+
+        assert_eq!(AllowedCountries::<T>::get(asset_id), countries);
+    }
+
+    #[benchmark]
+    fn set_blocked_countries() {
+        let asset_id = create_instruments::<T>(1);
+        let countries: CountriesOf<T> = (0..T::MaxCountries::get() as CountryCode)
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("fits the bound");
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, countries.clone());
+
+        assert_eq!(BlockedCountries::<T>::get(asset_id), countries);
+    }
+
+    #[benchmark]
+    fn set_max_holders() {
+        let asset_id = create_instruments::<T>(1);
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, Some(100));
+
+        assert_eq!(MaxHolders::<T>::get(asset_id), Some(100));
+    }
+
+    #[benchmark]
+    fn set_max_balance_per_holder() {
+        let asset_id = create_instruments::<T>(1);
+        let limit = HoldingLimit::ShareOfSupply(Perbill::from_percent(2));
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, Some(limit));
+
+        assert_eq!(MaxBalancePerHolder::<T>::get(asset_id), Some(limit));
+    }
+
+    #[benchmark]
+    fn set_account_holding_limit() {
+        let asset_id = create_instruments::<T>(1);
+        let account: T::AccountId = account("treasury", 0, 0);
+        let limit = HoldingLimit::Absolute(u128::MAX);
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, account.clone(), Some(limit));
+
+        assert_eq!(AccountHoldingLimit::<T>::get(asset_id, &account), Some(limit));
+    }
+
+    #[benchmark]
+    fn mint_with_schedule() {
+        let asset_id = create_instruments::<T>(1);
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let amount: u128 = 1_000_000;
+        let origin = role_origin::<T>(Role::Minter);
+
+        // Worst case: every other slot holds a still-locked schedule, and the
+        // instrument is capped like in `mint`
+        let max = T::MaxVestingSchedules::get();
+        add_vesting_locks::<T>(asset_id, &recipient, max.saturating_sub(1), 1);
+        MaxHolders::<T>::insert(asset_id, u32::MAX);
+        MaxSupply::<T>::insert(asset_id, u128::MAX);
+        MaxBalancePerHolder::<T>::insert(asset_id, HoldingLimit::ShareOfSupply(Perbill::one()));
+        let schedule =
+            VestingSchedule { start: 0u32.into(), cliff: 100u32.into(), end: 1_000u32.into() };
+        CurrentSnapshotId::<T>::insert(asset_id, 1);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, recipient.clone(), amount, schedule);
+
+        assert_eq!(Balances::<T>::get(asset_id, &recipient), amount);
+        assert_eq!(Vesting::<T>::get(asset_id, &recipient).len() as u32, max);
+    }
+
+    #[benchmark]
+    fn set_bond_terms() {
+        let asset_id = create_instruments::<T>(1);
+        let terms = bond_terms::<T>();
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, terms.clone());
+
+        assert_eq!(Bonds::<T>::get(asset_id), Some(terms));
+    }
+
+    #[benchmark]
+    fn distribute_coupon(n: Linear<0, { T::MaxHoldersPerPage::get() }>) {
+        // Worst case: every visited holder is paid, and the call closes the coupon
+        let asset_id = create_instruments::<T>(1);
+        let amount: u128 = 1_000;
+        for i in 0..n {
+            let holder: T::AccountId = account("holder", i, 0);
+            // Holders already exist in the native currency, so no payment fails
+            T::Currency::set_balance(&holder, T::Currency::minimum_balance());
+            Balances::<T>::insert(asset_id, &holder, amount);
+        }
+        TotalSupply::<T>::insert(asset_id, amount.saturating_mul(n.into()));
+        Bonds::<T>::insert(asset_id, bond_terms::<T>());
+        let pot = Pallet::<T>::coupon_pot(asset_id);
+        let funds = amount.saturating_mul(n.into()).unique_saturated_into();
+        T::Currency::set_balance(&pot, T::Currency::minimum_balance().saturating_add(funds));
+        frame_system::Pallet::<T>::set_block_number(1u32.into());
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), asset_id);
+
+        assert_eq!(NextCoupon::<T>::get(asset_id), 1);
+        assert!(CouponDistribution::<T>::get(asset_id).is_none());
+    }
+
+    #[benchmark]
+    fn redeem_at_maturity(n: Linear<0, { T::MaxHoldersPerPage::get() }>) {
+        // Worst case: the admin pages through holders that are all paid, which
+        // costs more than withholding their principal, and the last one matures
+        // the instrument
+        let asset_id = create_instruments::<T>(1);
+        let amount: u128 = 1_000;
+        for i in 0..n {
+            let holder: T::AccountId = account("holder", i, 0);
+            T::Currency::set_balance(&holder, T::Currency::minimum_balance());
+            Balances::<T>::insert(asset_id, &holder, amount);
+        }
+        HolderCount::<T>::insert(asset_id, n);
+        TotalSupply::<T>::insert(asset_id, amount.saturating_mul(n.into()));
+        let terms = bond_terms::<T>();
+        NextCoupon::<T>::insert(asset_id, terms.coupon_dates.len() as u32);
+        frame_system::Pallet::<T>::set_block_number(terms.maturity);
+        Bonds::<T>::insert(asset_id, terms);
+        let pot = Pallet::<T>::redemption_pot(asset_id);
+        let funds = amount.saturating_mul(n.into()).unique_saturated_into();
+        T::Currency::set_balance(&pot, T::Currency::minimum_balance().saturating_add(funds));
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+        CurrentSnapshotId::<T>::insert(asset_id, 1);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id);
+
+        assert_eq!(TotalSupply::<T>::get(asset_id), 0);
+        assert!(Matured::<T>::get(asset_id));
+    }
+
+    #[benchmark]
+    fn claim_matured_principal() {
+        // Worst case: the holder's vesting schedules have just run out and it was
+        // recovered to a new address, which is paid
+        let asset_id = create_instruments::<T>(1);
+        let caller: T::AccountId = whitelisted_caller();
+        let lost: T::AccountId = account("lost", 0, 0);
+        let holder: T::AccountId = account("holder", 0, 0);
+        let payout: u128 = 1_000;
+        add_vesting_locks::<T>(asset_id, &lost, T::MaxVestingSchedules::get(), payout);
+        frame_system::Pallet::<T>::set_block_number(1_000_000u32.into());
+        RecoveredAccounts::<T>::insert(asset_id, &lost, &holder);
+        T::Currency::set_balance(&holder, T::Currency::minimum_balance());
+        MaturityEscrow::<T>::insert(asset_id, &lost, payout);
+        MaturityEscrowTotal::<T>::insert(asset_id, payout);
+        let pot = Pallet::<T>::redemption_pot(asset_id);
+        let funds = payout.unique_saturated_into();
+        T::Currency::set_balance(&pot, T::Currency::minimum_balance().saturating_add(funds));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), asset_id, lost.clone());
+
+        assert_eq!(MaturityEscrow::<T>::get(asset_id, &lost), None);
+        assert_eq!(MaturityEscrowTotal::<T>::get(asset_id), 0);
+    }
+
+    #[benchmark]
+    fn claim_coupon() {
+        // Worst case: the holder was recovered to a new address, which is paid
+        let asset_id = create_instruments::<T>(1);
+        let caller: T::AccountId = whitelisted_caller();
+        let lost: T::AccountId = account("lost", 0, 0);
+        let holder: T::AccountId = account("holder", 0, 0);
+        let amount: u128 = 1_000;
+        RecoveredAccounts::<T>::insert(asset_id, &lost, &holder);
+        T::Currency::set_balance(&holder, T::Currency::minimum_balance());
+        UnpaidCoupons::<T>::insert((asset_id, 0, &lost), amount);
+        UnpaidCouponTotal::<T>::insert(asset_id, amount);
+        let pot = Pallet::<T>::coupon_pot(asset_id);
+        let funds = amount.unique_saturated_into();
+        T::Currency::set_balance(&pot, T::Currency::minimum_balance().saturating_add(funds));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), asset_id, 0, lost.clone());
+
+        assert_eq!(UnpaidCoupons::<T>::get((asset_id, 0, &lost)), None);
+        assert_eq!(UnpaidCouponTotal::<T>::get(asset_id), 0);
+    }
+
+    #[benchmark]
+    fn snapshot() {
+        let asset_id = create_instruments::<T>(1);
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id);
+
+        assert_eq!(CurrentSnapshotId::<T>::get(asset_id), 1);
+    }
+
+    #[benchmark]
+    fn declare_dividend() {
+        let asset_id = create_instruments::<T>(1);
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+        // Only a signed admin can fund a dividend
+        let issuer = frame_system::ensure_signed(origin.clone()).expect("Signed admin origin");
+        let amount: u128 = 1_000_000;
+        let funds = amount.unique_saturated_into();
+        T::Currency::set_balance(&issuer, T::Currency::minimum_balance().saturating_add(funds));
+        CurrentSnapshotId::<T>::insert(asset_id, 1);
+        SnapshotTotalSupply::<T>::insert(asset_id, 1, amount);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, 1, amount, 1_000u32.into(), issuer);
+
+        assert!(Dividends::<T>::get(asset_id, 0).is_some());
+    }
+
+    #[benchmark]
+    fn claim_dividend() {
+        // Worst case: the caller's snapshot balance was recorded, and it also
+        // collects the share of an address recovered to it
+        let asset_id = create_instruments::<T>(1);
+        let amount: u128 = 1_000_000;
+        declare_dividend::<T>(asset_id, amount);
+        let caller: T::AccountId = whitelisted_caller();
+        let lost: T::AccountId = account("lost", 0, 0);
+        SnapshotBalances::<T>::insert((asset_id, 1, &caller), amount / 2);
+        SnapshotBalances::<T>::insert((asset_id, 1, &lost), amount / 2);
+        RecoveredAccounts::<T>::insert(asset_id, &lost, &caller);
+        RecoveredFrom::<T>::insert(asset_id, &caller, &lost);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), asset_id, 0);
+
+        assert_eq!(
+            DividendClaims::<T>::get((asset_id, 0, &lost)),
+            Some(DividendClaim::Paid(amount / 2))
+        );
+    }
+
+    #[benchmark]
+    fn distribute_dividend(n: Linear<0, { T::MaxHoldersPerPage::get() }>) {
+        // Worst case: every visited holder has a recorded snapshot balance and is
+        // paid, and the call completes the walk
+        let asset_id = create_instruments::<T>(1);
+        let amount: u128 = 1_000;
+        declare_dividend::<T>(asset_id, amount.saturating_mul(n.max(1).into()));
+        for i in 0..n {
+            let holder: T::AccountId = account("holder", i, 0);
+            T::Currency::set_balance(&holder, T::Currency::minimum_balance());
+            Balances::<T>::insert(asset_id, &holder, 0);
+            SnapshotBalances::<T>::insert((asset_id, 1, &holder), amount);
+        }
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), asset_id, 0);
+
+        assert!(Dividends::<T>::get(asset_id, 0).expect("declared").distributed);
+    }
+
+    #[benchmark]
+    fn reclaim_dividend() {
+        let asset_id = create_instruments::<T>(1);
+        let mut dividend = declare_dividend::<T>(asset_id, 1_000_000);
+        dividend.distributed = true;
+        Dividends::<T>::insert(asset_id, 0, dividend);
+        frame_system::Pallet::<T>::set_block_number(1_001u32.into());
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), asset_id, 0);
+
+        assert!(Dividends::<T>::get(asset_id, 0).expect("declared").reclaimed);
+    }
+
+    #[benchmark]
+    fn split(n: Linear<0, { T::MaxHoldersPerPage::get() }>) {
+        // Worst case: a reverse split of a capped instrument with an absolute
+        // holding limit and recent mints, that empties every holder in one call
+        let asset_id = create_instruments::<T>(1);
+        split_holders::<T>(asset_id, n);
+        MaxSupply::<T>::insert(asset_id, u128::MAX);
+        MaxBalancePerHolder::<T>::insert(asset_id, HoldingLimit::Absolute(1_000));
+        RecentMints::<T>::insert(asset_id, MintWindow { start: 0u32.into(), minted: 1_000 });
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, 1, 2_000);
+
+        assert!(SplitInProgress::<T>::get(asset_id).is_none());
+        assert_eq!(TotalSupply::<T>::get(asset_id), 0);
+    }
+
+    #[benchmark]
+    fn continue_split(n: Linear<0, { T::MaxHoldersPerPage::get() }>) {
+        let asset_id = create_instruments::<T>(1);
+        split_holders::<T>(asset_id, n);
+        let old_supply = TotalSupply::<T>::get(asset_id);
+        SplitInProgress::<T>::insert(
+            asset_id,
+            SplitProgress {
+                numerator: 1,
+                denominator: 2_000,
+                rescaling_limits: false,
+                last_holder: None,
+                old_supply,
+                new_supply: 0,
+            },
+        );
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), asset_id);
+
+        assert!(SplitInProgress::<T>::get(asset_id).is_none());
+    }
+
+    #[benchmark]
+    fn recover_account() {
+        // Worst case: every piece of `lost`'s position exists and moves, `new`
+        // needs every claim topic, and a pending snapshot records both balances
+        let asset_id = create_instruments::<T>(1);
+        let lost: T::AccountId = account("lost", 0, 0);
+        let new: T::AccountId = account("new", 0, 0);
+        require_claims::<T>(asset_id, &[&new]);
+        Whitelist::<T>::insert(asset_id, &lost, WhitelistEntry { expires_at: None });
+        InvestorCountry::<T>::insert(asset_id, &lost, 398);
+        HoldersPerCountry::<T>::insert(asset_id, 398, 1);
+        Balances::<T>::insert(asset_id, &lost, 1_000_000);
+        HolderCount::<T>::insert(asset_id, 1);
+        TotalSupply::<T>::insert(asset_id, 1_000_000);
+        Frozen::<T>::insert(asset_id, &lost, true);
+        FrozenAmount::<T>::insert(asset_id, &lost, 1_000);
+        add_vesting_locks::<T>(asset_id, &lost, T::MaxVestingSchedules::get(), 1);
+        AccountHoldingLimit::<T>::insert(asset_id, &lost, HoldingLimit::Absolute(u128::MAX));
+        CurrentSnapshotId::<T>::insert(asset_id, 1);
+        let origin = role_origin::<T>(Role::ComplianceAgent);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, lost.clone(), new.clone());
+
+        assert_eq!(Balances::<T>::get(asset_id, &new), 1_000_000);
+        assert_eq!(RecoveredAccounts::<T>::get(asset_id, &lost), Some(new));
+    }
+
+    #[benchmark]
+    fn grant_role() {
+        let operator: T::AccountId = account("operator", 0, 0);
+        let origin = role_origin::<T>(Role::RoleAdmin);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Role::Minter, operator.clone());
+
+        assert!(Roles::<T>::get(Role::Minter, &operator));
+    }
+
+    #[benchmark]
+    fn revoke_role() {
+        let operator: T::AccountId = account("operator", 0, 0);
+        Roles::<T>::insert(Role::Minter, &operator, true);
+        let origin = role_origin::<T>(Role::RoleAdmin);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Role::Minter, operator.clone());
+
+        assert!(!Roles::<T>::get(Role::Minter, &operator));
+    }
+
+    #[benchmark]
+    fn schedule_mint() {
+        // Worst case: the queue of the execution block is one short of full
+        let asset_id = create_instruments::<T>(1);
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let max = T::MaxPendingMints::get();
+        let delay = T::MintDelay::get().max(One::one());
+        let execute_at = frame_system::Pallet::<T>::block_number() + delay;
+        let queued: Vec<PendingMintId> = (0..max.saturating_sub(1)).collect();
+        MintQueue::<T>::insert(execute_at, PendingMintIdsOf::<T>::truncate_from(queued));
+        NextPendingMintId::<T>::put(max.saturating_sub(1));
+        let amount = T::LargeMintThreshold::get();
+        let origin = role_origin::<T>(Role::Minter);
+
+        #[block]
+        {
+            Pallet::<T>::mint(origin, asset_id, recipient.clone(), amount).expect("queued");
+        }
+
+        assert_eq!(MintQueue::<T>::get(execute_at).len() as u32, max);
+        assert_eq!(Balances::<T>::get(asset_id, &recipient), 0);
+    }
+
+    #[benchmark]
+    fn cancel_mint() {
+        // Worst case: the cancelled mint is last in a full queue
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let max = T::MaxPendingMints::get();
+        let execute_at: BlockNumberFor<T> = 10u32.into();
+        let queued: Vec<PendingMintId> = (0..max).collect();
+        MintQueue::<T>::insert(execute_at, PendingMintIdsOf::<T>::truncate_from(queued));
+        let id = max.saturating_sub(1);
+        let schedule =
+            VestingSchedule { start: 0u32.into(), cliff: 100u32.into(), end: 1_000u32.into() };
+        PendingMints::<T>::insert(
+            id,
+            PendingMint {
+                asset_id: 0,
+                to: recipient,
+                amount: 1_000_000,
+                schedule: Some(schedule),
+                execute_at,
+            },
+        );
+        PendingMintCount::<T>::insert(0, max);
+        let origin = T::VetoOrigin::try_successful_origin().expect("Veto origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, id);
+
+        assert!(PendingMints::<T>::get(id).is_none());
+        assert!(!MintQueue::<T>::get(execute_at).contains(&id));
+    }
+
+    #[benchmark]
+    fn execute_pending_mints(n: Linear<0, { T::MaxPendingMints::get() }>) {
+        // Worst case: every due mint has a lock-up and adds a holder to a capped
+        // instrument with a pending snapshot
+        let asset_id = create_instruments::<T>(1);
+        MaxHolders::<T>::insert(asset_id, u32::MAX);
+        MaxSupply::<T>::insert(asset_id, u128::MAX);
+        MaxBalancePerHolder::<T>::insert(asset_id, HoldingLimit::ShareOfSupply(Perbill::one()));
+        CurrentSnapshotId::<T>::insert(asset_id, 1);
+        let execute_at: BlockNumberFor<T> = 10u32.into();
+        let schedule =
+            VestingSchedule { start: 0u32.into(), cliff: 100u32.into(), end: 1_000u32.into() };
+        for id in 0..n {
+            let recipient: T::AccountId = account("recipient", id, 0);
+            PendingMints::<T>::insert(
+                id,
+                PendingMint {
+                    asset_id,
+                    to: recipient,
+                    amount: 1_000_000,
+                    schedule: Some(schedule),
+                    execute_at,
+                },
+            );
+        }
+        let queued: Vec<PendingMintId> = (0..n).collect();
+        MintQueue::<T>::insert(execute_at, PendingMintIdsOf::<T>::truncate_from(queued));
+        PendingMintCount::<T>::insert(asset_id, n);
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(execute_at);
+        }
+
+        for id in 0..n {
+            let recipient: T::AccountId = account("recipient", id, 0);
+            assert_eq!(Balances::<T>::get(asset_id, &recipient), 1_000_000);
+        }
+        assert_eq!(PendingMints::<T>::iter().count(), 0);
+        assert_eq!(PendingMintCount::<T>::get(asset_id), 0);
+    }
+
+    #[benchmark]
+    fn raise_max_supply() {
+        // Worst case: a first cap, checked against the total supply
+        let asset_id = create_instruments::<T>(1);
+        TotalSupply::<T>::insert(asset_id, 1_000_000);
+        let origin = T::SupplyCapOrigin::try_successful_origin().expect("Supply cap origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, 2_000_000);
+
+        assert_eq!(MaxSupply::<T>::get(asset_id), Some(2_000_000));
+    }
+
+    #[benchmark]
+    fn set_metadata() {
+        // Worst case: every field set, with a full-length prospectus URI
+        let asset_id = create_instruments::<T>(1);
+        let name: TokenNameOf = sp_std::vec![b'n'; 64].try_into().expect("64 bytes fit");
+        let symbol: TokenSymbolOf = sp_std::vec![b's'; 16].try_into().expect("16 bytes fit");
+        let metadata = InstrumentMetadata {
+            isin: Some(*b"KZ2C00012345"),
+            cfi: Some(*b"DBFTFR"),
+            issuer_lei: Some(*b"5493001KJTIIGC8Y1R12"),
+            currency: Some(*b"KZT"),
+            prospectus_hash: Some([7; 32]),
+            prospectus_uri: sp_std::vec![b'u'; 256].try_into().expect("256 bytes fit"),
+        };
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, name, symbol, metadata.clone());
+
+        assert_eq!(Metadata::<T>::get(asset_id), Some(metadata));
+    }
+
+    #[benchmark]
+    fn set_document() {
+        let asset_id = create_instruments::<T>(1);
+        let name: DocumentNameOf = sp_std::vec![b'd'; 32].try_into().expect("32 bytes fit");
+        let uri: DocumentUriOf = sp_std::vec![b'u'; 256].try_into().expect("256 bytes fit");
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, name.clone(), uri, [7; 32]);
+
+        assert!(Documents::<T>::contains_key(asset_id, &name));
+    }
+
+    #[benchmark]
+    fn remove_document() {
+        let asset_id = create_instruments::<T>(1);
+        let name: DocumentNameOf = sp_std::vec![b'd'; 32].try_into().expect("32 bytes fit");
+        let uri: DocumentUriOf = sp_std::vec![b'u'; 256].try_into().expect("256 bytes fit");
+        let document = Document { uri, content_hash: [7; 32], last_modified: 1u32.into() };
+        Documents::<T>::insert(asset_id, &name, document);
+        let origin = T::AdminOrigin::try_successful_origin().expect("Admin origin");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, name.clone());
+
+        assert!(!Documents::<T>::contains_key(asset_id, &name));
+    }
+
+    #[benchmark]
+    fn batch_mint(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        let asset_id = create_instruments::<T>(1);
+        let amount: u128 = 1_000_000;
+        let mints: Vec<(T::AccountId, u128)> =
+            (0..n).map(|i| (account("recipient", i, 0), amount)).collect();
+        let mints: BoundedVec<_, T::MaxBatchSize> = mints.try_into().expect("fits the bound");
+        let origin = role_origin::<T>(Role::Minter);
+
+        // Worst case, as for `mint`: every recipient is a new holder of a capped
+        // instrument with a pending snapshot
+        MaxHolders::<T>::insert(asset_id, u32::MAX);
+        MaxSupply::<T>::insert(asset_id, u128::MAX);
+        MaxBalancePerHolder::<T>::insert(asset_id, HoldingLimit::ShareOfSupply(Perbill::one()));
+        CurrentSnapshotId::<T>::insert(asset_id, 1);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, mints);
+
+        assert_eq!(TotalSupply::<T>::get(asset_id), amount * n as u128);
+        assert_eq!(HolderCount::<T>::get(asset_id), n);
+    }
+
+    #[benchmark]
+    fn batch_whitelist(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        let asset_id = create_instruments::<T>(1);
+        let investors: Vec<(T::AccountId, Option<CountryCode>)> =
+            (0..n).map(|i| (account("investor", i, 0), Some(458))).collect();
+        let investors: BoundedVec<_, T::MaxBatchSize> =
+            investors.try_into().expect("fits the bound");
+        let origin = role_origin::<T>(Role::ComplianceAgent);
+
+        let expires_at = frame_system::Pallet::<T>::block_number() + 1_000u32.into();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, investors, Some(expires_at));
+
+        assert_eq!(Whitelist::<T>::iter_prefix(asset_id).count() as u32, n);
+    }
+
+    #[benchmark]
+    fn batch_freeze(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        let asset_id = create_instruments::<T>(1);
+        let accounts: Vec<T::AccountId> = (0..n).map(|i| account("holder", i, 0)).collect();
+        let accounts: BoundedVec<_, T::MaxBatchSize> = accounts.try_into().expect("fits the bound");
+        let origin = role_origin::<T>(Role::Freezer);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, asset_id, accounts);
+
+        assert_eq!(Frozen::<T>::iter_prefix(asset_id).count() as u32, n);
+    }
+
+    #[benchmark]
+    fn transfer_with_reference() {
+        let asset_id = create_instruments::<T>(1);
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let amount: u128 = 1_000_000;
+        setup_transfer::<T>(asset_id, &caller, &recipient, amount);
+        let reference: TransferReferenceOf =
+            sp_std::vec![b'r'; 35].try_into().expect("35 bytes fit");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), asset_id, recipient.clone(), amount, reference);
+
+        assert_eq!(Balances::<T>::get(asset_id, &recipient), amount);
+    }
+
+    #[benchmark]
+    fn sweep_whitelist(n: Linear<0, { T::MaxWhitelistSweep::get() }>) {
+        // Worst case: every inspected entry has expired and is removed
+        let asset_id = create_instruments::<T>(1);
+        for i in 0..n {
+            let holder: T::AccountId = account("holder", i, 0);
+            Whitelist::<T>::insert(
+                asset_id,
+                &holder,
+                WhitelistEntry { expires_at: Some(1u32.into()) },
+            );
+        }
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+        #[block]
+        {
+            Pallet::<T>::sweep_whitelist(n);
+        }
+
+        assert_eq!(Whitelist::<T>::iter_prefix(asset_id).count() as u32, 0);
     }
 
     impl_benchmark_test_suite!(CladToken, crate::mock::new_test_ext(), crate::mock::Test);
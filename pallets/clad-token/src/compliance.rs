@@ -0,0 +1,90 @@
+//! Pluggable transfer compliance for pallet-clad-token.
+//!
+//! The pallet always enforces its built-in rules (pause, whitelist, freezes). On top
+//! of those, each runtime can plug in jurisdiction-specific rules through
+//! [`Config::Compliance`](crate::Config::Compliance) without forking the pallet.
+//!
+//! This mirrors the ERC-3643 `ModularCompliance` contract:
+//!
+//! | ERC-3643 | [`TransferCompliance`] | Called from |
+//! |----------|------------------------|-------------|
+//! | `canTransfer` | [`can_transfer`](TransferCompliance::can_transfer) | [`transfer`](crate::Pallet::transfer) |
+//! | `transferred` | [`transferred`](TransferCompliance::transferred) | [`transfer`](crate::Pallet::transfer), [`forced_transfer`](crate::Pallet::forced_transfer) |
+//! | `created` | [`created`](TransferCompliance::created) | [`mint`](crate::Pallet::mint) |
+//! | `destroyed` | [`destroyed`](TransferCompliance::destroyed) | [`burn`](crate::Pallet::burn), [`redeem`](crate::Pallet::redeem) |
+//!
+//! # Composition
+//!
+//! Tuples of modules implement the trait. `can_transfer` succeeds only if every
+//! module allows the transfer (the first rejection is returned); the notification
+//! hooks are forwarded to every module in order.
+//!
+//! ```ignore
+//! impl pallet_clad_token::Config for Runtime {
+//!     // ...
+//!     type Compliance = (MaxHoldersModule, CountryRestrictModule, InvestorCapModule);
+//! }
+//! ```
+//!
+//! # Weights
+//!
+//! The pallet's benchmarks are taken with `Compliance = ()`. Modules that read or
+//! write storage must keep their work small and bounded, or the runtime must
+//! account for it when benchmarking its own configuration.
+
+use crate::AssetId;
+use frame_support::dispatch::DispatchResult;
+
+/// Transfer rules and bookkeeping hooks plugged into pallet-clad-token.
+///
+/// `can_transfer` is only consulted for holder-initiated transfers. Admin actions
+/// (mint, burn, forced transfer) are not subject to it, matching ERC-3643 where the
+/// agent bypasses `canTransfer` but compliance state is still kept in sync through
+/// the notification hooks.
+pub trait TransferCompliance<AccountId> {
+    /// Check whether `amount` of `asset_id` may move from `from` to `to`.
+    ///
+    /// Called after the pallet's own checks have passed and before any balance is
+    /// changed. Return an error to reject the transfer; the error is surfaced to
+    /// the caller unchanged.
+    fn can_transfer(
+        asset_id: AssetId,
+        from: &AccountId,
+        to: &AccountId,
+        amount: u128,
+    ) -> DispatchResult;
+
+    /// Notification that `amount` of `asset_id` moved from `from` to `to`.
+    fn transferred(asset_id: AssetId, from: &AccountId, to: &AccountId, amount: u128);
+
+    /// Notification that `amount` of `asset_id` was minted to `to`.
+    fn created(asset_id: AssetId, to: &AccountId, amount: u128);
+
+    /// Notification that `amount` of `asset_id` was destroyed from `from`.
+    fn destroyed(asset_id: AssetId, from: &AccountId, amount: u128);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<AccountId> TransferCompliance<AccountId> for Tuple {
+    fn can_transfer(
+        asset_id: AssetId,
+        from: &AccountId,
+        to: &AccountId,
+        amount: u128,
+    ) -> DispatchResult {
+        for_tuples!( #( Tuple::can_transfer(asset_id, from, to, amount)?; )* );
+        Ok(())
+    }
+
+    fn transferred(asset_id: AssetId, from: &AccountId, to: &AccountId, amount: u128) {
+        for_tuples!( #( Tuple::transferred(asset_id, from, to, amount); )* );
+    }
+
+    fn created(asset_id: AssetId, to: &AccountId, amount: u128) {
+        for_tuples!( #( Tuple::created(asset_id, to, amount); )* );
+    }
+
+    fn destroyed(asset_id: AssetId, from: &AccountId, amount: u128) {
+        for_tuples!( #( Tuple::destroyed(asset_id, from, amount); )* );
+    }
+}
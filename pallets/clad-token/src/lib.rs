@@ -25,8 +25,8 @@
 //! 1. **Sovereign Bond Tokenization**: Finance ministries can issue tokenized government bonds
 //!    accessible to domestic and international investors without traditional custodian chains.
 //!
-//! 2. **Treasury Bills**: Short-term debt instruments with automatic maturity tracking
-//!    (planned feature: auto-repayment oracles).
+//! 2. **Treasury Bills**: Short-term debt instruments with on-chain maturity and coupon
+//!    schedules (see [Bond Lifecycle](#bond-lifecycle)).
 //!
 //! 3. **State-Owned Enterprise Equity**: Partial privatization via tokenized equity shares
 //!    with voting rights (planned feature: on-chain governance).
@@ -42,27 +42,184 @@
 //! - **Separate whitelist and freeze**: An account can be whitelisted but frozen—this allows
 //!   temporary suspension without losing KYC status.
 //!
-//! - **Admin-only minting**: No permissionless minting; all token creation requires explicit
-//!   ministry/regulator approval.
+//! - **Role-gated minting**: No permissionless minting; all token creation requires a
+//!   minter appointed through the ministry/regulator's role admins.
 //!
 //! ## Quick Start
 //!
 //! ### Typical Workflow
 //!
 //! ```text
-//! 1. Admin whitelists investor accounts (KYC approval)
-//! 2. Admin mints tokens to treasury/issuer account
-//! 3. Treasury transfers tokens to whitelisted investors
-//! 4. Investors can transfer among themselves (if both whitelisted)
-//! 5. Admin can freeze accounts for compliance issues
+//! 1. Admin creates an instrument (e.g. a 5-year bond series) and receives its AssetId
+//! 2. Admin appoints a role admin, who grants the minter, freezer and compliance roles
+//! 3. Compliance agent whitelists investor accounts for that instrument (KYC approval)
+//! 4. Minter mints tokens of the instrument to treasury/issuer account
+//! 5. Treasury transfers tokens to whitelisted investors
+//! 6. Investors can transfer among themselves (if both whitelisted)
+//! 7. Freezer can freeze accounts for compliance issues
 //! ```
 //!
+//! ### Multiple Instruments
+//!
+//! A single chain can carry many instruments (T-bills, 5y and 10y bonds, equity series).
+//! Every storage item and every token extrinsic is keyed by an [`AssetId`]. The instrument
+//! configured at genesis is always [`GENESIS_ASSET_ID`] (`0`); further instruments are
+//! registered with [`create_instrument`](pallet::Pallet::create_instrument) and receive
+//! sequential ids.
+//!
+//! ### Payment References
+//!
+//! Settlement teams reconcile each on-chain transfer against a SWIFT or ISO 20022
+//! payment instruction. [`transfer_with_reference`](pallet::Pallet::transfer_with_reference)
+//! carries that instruction's end-to-end id (up to 35 bytes) in the
+//! [`Transferred`](pallet::Event::Transferred) event; plain transfers leave it empty.
+//!
+//! ### Batch Operations
+//!
+//! Onboarding a primary-market allocation takes hundreds of whitelistings and
+//! mints. [`batch_mint`](pallet::Pallet::batch_mint),
+//! [`batch_whitelist`](pallet::Pallet::batch_whitelist) and
+//! [`batch_freeze`](pallet::Pallet::batch_freeze) apply up to
+//! [`Config::MaxBatchSize`] of them in one call, so a multisig committee approves a
+//! whole allocation once. A batch is atomic: if one entry fails, none is applied.
+//!
+//! ### Instrument Metadata
+//!
+//! Besides name, symbol and decimals, each instrument can carry the identifiers
+//! registrars and explorers look up: ISIN, CFI code, issuer LEI, currency of
+//! denomination, and the hash and URI of the prospectus ([`InstrumentMetadata`]).
+//! [`set_metadata`](pallet::Pallet::set_metadata) updates them together with the
+//! name and symbol. Decimals are fixed at creation.
+//!
+//! ### Legal Documents
+//!
+//! Following ERC-1643, each instrument keeps a registry of the documents that
+//! govern it (offering circular, amendments, regulatory filings), keyed by name.
+//! [`set_document`](pallet::Pallet::set_document) records a document's URI and
+//! content hash, stamped with the current block;
+//! [`remove_document`](pallet::Pallet::remove_document) withdraws it. Clients list
+//! an instrument's documents with the `CladTokenApi::documents` runtime API.
+//!
+//! ### Supply Cap
+//!
+//! Each instrument can carry the authorised amount of its prospectus as a
+//! [`MaxSupply`], set at creation or genesis. Mints that would take the total supply
+//! above it fail with [`Error::SupplyCapExceeded`]. Only [`Config::SupplyCapOrigin`]
+//! (for example the body authorising issuance) can lift the cap, or cap an
+//! uncapped instrument at no less than its supply, with
+//! [`raise_max_supply`](pallet::Pallet::raise_max_supply); a cap can never be
+//! lowered. Splits rescale the cap together with the balances.
+//!
+//! ### Bond Lifecycle
+//!
+//! An instrument can carry [`BondTerms`]: face value, coupon rate, coupon dates and
+//! maturity block, set with [`set_bond_terms`](pallet::Pallet::set_bond_terms). The
+//! issuer funds each instrument's coupon pot ([`Pallet::coupon_pot`]) in the native
+//! currency. From each coupon date on, anyone (a keeper bot or a scheduled call) can
+//! invoke [`distribute_coupon`](pallet::Pallet::distribute_coupon), which pays holders
+//! pro rata, [`Config::MaxHoldersPerPage`] holders per call, until every holder is paid.
+//! Balances of the instrument are fixed while a coupon is being paid. A coupon that
+//! cannot be transferred stays in the pot ([`UnpaidCoupons`]) until
+//! [`claim_coupon`](pallet::Pallet::claim_coupon) pays it out.
+//!
+//! At maturity, once every coupon has been paid, the principal is repaid from the
+//! redemption pot ([`Pallet::redemption_pot`]) with
+//! [`redeem_at_maturity`](pallet::Pallet::redeem_at_maturity): each holder's tokens
+//! are burned against `face_value` per token. Frozen holders and holders with
+//! locked tokens are burned too, but their principal stays in the pot
+//! ([`MaturityEscrow`]) until the restriction is lifted and
+//! [`claim_matured_principal`](pallet::Pallet::claim_matured_principal) releases it.
+//! When the supply reaches zero the instrument is marked [`Matured`] and no longer
+//! moves.
+//!
+//! ### Record-Date Snapshots
+//!
+//! Coupons, dividends and shareholder votes are computed on balances as of a record
+//! date. [`snapshot`](pallet::Pallet::snapshot) fixes such a date; the first mint,
+//! burn or transfer touching an account afterwards records its previous balance
+//! (ERC-20 Snapshot style), so taking a snapshot costs the same however many holders
+//! there are. [`Pallet::balance_at`] and [`Pallet::total_supply_at`] read the recorded
+//! values, also through the `CladTokenApi` runtime API.
+//!
+//! ### Dividends
+//!
+//! Equity issuers pay dividends against a snapshot.
+//! [`declare_dividend`](pallet::Pallet::declare_dividend) moves the dividend from the
+//! issuer into a dedicated pot ([`Pallet::dividend_pot`]); each holder is entitled to
+//! `amount * balance / supply` at the snapshot, rounded down. Holders
+//! [`claim_dividend`](pallet::Pallet::claim_dividend) their share, or anyone pushes it
+//! with [`distribute_dividend`](pallet::Pallet::distribute_dividend), one page of
+//! holders per call. Shares of frozen holders are escrowed and paid once they are
+//! unfrozen. After the deadline, [`reclaim_dividend`](pallet::Pallet::reclaim_dividend)
+//! returns everything else left unclaimed to the issuer.
+//!
+//! ### Splits
+//!
+//! [`split`](pallet::Pallet::split) rescales an equity instrument by
+//! `numerator / denominator`: `2 / 1` is a 2-for-1 split, `1 / 10` a 1-for-10 reverse
+//! split. Holders are rescaled in pages of [`Config::MaxHoldersPerPage`]; anyone can
+//! drive the remaining pages with [`continue_split`](pallet::Pallet::continue_split),
+//! and the instrument is locked until the last page. Each balance, partial freeze and
+//! vesting lock rounds down, and the new total supply is the sum of the rescaled
+//! balances, so fractional entitlements are dropped (to be settled as cash in lieu
+//! off-chain). Snapshots taken before a split keep reporting pre-split amounts.
+//!
+//! ### Account Recovery
+//!
+//! When an investor loses their keys, [`recover_account`](pallet::Pallet::recover_account)
+//! (ERC-3643 `recoveryAddress`) moves their position on an instrument to a new
+//! address holding the required claims: balance, freeze state, whitelist approval,
+//! country, lock-ups and holding-limit override. The lost address is recorded in
+//! [`RecoveredAccounts`] and can never be whitelisted for, or hold, that instrument
+//! again. Entitlements earned by the lost address follow the position: its dividend
+//! shares, including escrowed ones, are paid to the new address, and time-locked
+//! mints to it execute to the new address.
+//!
+//! ### Time-Locked Mints
+//!
+//! An instrument's immediate mints are capped at [`Config::LargeMintThreshold`]
+//! tokens per window of [`Config::MintDelay`] blocks, tracked in [`RecentMints`]. A
+//! [`mint`](pallet::Pallet::mint), [`mint_with_schedule`](pallet::Pallet::mint_with_schedule)
+//! or [`batch_mint`](pallet::Pallet::batch_mint) entry that reaches the cap is not
+//! executed at once. It is queued in [`PendingMints`] and executed at the start of
+//! the block [`Config::MintDelay`] blocks later, unless [`Config::VetoOrigin`] (for
+//! example an audit office) calls [`cancel_mint`](pallet::Pallet::cancel_mint) in
+//! the meantime. Splitting a large
+//! issuance into smaller mints therefore does not avoid the delay.
+//!
+//! ### Roles
+//!
+//! Day-to-day operations are performed by accounts holding a [`Role`]: minters
+//! issue and burn, freezers freeze, compliance agents manage the whitelist, force
+//! transfers and recover wallets. [`Config::AdminOrigin`] does not pass these
+//! checks; it appoints role admins, who [`grant_role`](pallet::Pallet::grant_role)
+//! and [`revoke_role`](pallet::Pallet::revoke_role) the operational roles. The
+//! admin account holds every role from genesis (or from the v5 migration) until
+//! they are handed to separate keys; see [`roles`].
+//!
 //! ### Integration Example
 //!
 //! ```ignore
 //! // In your runtime configuration:
 //! impl pallet_clad_token::Config for Runtime {
 //!     type AdminOrigin = EnsureRoot<AccountId>;  // Or custom multi-sig origin
+//!     type MaxInstruments = ConstU32<256>;
+//!     type PauseBlocksMinting = ConstBool<true>;
+//!     type Compliance = ();  // Or a tuple of compliance modules
+//!     type MaxClaimTopics = ConstU32<8>;
+//!     type MaxWhitelistSweep = ConstU32<128>;
+//!     type MaxCountries = ConstU32<256>;
+//!     type MaxVestingSchedules = ConstU32<8>;
+//!     type Currency = Balances;
+//!     type PalletId = CladTokenPalletId;
+//!     type MaxCouponDates = ConstU32<64>;
+//!     type MaxHoldersPerPage = ConstU32<256>;
+//!     type LargeMintThreshold = ConstU128<10_000_000_000_000>;
+//!     type MintDelay = ConstU32<14_400>;
+//!     type VetoOrigin = EnsureRoot<AccountId>;  // Or the audit office's origin
+//!     type MaxPendingMints = ConstU32<16>;
+//!     type SupplyCapOrigin = EnsureRoot<AccountId>;  // Or a parliament/council origin
+//!     type MaxBatchSize = ConstU32<128>;
 //!     type WeightInfo = pallet_clad_token::weights::SubstrateWeight<Runtime>;
 //! }
 //! ```
@@ -71,26 +228,112 @@
 //!
 //! | Storage Item | Type | Purpose |
 //! |--------------|------|---------|
-//! | `TokenName` | `BoundedVec<u8, 64>` | Human-readable token name |
-//! | `TokenSymbol` | `BoundedVec<u8, 16>` | Trading symbol (e.g., "KZT-BOND-2025") |
-//! | `Decimals` | `u8` | Decimal precision (typically 6 or 18) |
-//! | `TotalSupply` | `u128` | Total tokens in circulation |
-//! | `Balances` | `Map<AccountId, u128>` | Per-account token balances |
-//! | `Frozen` | `Map<AccountId, bool>` | Frozen account flags |
-//! | `Whitelist` | `Map<AccountId, bool>` | KYC-approved account flags |
+//! | `NextAssetId` | `AssetId` | Id assigned to the next created instrument |
+//! | `TokenName` | `Map<AssetId, BoundedVec<u8, 64>>` | Human-readable token name |
+//! | `TokenSymbol` | `Map<AssetId, BoundedVec<u8, 16>>` | Trading symbol (e.g., "KZT-BOND-2025") |
+//! | `Decimals` | `Map<AssetId, u8>` | Decimal precision (typically 6 or 18) |
+//! | `Metadata` | `Map<AssetId, InstrumentMetadata>` | ISIN, CFI, issuer LEI, currency and prospectus |
+//! | `Documents` | `DoubleMap<AssetId, BoundedVec<u8, 32>, Document>` | Legal documents (ERC-1643) |
+//! | `TotalSupply` | `Map<AssetId, u128>` | Total tokens in circulation |
+//! | `MaxSupply` | `Map<AssetId, u128>` | Authorised amount mints may not exceed |
+//! | `Balances` | `DoubleMap<AssetId, AccountId, u128>` | Per-account token balances |
+//! | `Frozen` | `DoubleMap<AssetId, AccountId, bool>` | Frozen account flags |
+//! | `FrozenAmount` | `DoubleMap<AssetId, AccountId, u128>` | Partially frozen balances |
+//! | `Paused` | `Map<AssetId, bool>` | Instrument-wide kill switch |
+//! | `TrustedIssuers` | `Map<AccountId, BoundedVec<ClaimTopic>>` | Claim issuers and the topics they may attest |
+//! | `Claims` | `DoubleMap<AccountId, ClaimTopic, Claim>` | Identity claims with expiry |
+//! | `RequiredClaimTopics` | `Map<AssetId, BoundedVec<ClaimTopic>>` | Claims a holder needs per instrument |
+//! | `Whitelist` | `DoubleMap<AssetId, AccountId, WhitelistEntry>` | KYC approvals with optional expiry |
+//! | `WhitelistSweepCursor` | `Value<(AssetId, AccountId)>` | Progress of the expired-approval sweep |
+//! | `InvestorCountry` | `DoubleMap<AssetId, AccountId, CountryCode>` | Investor jurisdiction (ISO 3166-1 numeric) |
+//! | `AllowedCountries` | `Map<AssetId, BoundedVec<CountryCode>>` | Jurisdictions that may receive an instrument |
+//! | `BlockedCountries` | `Map<AssetId, BoundedVec<CountryCode>>` | Jurisdictions that may not receive an instrument |
+//! | `HoldersPerCountry` | `DoubleMap<AssetId, CountryCode, u32>` | Number of holders per jurisdiction |
+//! | `HolderCount` | `Map<AssetId, u32>` | Number of accounts with a non-zero balance |
+//! | `MaxHolders` | `Map<AssetId, u32>` | Holder cap for private placements |
+//! | `MaxBalancePerHolder` | `Map<AssetId, HoldingLimit>` | Concentration limit for every holder |
+//! | `AccountHoldingLimit` | `DoubleMap<AssetId, AccountId, HoldingLimit>` | Per-investor override of the limit |
+//! | `Vesting` | `DoubleMap<AssetId, AccountId, BoundedVec<VestingLock>>` | Lock-ups on minted tokens |
+//! | `Bonds` | `Map<AssetId, BondTerms>` | Face value, coupon schedule and maturity |
+//! | `NextCoupon` | `Map<AssetId, u32>` | Index of the next unpaid coupon date |
+//! | `CouponDistribution` | `Map<AssetId, CouponProgress>` | Coupon payment in progress |
+//! | `UnpaidCoupons` | `NMap<(AssetId, u32, AccountId), u128>` | Coupons that could not be paid, until claimed |
+//! | `UnpaidCouponTotal` | `Map<AssetId, u128>` | Unpaid coupons per instrument |
+//! | `RedemptionCursor` | `Map<AssetId, AccountId>` | Progress of the admin's maturity redemption |
+//! | `Matured` | `Map<AssetId, bool>` | Fully redeemed instruments |
+//! | `MaturityEscrow` | `DoubleMap<AssetId, AccountId, u128>` | Principal withheld from restricted holders |
+//! | `MaturityEscrowTotal` | `Map<AssetId, u128>` | Principal withheld per instrument |
+//! | `CurrentSnapshotId` | `Map<AssetId, SnapshotId>` | Latest record-date snapshot |
+//! | `SnapshotBalances` | `NMap<(AssetId, SnapshotId, AccountId), u128>` | Balances recorded for snapshots |
+//! | `SnapshotTotalSupply` | `DoubleMap<AssetId, SnapshotId, u128>` | Supply recorded for snapshots |
+//! | `NextDividendId` | `Map<AssetId, DividendId>` | Id of the next dividend |
+//! | `Dividends` | `DoubleMap<AssetId, DividendId, Dividend>` | Declared dividends and their progress |
+//! | `DividendClaims` | `NMap<(AssetId, DividendId, AccountId), DividendClaim>` | Shares paid or escrowed |
+//! | `SplitInProgress` | `Map<AssetId, SplitProgress>` | Split being applied |
+//! | `RecoveredAccounts` | `DoubleMap<AssetId, AccountId, AccountId>` | Lost addresses and where they were recovered to |
+//! | `RecoveredFrom` | `DoubleMap<AssetId, AccountId, AccountId>` | Recovery targets and the lost address they replaced |
+//! | `NextPendingMintId` | `PendingMintId` | Id of the next time-locked mint |
+//! | `PendingMints` | `Map<PendingMintId, PendingMint>` | Large mints waiting out the delay |
+//! | `PendingMintCount` | `Map<AssetId, u32>` | Large mints queued per instrument |
+//! | `MintQueue` | `Map<BlockNumber, BoundedVec<PendingMintId>>` | Time-locked mints due at each block |
+//! | `RecentMints` | `Map<AssetId, MintWindow>` | Immediate mints in the current time-lock window |
+//! | `Roles` | `DoubleMap<Role, AccountId, bool>` | Accounts holding each operator role |
 //! | `Admin` | `Option<AccountId>` | Storage-based admin (enables rotation) |
 //!
 //! ## Dispatchable Functions
 //!
 //! | Extrinsic | Permission | Description |
 //! |-----------|------------|-------------|
-//! | [`mint`](pallet::Pallet::mint) | Admin | Create new tokens |
+//! | [`mint`](pallet::Pallet::mint) | Minter | Create new tokens |
 //! | [`transfer`](pallet::Pallet::transfer) | Signed | Transfer tokens between accounts |
-//! | [`freeze`](pallet::Pallet::freeze) | Admin | Freeze an account |
-//! | [`unfreeze`](pallet::Pallet::unfreeze) | Admin | Unfreeze an account |
-//! | [`add_to_whitelist`](pallet::Pallet::add_to_whitelist) | Admin | Approve account for transfers |
-//! | [`remove_from_whitelist`](pallet::Pallet::remove_from_whitelist) | Admin | Revoke transfer approval |
+//! | [`transfer_with_reference`](pallet::Pallet::transfer_with_reference) | Signed | Transfer tokens with a payment reference |
+//! | [`freeze`](pallet::Pallet::freeze) | Freezer | Freeze an account |
+//! | [`unfreeze`](pallet::Pallet::unfreeze) | Freezer | Unfreeze an account |
+//! | [`add_to_whitelist`](pallet::Pallet::add_to_whitelist) | Compliance agent | Approve account for transfers |
+//! | [`remove_from_whitelist`](pallet::Pallet::remove_from_whitelist) | Compliance agent | Revoke transfer approval |
 //! | [`set_admin`](pallet::Pallet::set_admin) | Admin | Rotate admin to new account |
+//! | [`create_instrument`](pallet::Pallet::create_instrument) | Admin | Register a new instrument |
+//! | [`burn`](pallet::Pallet::burn) | Minter | Destroy tokens held by an account |
+//! | [`redeem`](pallet::Pallet::redeem) | Signed | Holder destroys own tokens (early redemption) |
+//! | [`forced_transfer`](pallet::Pallet::forced_transfer) | Compliance agent | Move tokens without the holder's signature |
+//! | [`freeze_partial`](pallet::Pallet::freeze_partial) | Freezer | Freeze part of an account's balance |
+//! | [`unfreeze_partial`](pallet::Pallet::unfreeze_partial) | Freezer | Release partially frozen tokens |
+//! | [`pause`](pallet::Pallet::pause) | Admin | Halt all transfers of an instrument |
+//! | [`unpause`](pallet::Pallet::unpause) | Admin | Resume transfers of an instrument |
+//! | [`add_trusted_issuer`](pallet::Pallet::add_trusted_issuer) | Admin | Register or update a claim issuer |
+//! | [`remove_trusted_issuer`](pallet::Pallet::remove_trusted_issuer) | Admin | Revoke a claim issuer |
+//! | [`add_claim`](pallet::Pallet::add_claim) | Trusted issuer | Attest a claim about an account |
+//! | [`remove_claim`](pallet::Pallet::remove_claim) | Issuer or Admin | Revoke a claim |
+//! | [`set_required_claim_topics`](pallet::Pallet::set_required_claim_topics) | Admin | Set the claims an instrument requires |
+//! | [`set_allowed_countries`](pallet::Pallet::set_allowed_countries) | Admin | Restrict an instrument to listed jurisdictions |
+//! | [`set_blocked_countries`](pallet::Pallet::set_blocked_countries) | Admin | Exclude jurisdictions from an instrument |
+//! | [`set_max_holders`](pallet::Pallet::set_max_holders) | Admin | Cap the number of holders of an instrument |
+//! | [`set_max_balance_per_holder`](pallet::Pallet::set_max_balance_per_holder) | Admin | Cap each holder's position |
+//! | [`set_account_holding_limit`](pallet::Pallet::set_account_holding_limit) | Admin | Override the cap for one investor |
+//! | [`mint_with_schedule`](pallet::Pallet::mint_with_schedule) | Minter | Mint tokens under a lock-up and vesting schedule |
+//! | [`set_bond_terms`](pallet::Pallet::set_bond_terms) | Admin | Set face value, coupons and maturity |
+//! | [`distribute_coupon`](pallet::Pallet::distribute_coupon) | Signed | Pay the due coupon to the next page of holders |
+//! | [`redeem_at_maturity`](pallet::Pallet::redeem_at_maturity) | Holder or Admin | Repay principal and burn the tokens |
+//! | [`snapshot`](pallet::Pallet::snapshot) | Admin | Fix a record date for balances |
+//! | [`declare_dividend`](pallet::Pallet::declare_dividend) | Admin | Deposit a dividend against a snapshot |
+//! | [`claim_dividend`](pallet::Pallet::claim_dividend) | Holder | Collect a dividend share |
+//! | [`distribute_dividend`](pallet::Pallet::distribute_dividend) | Signed | Pay a dividend to the next page of holders |
+//! | [`reclaim_dividend`](pallet::Pallet::reclaim_dividend) | Signed | Return an expired dividend's remainder to the issuer |
+//! | [`split`](pallet::Pallet::split) | Admin | Split or consolidate an instrument's tokens |
+//! | [`continue_split`](pallet::Pallet::continue_split) | Signed | Rescale the next page of holders |
+//! | [`recover_account`](pallet::Pallet::recover_account) | Compliance agent | Move a lost wallet's position to a new address |
+//! | [`cancel_mint`](pallet::Pallet::cancel_mint) | Veto | Stop a time-locked mint before it executes |
+//! | [`grant_role`](pallet::Pallet::grant_role) | Role admin (Admin for role admins) | Give an account an operator role |
+//! | [`revoke_role`](pallet::Pallet::revoke_role) | Role admin (Admin for role admins) | Take an operator role away |
+//! | [`raise_max_supply`](pallet::Pallet::raise_max_supply) | Supply cap | Raise an instrument's authorised amount |
+//! | [`set_metadata`](pallet::Pallet::set_metadata) | Admin | Update name, symbol, identifiers and prospectus |
+//! | [`set_document`](pallet::Pallet::set_document) | Admin | Attach or update a legal document |
+//! | [`remove_document`](pallet::Pallet::remove_document) | Admin | Withdraw a legal document |
+//! | [`batch_mint`](pallet::Pallet::batch_mint) | Minter | Mint to many accounts at once |
+//! | [`batch_whitelist`](pallet::Pallet::batch_whitelist) | Compliance agent | Whitelist many accounts at once |
+//! | [`batch_freeze`](pallet::Pallet::batch_freeze) | Freezer | Freeze many accounts at once |
+//! | [`claim_matured_principal`](pallet::Pallet::claim_matured_principal) | Signed | Release principal withheld at maturity |
+//! | [`claim_coupon`](pallet::Pallet::claim_coupon) | Signed | Pay out a coupon that could not be paid |
 //!
 //! ## License
 //!
@@ -100,11 +343,30 @@
 #![allow(clippy::let_unit_value)]
 #![warn(missing_docs)]
 
-use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, traits::EnsureOrigin};
+use frame_support::{
+    dispatch::DispatchResult,
+    ensure,
+    pallet_prelude::*,
+    traits::{
+        fungible,
+        tokens::{Fortitude, Preservation},
+        EnsureOrigin,
+    },
+    CloneNoBound, EqNoBound, PalletId, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use frame_system::{ensure_signed, pallet_prelude::*};
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::{AccountIdConversion, AtLeast32BitUnsigned, One, Saturating, UniqueSaturatedInto},
+    Perbill, Rounding,
+};
 use sp_std::prelude::*;
 
+pub use compliance::TransferCompliance;
 pub use pallet::*;
+pub use roles::{
+    EnsureComplianceAgent, EnsureFreezer, EnsureMinter, EnsureRole, EnsureRoleAdmin, Role,
+};
 pub use weights::WeightInfo;
 
 #[cfg(test)]
@@ -116,11 +378,500 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod compliance;
 pub mod migrations;
+pub mod roles;
 pub mod weights;
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
+/// Identifier of a token instrument (bond series, T-bill, equity class).
+///
+/// Ids are assigned sequentially by [`Pallet::create_instrument`], starting after
+/// [`GENESIS_ASSET_ID`].
+pub type AssetId = u32;
+
+/// Asset id of the instrument configured in the genesis config.
+///
+/// Chains that ran the single-token layout (storage version 1) have their token
+/// migrated into this id by [`migrations::v2::MigrateToV2`].
+pub const GENESIS_ASSET_ID: AssetId = 0;
+
+/// Bounded token name (64 bytes max).
+pub type TokenNameOf = BoundedVec<u8, ConstU32<64>>;
+
+/// Bounded token symbol (16 bytes max).
+pub type TokenSymbolOf = BoundedVec<u8, ConstU32<16>>;
+
+/// Bounded URI of an off-chain document (256 bytes max), e.g. an IPFS or HTTPS link.
+pub type DocumentUriOf = BoundedVec<u8, ConstU32<256>>;
+
+/// International Securities Identification Number (ISO 6166), e.g. `KZ2C00012345`.
+pub type Isin = [u8; 12];
+
+/// Classification of Financial Instruments code (ISO 10962), e.g. `DBFTFR` for a
+/// fixed-rate bond.
+pub type CfiCode = [u8; 6];
+
+/// Legal Entity Identifier (ISO 17442) of the issuer.
+pub type Lei = [u8; 20];
+
+/// Alphabetic currency code (ISO 4217) of the denomination, e.g. `KZT`.
+pub type CurrencyCode = [u8; 3];
+
+/// Regulatory identifiers and offering documents of an instrument.
+///
+/// Every field is optional, since identifiers are often assigned after an
+/// instrument is registered. Codes are stored as upper-case ASCII; the pallet
+/// checks their format but not their check digits.
+#[derive(
+    Clone,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct InstrumentMetadata {
+    /// ISIN of the instrument.
+    pub isin: Option<Isin>,
+    /// CFI code of the instrument.
+    pub cfi: Option<CfiCode>,
+    /// LEI of the issuer (ministry of finance, SOE).
+    pub issuer_lei: Option<Lei>,
+    /// Currency of denomination.
+    pub currency: Option<CurrencyCode>,
+    /// Hash of the prospectus or offering circular (e.g. SHA-256 of the PDF).
+    pub prospectus_hash: Option<[u8; 32]>,
+    /// Where the prospectus can be downloaded; empty if unpublished.
+    pub prospectus_uri: DocumentUriOf,
+}
+
+impl InstrumentMetadata {
+    /// Whether every identifier present has the format of its standard.
+    ///
+    /// An ISIN is two letters followed by ten letters or digits, a CFI code six
+    /// letters, an LEI twenty letters or digits and a currency code three letters,
+    /// all upper-case.
+    pub fn is_well_formed(&self) -> bool {
+        fn letters(code: &[u8]) -> bool {
+            code.iter().all(u8::is_ascii_uppercase)
+        }
+        fn alphanumeric(code: &[u8]) -> bool {
+            code.iter().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        }
+
+        self.isin.is_none_or(|isin| letters(&isin[..2]) && alphanumeric(&isin[2..]))
+            && self.cfi.is_none_or(|cfi| letters(&cfi))
+            && self.issuer_lei.is_none_or(|lei| alphanumeric(&lei))
+            && self.currency.is_none_or(|currency| letters(&currency))
+    }
+}
+
+/// Bounded name of a legal document (32 bytes max), e.g. `"offering-circular"`.
+///
+/// Matches the `bytes32` document names of ERC-1643.
+pub type DocumentNameOf = BoundedVec<u8, ConstU32<32>>;
+
+/// A legal document attached to an instrument (ERC-1643).
+///
+/// The document itself lives off-chain; the hash lets investors and auditors
+/// check that the file they downloaded is the one registered.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Document<BlockNumber> {
+    /// Where the document can be downloaded.
+    pub uri: DocumentUriOf,
+    /// Hash of the document's content (e.g. SHA-256 of the PDF).
+    pub content_hash: [u8; 32],
+    /// Block at which the document was last set.
+    pub last_modified: BlockNumber,
+}
+
+/// Payment reference attached to a transfer (35 bytes max).
+///
+/// Sized for an ISO 20022 end-to-end id (`EndToEndId`, `Max35Text`), so a transfer
+/// can be matched to the SWIFT or ISO 20022 payment instruction it settles.
+pub type TransferReferenceOf = BoundedVec<u8, ConstU32<35>>;
+
+/// Bounded external reference attached to a forced transfer (64 bytes max).
+///
+/// Typically a court case number, probate reference or internal ticket id.
+pub type ForcedTransferReferenceOf = BoundedVec<u8, ConstU32<64>>;
+
+/// Category of a forced transfer, recorded on-chain for auditors.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum ForcedTransferCode {
+    /// Executing a court order or regulator instruction.
+    CourtOrder,
+    /// Moving a deceased holder's position to their heirs.
+    Inheritance,
+    /// Reversing a mistaken transfer.
+    Correction,
+    /// Anything else; the reference must explain it.
+    Other,
+}
+
+/// Reason attached to [`Pallet::forced_transfer`].
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct ForcedTransferReason {
+    /// Category of the forced transfer.
+    pub code: ForcedTransferCode,
+    /// External reference (court case number, ticket id).
+    pub reference: ForcedTransferReferenceOf,
+}
+
+/// A whitelist (KYC approval) entry.
+///
+/// KYC approvals lapse: most regulators require investors to be re-verified
+/// periodically. An entry without `expires_at` never lapses (treasury and admin
+/// accounts).
+#[derive(
+    Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct WhitelistEntry<BlockNumber> {
+    /// First block at which the approval is no longer valid, if any.
+    pub expires_at: Option<BlockNumber>,
+}
+
+impl<BlockNumber: PartialOrd> WhitelistEntry<BlockNumber> {
+    /// An approval that never lapses.
+    pub const fn permanent() -> Self {
+        Self { expires_at: None }
+    }
+
+    /// Whether the approval has lapsed at block `now`.
+    pub fn is_expired(&self, now: &BlockNumber) -> bool {
+        self.expires_at.as_ref().is_some_and(|expires_at| now >= expires_at)
+    }
+}
+
+/// Maximum position a single account may hold in an instrument.
+///
+/// Retail tranches are commonly capped per investor, either at a fixed amount or
+/// at a share of the issue.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum HoldingLimit {
+    /// At most this many tokens (raw value).
+    Absolute(u128),
+    /// At most this share of the instrument's total supply.
+    ShareOfSupply(Perbill),
+}
+
+impl HoldingLimit {
+    /// Largest permitted balance given the instrument's `total_supply`.
+    pub fn max_balance(&self, total_supply: u128) -> u128 {
+        match self {
+            Self::Absolute(amount) => *amount,
+            Self::ShareOfSupply(share) => share.mul_floor(total_supply),
+        }
+    }
+}
+
+/// Lock-up and release schedule for minted tokens, by block number.
+///
+/// Nothing is released before `cliff`. From `cliff` on, tokens count as released
+/// linearly from `start`, so a cliff after `start` releases the accrued part at
+/// once. Everything is released at `end`.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct VestingSchedule<BlockNumber> {
+    /// Block from which release accrues.
+    pub start: BlockNumber,
+    /// First block at which any tokens are released.
+    pub cliff: BlockNumber,
+    /// Block at which all tokens are released.
+    pub end: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> VestingSchedule<BlockNumber> {
+    /// Whether the blocks are in order (`start <= cliff <= end`).
+    pub fn is_valid(&self) -> bool {
+        self.start <= self.cliff && self.cliff <= self.end
+    }
+
+    /// Part of `amount` still locked at block `now`.
+    pub fn locked_at(&self, amount: u128, now: BlockNumber) -> u128 {
+        if now < self.cliff {
+            return amount;
+        }
+        if now >= self.end {
+            return 0;
+        }
+        // start <= cliff <= now < end, so the duration is non-zero
+        let elapsed: u128 = (now - self.start).unique_saturated_into();
+        let duration: u128 = (self.end - self.start).unique_saturated_into();
+        let released =
+            multiply_by_rational_with_rounding(amount, elapsed, duration, Rounding::Down)
+                .unwrap_or(amount);
+        amount.saturating_sub(released)
+    }
+}
+
+/// Tokens minted under a [`VestingSchedule`].
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VestingLock<BlockNumber> {
+    /// Number of tokens minted under the schedule.
+    pub amount: u128,
+    /// When they are released.
+    pub schedule: VestingSchedule<BlockNumber>,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> VestingLock<BlockNumber> {
+    /// Part of the lock still locked at block `now`.
+    pub fn locked_at(&self, now: BlockNumber) -> u128 {
+        self.schedule.locked_at(self.amount, now)
+    }
+}
+
+/// Financial terms of a bond instrument.
+///
+/// Amounts in the native currency are raw units per raw token unit, so an
+/// instrument whose token has fewer decimals than the native currency uses a
+/// `face_value` above one.
+#[derive(
+    CloneNoBound,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    EqNoBound,
+    PartialEqNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[codec(mel_bound(BlockNumber: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxCouponDates))]
+pub struct BondTerms<BlockNumber: Clone + Eq + core::fmt::Debug, MaxCouponDates: Get<u32>> {
+    /// Native currency repaid per token at maturity.
+    pub face_value: u128,
+    /// Share of the face value paid on each coupon date (not an annual rate).
+    pub coupon_rate: Perbill,
+    /// Blocks from which each coupon may be paid, in increasing order.
+    pub coupon_dates: BoundedVec<BlockNumber, MaxCouponDates>,
+    /// Block at which the principal falls due.
+    pub maturity: BlockNumber,
+}
+
+impl<BlockNumber, MaxCouponDates> BondTerms<BlockNumber, MaxCouponDates>
+where
+    BlockNumber: Clone + Eq + Ord + core::fmt::Debug,
+    MaxCouponDates: Get<u32>,
+{
+    /// Whether the coupon dates strictly increase and none falls after maturity.
+    pub fn is_valid(&self) -> bool {
+        self.coupon_dates.windows(2).all(|pair| pair[0] < pair[1])
+            && self.coupon_dates.last().is_none_or(|last| *last <= self.maturity)
+    }
+
+    /// Coupon owed on a holding of `balance` tokens, rounded down.
+    pub fn coupon_amount(&self, balance: u128) -> u128 {
+        self.coupon_rate.mul_floor(self.face_value.saturating_mul(balance))
+    }
+}
+
+/// Progress of a coupon payment spread over several calls.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CouponProgress<AccountId> {
+    /// Index into [`BondTerms::coupon_dates`] of the coupon being paid.
+    pub coupon_index: u32,
+    /// Last holder visited; payment resumes after it.
+    pub last_holder: Option<AccountId>,
+    /// Native currency paid out so far.
+    pub paid: u128,
+}
+
+/// Identifier of a record-date snapshot of an instrument.
+///
+/// Ids are assigned per instrument by [`Pallet::snapshot`], starting at `1`.
+pub type SnapshotId = u32;
+
+/// Identifier of a dividend of an instrument.
+///
+/// Ids are assigned per instrument by [`Pallet::declare_dividend`], starting at `0`.
+pub type DividendId = u32;
+
+/// A dividend declared on an instrument, with its payment progress.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Dividend<AccountId, BlockNumber> {
+    /// Account that funded the dividend; it receives what is left unclaimed.
+    pub issuer: AccountId,
+    /// Snapshot whose balances entitle holders to a share.
+    pub snapshot_id: SnapshotId,
+    /// Native currency deposited.
+    pub amount: u128,
+    /// Total supply at the snapshot.
+    pub supply: u128,
+    /// Last block at which shares can be claimed or pushed.
+    pub deadline: BlockNumber,
+    /// Native currency paid to holders so far.
+    pub paid: u128,
+    /// Shares of frozen holders held back until they are unfrozen.
+    pub escrowed: u128,
+    /// Last holder visited by [`Pallet::distribute_dividend`]; payment resumes
+    /// after it.
+    pub last_holder: Option<AccountId>,
+    /// Whether [`Pallet::distribute_dividend`] has visited every holder.
+    pub distributed: bool,
+    /// Whether the unclaimed remainder has been returned to the issuer.
+    pub reclaimed: bool,
+}
+
+impl<AccountId, BlockNumber> Dividend<AccountId, BlockNumber> {
+    /// Share owed on a holding of `balance` tokens at the snapshot, rounded down.
+    pub fn share(&self, balance: u128) -> u128 {
+        multiply_by_rational_with_rounding(balance, self.amount, self.supply, Rounding::Down)
+            .unwrap_or(0)
+    }
+}
+
+/// Progress of a split spread over several calls.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SplitProgress<AccountId> {
+    /// New tokens per `denominator` old tokens.
+    pub numerator: u32,
+    /// Old tokens per `numerator` new tokens.
+    pub denominator: u32,
+    /// Whether every holder has been rescaled, and the split has moved on to
+    /// the [`AccountHoldingLimit`] overrides.
+    pub rescaling_limits: bool,
+    /// Last account rescaled, holder or override; the split resumes after it.
+    pub last_holder: Option<AccountId>,
+    /// Total supply before the split.
+    pub old_supply: u128,
+    /// Sum of the balances rescaled so far.
+    pub new_supply: u128,
+}
+
+impl<AccountId> SplitProgress<AccountId> {
+    /// `amount` in post-split units, rounded down.
+    pub fn rescale(&self, amount: u128) -> u128 {
+        multiply_by_rational_with_rounding(
+            amount,
+            self.numerator.into(),
+            self.denominator.into(),
+            Rounding::Down,
+        )
+        .unwrap_or(u128::MAX)
+    }
+
+    /// `limit` in post-split units; a share of supply needs no rescaling.
+    pub fn rescale_limit(&self, limit: HoldingLimit) -> HoldingLimit {
+        match limit {
+            HoldingLimit::Absolute(amount) => HoldingLimit::Absolute(self.rescale(amount)),
+            share => share,
+        }
+    }
+}
+
+/// Identifier of a time-locked mint.
+///
+/// Ids are assigned sequentially by [`Pallet::mint`] and
+/// [`Pallet::mint_with_schedule`], starting at `0`.
+pub type PendingMintId = u32;
+
+/// A large mint waiting out [`Config::MintDelay`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingMint<AccountId, BlockNumber> {
+    /// Instrument to mint.
+    pub asset_id: AssetId,
+    /// Recipient of the new tokens.
+    pub to: AccountId,
+    /// Number of tokens to create (raw value).
+    pub amount: u128,
+    /// Lock-up requested through [`Pallet::mint_with_schedule`], if any.
+    pub schedule: Option<VestingSchedule<BlockNumber>>,
+    /// Block at whose start the mint is executed.
+    pub execute_at: BlockNumber,
+}
+
+/// Immediate mints of one instrument in the current time-lock window.
+///
+/// [`Config::LargeMintThreshold`] applies to `minted` rather than to each call.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MintWindow<BlockNumber> {
+    /// First block of the window, which lasts [`Config::MintDelay`] blocks.
+    pub start: BlockNumber,
+    /// Tokens minted without a time lock since `start` (raw value).
+    pub minted: u128,
+}
+
+/// Settlement of one holder's dividend share.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DividendClaim {
+    /// The share was paid out.
+    Paid(u128),
+    /// The holder was frozen; the share is held in the pot until it is claimed.
+    Escrowed(u128),
+}
+
+/// ISO 3166-1 numeric country code of an investor's jurisdiction (e.g. `398` for
+/// Kazakhstan, `458` for Malaysia).
+///
+/// The numeric form matches the ERC-3643 identity registry. The pallet does not
+/// validate codes against the ISO list.
+pub type CountryCode = u16;
+
+/// Identifier of a claim topic (e.g. KYC, accredited investor, residency).
+///
+/// Topic numbering is a chain-wide convention agreed between issuers and the
+/// token admin; the pallet attaches no meaning to the values.
+pub type ClaimTopic = u32;
+
+/// A claim about an account, attested by a trusted issuer.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Claim<AccountId, BlockNumber> {
+    /// Issuer that attested the claim (KYC provider, transfer agent).
+    pub issuer: AccountId,
+    /// First block at which the claim is no longer valid.
+    pub expires_at: BlockNumber,
+}
 
 /// The main pallet module containing configuration, storage, events, errors, and dispatchables.
 #[frame_support::pallet]
@@ -142,6 +893,40 @@ pub mod pallet {
     /// impl pallet_clad_token::Config for Runtime {
     ///     // Only sudo/root can perform admin operations
     ///     type AdminOrigin = EnsureRoot<AccountId>;
+    ///     // Up to 256 bond series / equity classes on one chain
+    ///     type MaxInstruments = ConstU32<256>;
+    ///     // A paused token also rejects new issuance
+    ///     type PauseBlocksMinting = ConstBool<true>;
+    ///     // No extra transfer rules beyond whitelist and freezes
+    ///     type Compliance = ();
+    ///     // KYC, accreditation, residency and a few spare topics
+    ///     type MaxClaimTopics = ConstU32<8>;
+    ///     // Expired KYC approvals cleaned up per idle block
+    ///     type MaxWhitelistSweep = ConstU32<128>;
+    ///     // Enough to list every ISO 3166-1 country
+    ///     type MaxCountries = ConstU32<256>;
+    ///     // Concurrent lock-ups per investor and instrument
+    ///     type MaxVestingSchedules = ConstU32<8>;
+    ///     // Coupons are paid in the native token
+    ///     type Currency = Balances;
+    ///     // Coupon pots are derived from this id
+    ///     type PalletId = CladTokenPalletId;
+    ///     // 30 years of semi-annual coupons, with room to spare
+    ///     type MaxCouponDates = ConstU32<64>;
+    ///     // Holders paid or rescaled per paged call
+    ///     type MaxHoldersPerPage = ConstU32<256>;
+    ///     // Mints taking a day's issuance to 10M tokens (6 decimals) or more wait
+    ///     // a day (6s blocks)
+    ///     type LargeMintThreshold = ConstU128<10_000_000_000_000>;
+    ///     type MintDelay = ConstU32<14_400>;
+    ///     // The audit office can cancel them in the meantime
+    ///     type VetoOrigin = EnsureSignedBy<AuditOffice, AccountId>;
+    ///     // Large mints falling due in the same block
+    ///     type MaxPendingMints = ConstU32<16>;
+    ///     // Authorised amounts are raised by parliament, not the debt office
+    ///     type SupplyCapOrigin = EnsureRoot<AccountId>;
+    ///     // Investors per batch_mint / batch_whitelist / batch_freeze
+    ///     type MaxBatchSize = ConstU32<128>;
     ///     // Use benchmark-derived weights
     ///     type WeightInfo = pallet_clad_token::weights::SubstrateWeight<Runtime>;
     /// }
@@ -149,20 +934,27 @@ pub mod pallet {
     ///
     /// # Security Considerations
     ///
-    /// The `AdminOrigin` controls all privileged operations including minting,
-    /// freezing, and whitelisting. For production deployments, consider:
+    /// The `AdminOrigin` controls instrument configuration and appoints the role
+    /// admins who delegate minting, freezing, and whitelisting. For production
+    /// deployments, consider:
     ///
     /// - Using a multi-signature origin (e.g., 3-of-5 ministry officials)
     /// - Implementing a council/governance origin for democratic oversight
-    /// - Adding time-locks for large minting operations
+    /// - Setting [`Config::LargeMintThreshold`] so large mints wait out
+    ///   [`Config::MintDelay`], with an independent [`Config::VetoOrigin`]
     #[pallet::config]
     pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
         /// Origin that can perform administrative operations.
         ///
         /// This origin is authorized to:
-        /// - Mint new tokens ([`Pallet::mint`])
-        /// - Freeze/unfreeze accounts ([`Pallet::freeze`], [`Pallet::unfreeze`])
-        /// - Manage whitelist ([`Pallet::add_to_whitelist`], [`Pallet::remove_from_whitelist`])
+        /// - Register instruments ([`Pallet::create_instrument`])
+        /// - Configure instruments (pause, compliance rules, bond terms, splits)
+        /// - Appoint and remove role admins ([`Pallet::grant_role`],
+        ///   [`Pallet::revoke_role`] with [`Role::RoleAdmin`])
+        ///
+        /// It does not pass role checks: minting, freezing and whitelisting need a
+        /// [`Role`] holder. The admin account holds every role from genesis (or the
+        /// v5 migration), and acts through those roles until they are revoked.
         ///
         /// # Typical Configurations
         ///
@@ -179,6 +971,136 @@ pub mod pallet {
         /// Unauthorized minting would destroy the token's value and credibility.
         type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+        /// Maximum number of instruments that can be registered on this chain.
+        ///
+        /// Bounds the per-instrument work done by [`Pallet::set_admin`], which
+        /// whitelists the new admin on every existing instrument. The genesis
+        /// instrument counts towards this limit.
+        ///
+        /// A debt office issuing a few dozen series per year is well served by
+        /// a value in the low hundreds.
+        #[pallet::constant]
+        type MaxInstruments: Get<u32>;
+
+        /// Whether [`Pallet::pause`] also blocks [`Pallet::mint`].
+        ///
+        /// Holder-initiated movements ([`Pallet::transfer`], [`Pallet::redeem`]) are
+        /// always blocked while an instrument is paused. Set this to `true` if a
+        /// security incident should also halt new issuance; leave it `false` if the
+        /// issuer must be able to mint during remediation.
+        #[pallet::constant]
+        type PauseBlocksMinting: Get<bool>;
+
+        /// Additional transfer rules plugged in by the runtime.
+        ///
+        /// Consulted by [`Pallet::transfer`] after the built-in whitelist and freeze
+        /// checks, and notified of every mint, burn and transfer so modules can keep
+        /// their own state (holder counts, per-investor totals) in sync. Use `()` for
+        /// no extra rules, or a tuple to combine several modules. See
+        /// [`compliance`](crate::compliance) for details.
+        type Compliance: TransferCompliance<Self::AccountId>;
+
+        /// Maximum number of claim topics an instrument can require, and an issuer
+        /// can be trusted for.
+        ///
+        /// Every required topic costs two storage reads per verified account, so this
+        /// directly bounds the weight of [`Pallet::transfer`].
+        #[pallet::constant]
+        type MaxClaimTopics: Get<u32>;
+
+        /// Maximum number of whitelist entries inspected per block by the
+        /// `on_idle` sweep that removes expired approvals.
+        ///
+        /// The sweep only uses weight left over at the end of a block, so this is
+        /// an upper bound rather than a target.
+        #[pallet::constant]
+        type MaxWhitelistSweep: Get<u32>;
+
+        /// Maximum number of countries in an instrument's allowed or blocked list.
+        ///
+        /// Both lists are read on every [`Pallet::transfer`]. There are fewer than
+        /// 256 ISO 3166-1 countries, so `256` never truncates a prospectus.
+        #[pallet::constant]
+        type MaxCountries: Get<u32>;
+
+        /// Maximum number of vesting schedules per account and instrument.
+        ///
+        /// Every schedule is evaluated on each outgoing transfer of the account.
+        /// Fully vested schedules are pruned when a new one is added.
+        #[pallet::constant]
+        type MaxVestingSchedules: Get<u32>;
+
+        /// Native currency in which coupons and principal are paid, usually
+        /// `pallet_balances`.
+        type Currency: fungible::Mutate<Self::AccountId>;
+
+        /// Identifier from which each instrument's coupon and redemption pot
+        /// accounts are derived.
+        ///
+        /// See [`Pallet::coupon_pot`] and [`Pallet::redemption_pot`].
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Maximum number of coupon dates in an instrument's [`BondTerms`].
+        ///
+        /// A 30-year bond with semi-annual coupons needs 60.
+        #[pallet::constant]
+        type MaxCouponDates: Get<u32>;
+
+        /// Maximum number of holders visited per paged call over an instrument's
+        /// holders:
+        ///
+        /// - paid per [`Pallet::distribute_coupon`] or [`Pallet::distribute_dividend`]
+        ///   call
+        /// - redeemed per admin [`Pallet::redeem_at_maturity`] call
+        /// - rescaled per [`Pallet::split`] or [`Pallet::continue_split`] call
+        ///
+        /// Bounds the weight of each of these calls; larger holder sets are
+        /// handled over several calls. Benchmark the calls with the runtime's value.
+        #[pallet::constant]
+        type MaxHoldersPerPage: Get<u32>;
+
+        /// Tokens (raw value) an instrument may mint without a time lock per window
+        /// of [`Config::MintDelay`] blocks.
+        ///
+        /// A [`Pallet::mint`], [`Pallet::mint_with_schedule`] or [`Pallet::batch_mint`]
+        /// entry taking the window's running total (see [`RecentMints`]) to this
+        /// amount or above is queued and executed [`Config::MintDelay`] blocks later.
+        /// Use `u128::MAX` to execute every mint immediately.
+        #[pallet::constant]
+        type LargeMintThreshold: Get<u128>;
+
+        /// Number of blocks a large mint waits before it is executed.
+        #[pallet::constant]
+        type MintDelay: Get<BlockNumberFor<Self>>;
+
+        /// Origin that can cancel a time-locked mint during the delay.
+        ///
+        /// Should be independent of [`Config::AdminOrigin`], e.g. an audit office or
+        /// a council, so that a compromised admin cannot push a mint through.
+        type VetoOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of time-locked mints falling due in the same block.
+        ///
+        /// Bounds the work done in `on_initialize`.
+        #[pallet::constant]
+        type MaxPendingMints: Get<u32>;
+
+        /// Origin that can raise an instrument's [`MaxSupply`].
+        ///
+        /// The authorised amount comes from the prospectus, so raising it is a
+        /// governance decision (e.g. a parliamentary resolution) rather than a
+        /// day-to-day operation of [`Config::AdminOrigin`].
+        type SupplyCapOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of entries in a [`Pallet::batch_mint`],
+        /// [`Pallet::batch_whitelist`] or [`Pallet::batch_freeze`] call.
+        ///
+        /// Batch weights grow linearly with the number of entries, so this must
+        /// keep a full [`Pallet::batch_mint`] well within a block.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
         /// Weight information for extrinsics in this pallet.
         ///
         /// Weights determine transaction fees and block space allocation.
@@ -210,11 +1132,29 @@ pub mod pallet {
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Instrument Registry
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The asset id that will be assigned to the next created instrument.
+    ///
+    /// Every id strictly below this value refers to an existing instrument; there is
+    /// no way to delete an instrument, so the registry is append-only.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageValue<AssetId>`
+    /// - **Default**: `0` (set to `1` by genesis, which registers [`GENESIS_ASSET_ID`])
+    /// - **Mutability**: Incremented by [`create_instrument`](Pallet::create_instrument)
+    #[pallet::storage]
+    #[pallet::getter(fn next_asset_id)]
+    pub type NextAssetId<T> = StorageValue<_, AssetId, ValueQuery>;
+
     // ═══════════════════════════════════════════════════════════════════════════
     // STORAGE ITEMS - Token Metadata
     // ═══════════════════════════════════════════════════════════════════════════
 
-    /// Human-readable name of the token.
+    /// Human-readable name of each instrument.
     ///
     /// This is the full name displayed in wallets, block explorers, and official documents.
     /// For sovereign bonds, include the issuing country and maturity year.
@@ -235,25 +1175,26 @@ pub mod pallet {
     ///
     /// # Storage
     ///
-    /// - **Type**: `StorageValue` (single global value)
-    /// - **Default**: Empty vector (must be set via genesis or migration)
-    /// - **Mutability**: Set once at genesis; no extrinsic to change
+    /// - **Type**: `StorageMap<AssetId, BoundedVec<u8, 64>>`
+    /// - **Default**: Empty vector
+    /// - **Mutability**: Set at genesis or by [`create_instrument`](Pallet::create_instrument),
+    ///   updated by [`set_metadata`](Pallet::set_metadata)
     ///
     /// # Querying
     ///
     /// ```ignore
     /// // Via RPC (JavaScript)
-    /// const name = await api.query.cladToken.tokenName();
+    /// const name = await api.query.cladToken.tokenName(assetId);
     /// console.log(name.toUtf8()); // "Kazakhstan Sovereign Bond 2030"
     ///
     /// // Via getter function (Rust)
-    /// let name: Vec<u8> = Pallet::<T>::token_name().to_vec();
+    /// let name: Vec<u8> = Pallet::<T>::token_name(asset_id).to_vec();
     /// ```
     #[pallet::storage]
     #[pallet::getter(fn token_name)]
-    pub type TokenName<T> = StorageValue<_, BoundedVec<u8, ConstU32<64>>, ValueQuery>;
+    pub type TokenName<T> = StorageMap<_, Blake2_128Concat, AssetId, TokenNameOf, ValueQuery>;
 
-    /// Trading symbol for the token.
+    /// Trading symbol for each instrument.
     ///
     /// A short identifier used on exchanges, in mobile apps, and for quick reference.
     /// Similar to stock ticker symbols (e.g., AAPL, MSFT).
@@ -274,12 +1215,13 @@ pub mod pallet {
     ///
     /// # Storage
     ///
-    /// - **Type**: `StorageValue` (single global value)
+    /// - **Type**: `StorageMap<AssetId, BoundedVec<u8, 16>>`
     /// - **Default**: Empty vector
-    /// - **Mutability**: Set once at genesis
+    /// - **Mutability**: Set at genesis or by [`create_instrument`](Pallet::create_instrument),
+    ///   updated by [`set_metadata`](Pallet::set_metadata)
     #[pallet::storage]
     #[pallet::getter(fn token_symbol)]
-    pub type TokenSymbol<T> = StorageValue<_, BoundedVec<u8, ConstU32<16>>, ValueQuery>;
+    pub type TokenSymbol<T> = StorageMap<_, Blake2_128Concat, AssetId, TokenSymbolOf, ValueQuery>;
 
     /// Number of decimal places for token amounts.
     ///
@@ -310,27 +1252,90 @@ pub mod pallet {
     ///
     /// # Storage
     ///
-    /// - **Type**: `StorageValue<u8>` (single byte, 0-255)
-    /// - **Default**: `0` (must be set via genesis)
-    /// - **Mutability**: Set once at genesis
+    /// - **Type**: `StorageMap<AssetId, u8>` (single byte, 0-255)
+    /// - **Default**: `0`
+    /// - **Mutability**: Set at genesis or by [`create_instrument`](Pallet::create_instrument);
+    ///   never changed afterwards, since that would rescale every displayed balance
     #[pallet::storage]
     #[pallet::getter(fn decimals)]
-    pub type Decimals<T> = StorageValue<_, u8, ValueQuery>;
+    pub type Decimals<T> = StorageMap<_, Blake2_128Concat, AssetId, u8, ValueQuery>;
+
+    /// Regulatory identifiers and prospectus of each instrument.
+    ///
+    /// Lets registrars and explorers match an instrument to its ISIN, CFI code,
+    /// issuer LEI and currency, and verify the prospectus it was issued under,
+    /// without an off-chain mapping.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, InstrumentMetadata>`
+    /// - **Default**: `None` (no identifiers recorded)
+    /// - **Mutability**: Modified by [`set_metadata`](Pallet::set_metadata)
+    ///
+    /// # Querying
+    ///
+    /// ```ignore
+    /// // Via RPC (JavaScript)
+    /// const metadata = await api.query.cladToken.metadata(assetId);
+    /// console.log(metadata.unwrap().isin.toHuman()); // "KZ2C00012345"
+    /// ```
+    #[pallet::storage]
+    #[pallet::getter(fn metadata)]
+    pub type Metadata<T> =
+        StorageMap<_, Blake2_128Concat, AssetId, InstrumentMetadata, OptionQuery>;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Documents
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Document record stored for an instrument.
+    pub type DocumentOf<T> = Document<BlockNumberFor<T>>;
+
+    /// Legal documents of each instrument, keyed by name (ERC-1643).
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AssetId, BoundedVec<u8, 32>, Document>`
+    /// - **Default**: `None` (no such document)
+    /// - **Mutability**: Modified by [`set_document`](Pallet::set_document) and
+    ///   [`remove_document`](Pallet::remove_document)
+    ///
+    /// # Querying
+    ///
+    /// ```ignore
+    /// // One document (JavaScript)
+    /// const doc = await api.query.cladToken.documents(assetId, "offering-circular");
+    ///
+    /// // All documents of an instrument
+    /// const docs = await api.call.cladTokenApi.documents(assetId);
+    /// ```
+    #[pallet::storage]
+    #[pallet::getter(fn document)]
+    pub type Documents<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        DocumentNameOf,
+        DocumentOf<T>,
+        OptionQuery,
+    >;
 
     // ═══════════════════════════════════════════════════════════════════════════
     // STORAGE ITEMS - Supply & Balances
     // ═══════════════════════════════════════════════════════════════════════════
 
-    /// Total number of tokens in circulation.
+    /// Total number of tokens of each instrument in circulation.
     ///
-    /// This value increases when [`mint`](Pallet::mint) is called and represents
+    /// This value increases when [`mint`](Pallet::mint) is called, decreases on
+    /// [`burn`](Pallet::burn) and [`redeem`](Pallet::redeem), and represents
     /// the sum of all account balances. For sovereign bonds, this typically
     /// equals the total issuance amount of the debt instrument.
     ///
     /// # Invariant
     ///
     /// ```text
-    /// TotalSupply == Σ Balances[account] for all accounts
+    /// TotalSupply[asset] == Σ Balances[asset][account] for all accounts
     /// ```
     ///
     /// This invariant is maintained by the pallet and should never be violated.
@@ -345,40 +1350,58 @@ pub mod pallet {
     ///
     /// # Storage
     ///
-    /// - **Type**: `StorageValue<u128>` (max ~340 undecillion)
+    /// - **Type**: `StorageMap<AssetId, u128>` (max ~340 undecillion per instrument)
     /// - **Default**: `0`
-    /// - **Mutability**: Modified by [`mint`](Pallet::mint)
+    /// - **Mutability**: Increased by [`mint`](Pallet::mint), decreased by
+    ///   [`burn`](Pallet::burn) and [`redeem`](Pallet::redeem)
     ///
     /// # Querying
     ///
     /// ```ignore
     /// // Via RPC (JavaScript)
-    /// const supply = await api.query.cladToken.totalSupply();
-    /// const decimals = await api.query.cladToken.decimals();
+    /// const supply = await api.query.cladToken.totalSupply(assetId);
+    /// const decimals = await api.query.cladToken.decimals(assetId);
     /// const displaySupply = supply.toBigInt() / BigInt(10 ** decimals.toNumber());
     /// ```
     #[pallet::storage]
     #[pallet::getter(fn total_supply)]
-    pub type TotalSupply<T> = StorageValue<_, u128, ValueQuery>;
+    pub type TotalSupply<T> = StorageMap<_, Blake2_128Concat, AssetId, u128, ValueQuery>;
 
-    /// Token balance for each account.
+    /// Authorised amount of each instrument (raw value).
     ///
-    /// Maps account IDs to their token holdings. Accounts not in this map
-    /// have a balance of zero (via `ValueQuery` default).
+    /// [`mint`](Pallet::mint) and [`mint_with_schedule`](Pallet::mint_with_schedule)
+    /// fail with [`Error::SupplyCapExceeded`] if [`TotalSupply`] would end above it.
+    /// Burns and redemptions free room under the cap. A [`split`](Pallet::split)
+    /// rescales the cap by the split ratio, rounding down.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, u128>`
+    /// - **Default**: `None` (capped only by `u128::MAX`)
+    /// - **Mutability**: Set at genesis or by [`create_instrument`](Pallet::create_instrument),
+    ///   raised by [`raise_max_supply`](Pallet::raise_max_supply)
+    #[pallet::storage]
+    #[pallet::getter(fn max_supply)]
+    pub type MaxSupply<T> = StorageMap<_, Blake2_128Concat, AssetId, u128, OptionQuery>;
+
+    /// Token balance for each account, per instrument.
+    ///
+    /// Maps `(asset_id, account)` to the account's holdings of that instrument.
+    /// Accounts not in this map have a balance of zero (via `ValueQuery` default).
     ///
     /// # Access Patterns
     ///
     /// | Operation | Method |
     /// |-----------|--------|
-    /// | Read balance | `Balances::<T>::get(&account)` |
-    /// | Set balance | `Balances::<T>::insert(&account, amount)` |
-    /// | Remove (set to 0) | `Balances::<T>::remove(&account)` |
-    /// | Check exists | `Balances::<T>::contains_key(&account)` |
+    /// | Read balance | `Balances::<T>::get(asset_id, &account)` |
+    /// | Set balance | `Balances::<T>::insert(asset_id, &account, amount)` |
+    /// | Remove (set to 0) | `Balances::<T>::remove(asset_id, &account)` |
+    /// | All holders of an instrument | `Balances::<T>::iter_prefix(asset_id)` |
     ///
     /// # Storage
     ///
-    /// - **Type**: `StorageMap<AccountId, u128>`
-    /// - **Hasher**: `Blake2_128Concat` (secure, key-recoverable)
+    /// - **Type**: `StorageDoubleMap<AssetId, AccountId, u128>`
+    /// - **Hasher**: `Blake2_128Concat` for both keys (secure, key-recoverable)
     /// - **Default**: `0` for missing keys
     ///
     /// # Security Note
@@ -386,6 +1409,8 @@ pub mod pallet {
     /// Balance modifications should only occur through:
     /// - [`mint`](Pallet::mint): Admin creates new tokens
     /// - [`transfer`](Pallet::transfer): User transfers tokens
+    /// - [`forced_transfer`](Pallet::forced_transfer): Admin moves tokens (court orders)
+    /// - [`burn`](Pallet::burn) / [`redeem`](Pallet::redeem): Tokens are destroyed
     /// - Genesis configuration: Initial distribution
     ///
     /// Direct storage manipulation outside these paths breaks the `TotalSupply` invariant.
@@ -394,22 +1419,31 @@ pub mod pallet {
     ///
     /// ```ignore
     /// // Via RPC (JavaScript)
-    /// const balance = await api.query.cladToken.balances(accountId);
+    /// const balance = await api.query.cladToken.balances(assetId, accountId);
     ///
     /// // Via getter (Rust)
-    /// let balance: u128 = Pallet::<T>::balance_of(&account);
+    /// let balance: u128 = Pallet::<T>::balance_of(asset_id, &account);
     /// ```
     #[pallet::storage]
     #[pallet::getter(fn balance_of)]
-    pub type Balances<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+    pub type Balances<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        u128,
+        ValueQuery,
+    >;
 
     // ═══════════════════════════════════════════════════════════════════════════
     // STORAGE ITEMS - Compliance Controls
     // ═══════════════════════════════════════════════════════════════════════════
 
-    /// Accounts that are frozen and cannot send transfers.
+    /// Accounts that are frozen and cannot send transfers of an instrument.
     ///
-    /// Frozen accounts can still **receive** tokens but cannot **send** them.
+    /// Freezing is per instrument: an account frozen on one bond series can still
+    /// move its holdings of another. Frozen accounts can still **receive** tokens but cannot **send** them.
     /// This allows compliance officers to halt suspicious activity while
     /// preserving the account's ability to receive court-ordered returns.
     ///
@@ -435,7 +1469,7 @@ pub mod pallet {
     ///
     /// # Storage
     ///
-    /// - **Type**: `StorageMap<AccountId, bool>`
+    /// - **Type**: `StorageDoubleMap<AssetId, AccountId, bool>`
     /// - **Hasher**: `Blake2_128Concat`
     /// - **Default**: `false` (not frozen)
     /// - **Mutability**: Modified by [`freeze`](Pallet::freeze) / [`unfreeze`](Pallet::unfreeze)
@@ -446,7 +1480,79 @@ pub mod pallet {
     /// which is more storage-efficient than storing `false` for all unfrozen accounts.
     #[pallet::storage]
     #[pallet::getter(fn is_frozen)]
-    pub type Frozen<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+    pub type Frozen<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+    /// Portion of an account's balance that is frozen (ERC-3643 partial freeze).
+    ///
+    /// Unlike [`Frozen`], which blocks every outgoing transfer, a partial freeze
+    /// only locks `amount` tokens. The holder can still move the rest, e.g. while
+    /// part of the position is pledged as collateral under a margin agreement.
+    ///
+    /// # Spendable Balance
+    ///
+    /// ```text
+    /// free_balance = Balances[asset][account] - FrozenAmount[asset][account]
+    /// ```
+    ///
+    /// [`transfer`](Pallet::transfer), [`burn`](Pallet::burn) and
+    /// [`redeem`](Pallet::redeem) may only spend the free balance.
+    /// [`forced_transfer`](Pallet::forced_transfer) may dip into frozen tokens; the
+    /// frozen amount is then reduced so it never exceeds the remaining balance.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AssetId, AccountId, u128>`
+    /// - **Hasher**: `Blake2_128Concat`
+    /// - **Default**: `0` (nothing frozen)
+    /// - **Mutability**: Modified by [`freeze_partial`](Pallet::freeze_partial) /
+    ///   [`unfreeze_partial`](Pallet::unfreeze_partial)
+    ///
+    /// # Implementation Note
+    ///
+    /// Entries are removed when the frozen amount drops to zero.
+    #[pallet::storage]
+    #[pallet::getter(fn frozen_amount)]
+    pub type FrozenAmount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        u128,
+        ValueQuery,
+    >;
+
+    /// Instruments that are paused (global kill switch).
+    ///
+    /// While an instrument is paused no holder can move it, regardless of their
+    /// whitelist or freeze status. This is the incident-response tool: freezing
+    /// every holder one by one is not realistic during an attack.
+    ///
+    /// # Effect
+    ///
+    /// | Extrinsic | While paused |
+    /// |-----------|--------------|
+    /// | [`transfer`](Pallet::transfer) | Blocked |
+    /// | [`redeem`](Pallet::redeem) | Blocked |
+    /// | [`mint`](Pallet::mint) | Blocked if [`Config::PauseBlocksMinting`] is `true` |
+    /// | Admin remediation ([`burn`](Pallet::burn), [`forced_transfer`](Pallet::forced_transfer), freezes) | Allowed |
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, bool>`
+    /// - **Default**: `false` (not paused)
+    /// - **Mutability**: Modified by [`pause`](Pallet::pause) / [`unpause`](Pallet::unpause)
+    #[pallet::storage]
+    #[pallet::getter(fn is_paused)]
+    pub type Paused<T> = StorageMap<_, Blake2_128Concat, AssetId, bool, ValueQuery>;
 
     // ═══════════════════════════════════════════════════════════════════════════
     // STORAGE ITEMS - Admin Configuration
@@ -456,7 +1562,8 @@ pub mod pallet {
     ///
     /// This storage item allows runtime admin rotation without requiring a full
     /// runtime upgrade. When set, this account (typically a multi-sig address)
-    /// can perform admin operations like minting, freezing, and whitelisting.
+    /// can perform admin operations like registering instruments and appointing
+    /// role admins.
     ///
     /// # Admin Resolution Order
     ///
@@ -494,10 +1601,12 @@ pub mod pallet {
     #[pallet::getter(fn admin)]
     pub type Admin<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
-    /// Accounts approved to participate in token transfers.
+    /// Accounts approved to participate in token transfers, per instrument.
     ///
     /// The whitelist implements the KYC (Know Your Customer) requirement of ERC-3643.
-    /// Both sender and receiver must be whitelisted for a transfer to succeed.
+    /// Both sender and receiver must be whitelisted on the instrument for a transfer
+    /// to succeed; eligibility can differ between series (e.g. a retail tranche versus
+    /// a qualified-investor-only private placement).
     ///
     /// # ERC-3643 Compliance
     ///
@@ -506,24 +1615,31 @@ pub mod pallet {
     /// > "Transfers SHALL be restricted to verified investors who have been
     /// > validated by an authorized identity registry."
     ///
-    /// The whitelist serves as this identity registry in a simplified form.
+    /// The whitelist is the registration step of this identity registry. Instruments
+    /// can additionally require claims from trusted issuers, see [`RequiredClaimTopics`].
     ///
     /// # Typical Workflow
     ///
     /// ```text
     /// 1. Investor submits KYC documents off-chain
     /// 2. Compliance officer verifies identity
-    /// 3. Admin calls add_to_whitelist(investor)
+    /// 3. Admin calls add_to_whitelist(asset_id, investor)
     /// 4. Investor can now receive/send tokens
     /// ```
     ///
     /// # Storage
     ///
-    /// - **Type**: `StorageMap<AccountId, bool>`
+    /// - **Type**: `StorageDoubleMap<AssetId, AccountId, WhitelistEntry>`
     /// - **Hasher**: `Blake2_128Concat`
-    /// - **Default**: `false` (not whitelisted)
+    /// - **Default**: `None` (not whitelisted)
     /// - **Mutability**: Modified by [`add_to_whitelist`](Pallet::add_to_whitelist) /
-    ///   [`remove_from_whitelist`](Pallet::remove_from_whitelist)
+    ///   [`remove_from_whitelist`](Pallet::remove_from_whitelist); expired entries
+    ///   are removed by the `on_idle` sweep
+    ///
+    /// # Expiry
+    ///
+    /// An entry whose `expires_at` has been reached no longer counts as whitelisted,
+    /// even before the sweep removes it; transfers fail with [`Error::KycExpired`].
     ///
     /// # Security Note
     ///
@@ -534,518 +1650,5118 @@ pub mod pallet {
     /// # Querying
     ///
     /// ```ignore
-    /// // Check if account is whitelisted (JavaScript)
-    /// const isWhitelisted = await api.query.cladToken.whitelist(accountId);
+    /// // Fetch the entry (JavaScript); `expiresAt` is null for permanent approvals
+    /// const entry = await api.query.cladToken.whitelist(assetId, accountId);
     ///
-    /// // Rust getter
-    /// let is_whitelisted: bool = Pallet::<T>::whitelist(&account);
+    /// // Rust helper, taking expiry into account
+    /// let is_whitelisted: bool = Pallet::<T>::is_whitelisted(asset_id, &account);
     /// ```
     #[pallet::storage]
-    #[pallet::getter(fn whitelist)]
-    pub type Whitelist<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+    #[pallet::getter(fn whitelist_entry)]
+    pub type Whitelist<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        WhitelistEntryOf<T>,
+        OptionQuery,
+    >;
+
+    /// Whitelist entry stored for an account.
+    pub type WhitelistEntryOf<T> = WhitelistEntry<BlockNumberFor<T>>;
+
+    /// Last whitelist entry inspected by the `on_idle` expiry sweep.
+    ///
+    /// The sweep resumes after this key in the next block with spare weight, and
+    /// clears it once the whole map has been walked.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageValue<(AssetId, AccountId)>`
+    /// - **Default**: `None` (start from the beginning)
+    #[pallet::storage]
+    pub type WhitelistSweepCursor<T: Config> =
+        StorageValue<_, (AssetId, T::AccountId), OptionQuery>;
 
     // ═══════════════════════════════════════════════════════════════════════════
-    // EVENTS
+    // STORAGE ITEMS - Jurisdictions
     // ═══════════════════════════════════════════════════════════════════════════
 
-    /// Events emitted by this pallet.
+    /// Bounded list of country codes.
+    pub type CountriesOf<T> = BoundedVec<CountryCode, <T as Config>::MaxCountries>;
+
+    /// Jurisdiction of each investor, per instrument.
     ///
-    /// Events provide an audit trail for off-chain systems (block explorers, mobile apps,
-    /// compliance dashboards) to track token operations. Each event is stored in the
-    /// block's event log and can be queried via RPC.
+    /// Recorded by [`add_to_whitelist`](Pallet::add_to_whitelist) alongside the KYC
+    /// approval. The entry outlives the whitelist approval: an investor removed
+    /// from the whitelist still holds tokens and is still counted in
+    /// [`HoldersPerCountry`].
     ///
-    /// # Indexing for Off-Chain Systems
+    /// # Storage
     ///
-    /// Events are the primary mechanism for off-chain systems to track token activity.
-    /// Subscribe to events via WebSocket or poll recent blocks:
+    /// - **Type**: `StorageDoubleMap<AssetId, AccountId, CountryCode>`
+    /// - **Hasher**: `Blake2_128Concat`
+    /// - **Default**: `None` (jurisdiction not recorded)
+    /// - **Mutability**: Modified by [`add_to_whitelist`](Pallet::add_to_whitelist)
+    #[pallet::storage]
+    #[pallet::getter(fn investor_country)]
+    pub type InvestorCountry<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        CountryCode,
+        OptionQuery,
+    >;
+
+    /// Jurisdictions allowed to receive each instrument.
     ///
-    /// ```text
-    /// // JavaScript: Subscribe to all CladToken events
-    /// api.query.system.events((events) => {
-    ///     events.forEach((record) => {
-    ///         if (record.event.section === 'cladToken') {
-    ///             console.log(record.event.method, record.event.data);
-    ///         }
-    ///     });
-    /// });
-    /// ```
+    /// An empty list allows every jurisdiction. A non-empty list makes
+    /// [`transfer`](Pallet::transfer) reject receivers whose country is not listed,
+    /// or not recorded, with [`Error::CountryNotAllowed`].
     ///
-    /// # Event Categories
+    /// # Storage
     ///
-    /// | Category | Events | Use Case |
-    /// |----------|--------|----------|
-    /// | Transfer | `Transferred`, `Minted` | Balance tracking, portfolio updates |
-    /// | Compliance | `Frozen`, `Unfrozen` | Risk monitoring, alerts |
-    /// | Access | `Whitelisted`, `RemovedFromWhitelist` | KYC status tracking |
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// Tokens were transferred between accounts.
-        ///
-        /// This event is emitted by [`Pallet::transfer`] when tokens move between
-        /// whitelisted, non-frozen accounts.
+    /// - **Type**: `StorageMap<AssetId, BoundedVec<CountryCode, MaxCountries>>`
+    /// - **Default**: Empty (all jurisdictions allowed)
+    /// - **Mutability**: Modified by [`set_allowed_countries`](Pallet::set_allowed_countries)
+    #[pallet::storage]
+    #[pallet::getter(fn allowed_countries)]
+    pub type AllowedCountries<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, CountriesOf<T>, ValueQuery>;
+
+    /// Jurisdictions barred from receiving each instrument.
+    ///
+    /// Typically the sanctioned or offering-restricted jurisdictions listed in the
+    /// prospectus. [`transfer`](Pallet::transfer) rejects receivers in a listed
+    /// country with [`Error::CountryBlocked`]. Checked before the allow list.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, BoundedVec<CountryCode, MaxCountries>>`
+    /// - **Default**: Empty (no jurisdiction blocked)
+    /// - **Mutability**: Modified by [`set_blocked_countries`](Pallet::set_blocked_countries)
+    #[pallet::storage]
+    #[pallet::getter(fn blocked_countries)]
+    pub type BlockedCountries<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, CountriesOf<T>, ValueQuery>;
+
+    /// Number of accounts with a non-zero balance, per instrument and jurisdiction.
+    ///
+    /// Only investors with a recorded [`InvestorCountry`] are counted. Kept up to
+    /// date on every mint, burn and transfer, and when an investor's country
+    /// changes.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AssetId, CountryCode, u32>`
+    /// - **Default**: `0`
+    ///
+    /// # Querying
+    ///
+    /// ```ignore
+    /// // Holders of the genesis bond resident in Kazakhstan (JavaScript)
+    /// const holders = await api.query.cladToken.holdersPerCountry(0, 398);
+    /// ```
+    #[pallet::storage]
+    #[pallet::getter(fn holders_per_country)]
+    pub type HoldersPerCountry<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        CountryCode,
+        u32,
+        ValueQuery,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Holders
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Number of accounts with a non-zero balance of each instrument.
+    ///
+    /// Incremented when a balance moves from zero to non-zero and decremented when
+    /// it returns to zero. Also exposed through the `CladTokenApi` runtime API so
+    /// dashboards need not scan [`Balances`].
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, u32>`
+    /// - **Default**: `0`
+    /// - **Mutability**: Modified by every mint, burn and transfer
+    #[pallet::storage]
+    #[pallet::getter(fn holder_count)]
+    pub type HolderCount<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, u32, ValueQuery>;
+
+    /// Maximum number of holders of each instrument.
+    ///
+    /// Private placements are typically limited to a fixed number of investors.
+    /// [`mint`](Pallet::mint) and [`transfer`](Pallet::transfer) fail with
+    /// [`Error::TooManyHolders`] if they would add a holder beyond the cap. Lowering
+    /// the cap below [`HolderCount`] only blocks new holders; nobody is forced out.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, u32>`
+    /// - **Default**: `None` (no cap)
+    /// - **Mutability**: Modified by [`set_max_holders`](Pallet::set_max_holders)
+    #[pallet::storage]
+    #[pallet::getter(fn max_holders)]
+    pub type MaxHolders<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, u32, OptionQuery>;
+
+    /// Maximum balance any single account may hold of each instrument.
+    ///
+    /// [`mint`](Pallet::mint) and [`transfer`](Pallet::transfer) fail with
+    /// [`Error::HoldingLimitExceeded`] if the receiver would end above the limit.
+    /// A [`HoldingLimit::ShareOfSupply`] limit is evaluated against the supply
+    /// after the operation. Accounts with an [`AccountHoldingLimit`] use that
+    /// instead.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, HoldingLimit>`
+    /// - **Default**: `None` (no limit)
+    /// - **Mutability**: Modified by
+    ///   [`set_max_balance_per_holder`](Pallet::set_max_balance_per_holder);
+    ///   an absolute limit is rescaled by [`split`](Pallet::split)
+    #[pallet::storage]
+    #[pallet::getter(fn max_balance_per_holder)]
+    pub type MaxBalancePerHolder<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, HoldingLimit, OptionQuery>;
+
+    /// Per-account override of [`MaxBalancePerHolder`].
+    ///
+    /// Used for anchor investors with a larger allocation, and for the treasury,
+    /// which must be able to receive the whole issue before distributing it
+    /// (`HoldingLimit::ShareOfSupply(Perbill::one())`).
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AssetId, AccountId, HoldingLimit>`
+    /// - **Default**: `None` (instrument-wide limit applies)
+    /// - **Mutability**: Modified by
+    ///   [`set_account_holding_limit`](Pallet::set_account_holding_limit);
+    ///   absolute limits are rescaled by [`split`](Pallet::split)
+    #[pallet::storage]
+    #[pallet::getter(fn account_holding_limit)]
+    pub type AccountHoldingLimit<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        HoldingLimit,
+        OptionQuery,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Vesting
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Vesting schedule type used in calls.
+    pub type VestingScheduleOf<T> = VestingSchedule<BlockNumberFor<T>>;
+
+    /// Vesting lock stored for an account.
+    pub type VestingLockOf<T> = VestingLock<BlockNumberFor<T>>;
+
+    /// Bounded list of an account's vesting locks.
+    pub type VestingLocksOf<T> = BoundedVec<VestingLockOf<T>, <T as Config>::MaxVestingSchedules>;
+
+    /// Lock-ups on tokens minted with [`mint_with_schedule`](Pallet::mint_with_schedule).
+    ///
+    /// The still-locked part of every lock is excluded from the account's
+    /// spendable balance, on top of any [`FrozenAmount`].
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AssetId, AccountId, BoundedVec<VestingLock, MaxVestingSchedules>>`
+    /// - **Default**: Empty (nothing locked)
+    /// - **Mutability**: Modified by [`mint_with_schedule`](Pallet::mint_with_schedule)
+    ///
+    /// # Querying
+    ///
+    /// Use the `CladTokenApi::vesting_balance` runtime API for the locked and
+    /// unlocked amounts at the current block.
+    #[pallet::storage]
+    #[pallet::getter(fn vesting)]
+    pub type Vesting<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        VestingLocksOf<T>,
+        ValueQuery,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Bond Lifecycle
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Bond terms type used in calls and storage.
+    pub type BondTermsOf<T> = BondTerms<BlockNumberFor<T>, <T as Config>::MaxCouponDates>;
+
+    /// Coupon payment progress stored for an instrument.
+    pub type CouponProgressOf<T> = CouponProgress<<T as frame_system::Config>::AccountId>;
+
+    /// Bond terms of each instrument.
+    ///
+    /// Instruments without terms (equity, or bonds not yet configured) pay no
+    /// coupons.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, BondTerms>`
+    /// - **Default**: `None` (no bond terms)
+    /// - **Mutability**: Modified by [`set_bond_terms`](Pallet::set_bond_terms)
+    #[pallet::storage]
+    #[pallet::getter(fn bond_terms)]
+    pub type Bonds<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, BondTermsOf<T>, OptionQuery>;
+
+    /// Index into [`BondTerms::coupon_dates`] of each instrument's next unpaid
+    /// coupon.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, u32>`
+    /// - **Default**: `0` (no coupon paid yet)
+    /// - **Mutability**: Incremented when [`distribute_coupon`](Pallet::distribute_coupon)
+    ///   has paid every holder
+    #[pallet::storage]
+    #[pallet::getter(fn next_coupon)]
+    pub type NextCoupon<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, u32, ValueQuery>;
+
+    /// Coupon payment in progress for each instrument.
+    ///
+    /// While an entry exists, mints, burns and transfers of the instrument fail
+    /// with [`Error::CouponDistributionInProgress`], so every holder is paid on
+    /// the balance held when the payment started.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, CouponProgress>`
+    /// - **Default**: `None` (no payment in progress)
+    /// - **Mutability**: Modified by [`distribute_coupon`](Pallet::distribute_coupon)
+    #[pallet::storage]
+    #[pallet::getter(fn coupon_distribution)]
+    pub type CouponDistribution<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, CouponProgressOf<T>, OptionQuery>;
+
+    /// Coupons that could not be paid to a holder, by coupon index.
+    ///
+    /// The native currency stays in [`Pallet::coupon_pot`] until
+    /// [`claim_coupon`](Pallet::claim_coupon) pays it out.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageNMap<(AssetId, u32, AccountId), u128>`
+    /// - **Default**: `None` (nothing owed)
+    /// - **Mutability**: Written by [`distribute_coupon`](Pallet::distribute_coupon)
+    ///   when a payment fails, removed by [`claim_coupon`](Pallet::claim_coupon)
+    #[pallet::storage]
+    #[pallet::getter(fn unpaid_coupon)]
+    pub type UnpaidCoupons<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, AssetId>,
+            NMapKey<Blake2_128Concat, u32>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        u128,
+        OptionQuery,
+    >;
+
+    /// Sum of [`UnpaidCoupons`] per instrument, kept out of the funds available
+    /// for later coupons.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, u128>`
+    /// - **Default**: `0`
+    /// - **Mutability**: Modified alongside [`UnpaidCoupons`]
+    #[pallet::storage]
+    pub type UnpaidCouponTotal<T> = StorageMap<_, Blake2_128Concat, AssetId, u128, ValueQuery>;
+
+    /// Last holder visited by the admin's paged
+    /// [`redeem_at_maturity`](Pallet::redeem_at_maturity); the next call resumes
+    /// after it.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, AccountId>`
+    /// - **Default**: `None` (start from the first holder)
+    /// - **Mutability**: Modified by [`redeem_at_maturity`](Pallet::redeem_at_maturity)
+    #[pallet::storage]
+    pub type RedemptionCursor<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, T::AccountId, OptionQuery>;
+
+    /// Instruments whose principal has been fully repaid.
+    ///
+    /// Set by [`redeem_at_maturity`](Pallet::redeem_at_maturity) once the supply
+    /// reaches zero. A matured instrument can no longer be minted or transferred.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, bool>`
+    /// - **Default**: `false` (outstanding)
+    /// - **Mutability**: Set once, never cleared
+    #[pallet::storage]
+    #[pallet::getter(fn is_matured)]
+    pub type Matured<T> = StorageMap<_, Blake2_128Concat, AssetId, bool, ValueQuery>;
+
+    /// Principal withheld from holders whose tokens were burned at maturity while
+    /// frozen, partially frozen or locked by vesting, or who could not be paid.
+    ///
+    /// The native currency stays in [`Pallet::redemption_pot`] until
+    /// [`claim_matured_principal`](Pallet::claim_matured_principal) pays it out.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AssetId, AccountId, u128>`
+    /// - **Default**: `None` (nothing withheld)
+    /// - **Mutability**: Modified by [`redeem_at_maturity`](Pallet::redeem_at_maturity)
+    ///   and [`claim_matured_principal`](Pallet::claim_matured_principal)
+    #[pallet::storage]
+    #[pallet::getter(fn maturity_escrow)]
+    pub type MaturityEscrow<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        u128,
+        OptionQuery,
+    >;
+
+    /// Sum of [`MaturityEscrow`] per instrument, kept out of the funds available
+    /// for redemption.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, u128>`
+    /// - **Default**: `0`
+    /// - **Mutability**: Modified alongside [`MaturityEscrow`]
+    #[pallet::storage]
+    pub type MaturityEscrowTotal<T> = StorageMap<_, Blake2_128Concat, AssetId, u128, ValueQuery>;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Snapshots
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Id of the latest snapshot of each instrument.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, SnapshotId>`
+    /// - **Default**: `0` (no snapshot taken)
+    /// - **Mutability**: Incremented by [`snapshot`](Pallet::snapshot)
+    #[pallet::storage]
+    #[pallet::getter(fn current_snapshot_id)]
+    pub type CurrentSnapshotId<T> =
+        StorageMap<_, Blake2_128Concat, AssetId, SnapshotId, ValueQuery>;
+
+    /// Balances recorded for snapshots.
+    ///
+    /// An entry `(asset, id, account)` holds the account's balance before its
+    /// first change after snapshot `id` was taken. That is its balance at
+    /// snapshot `id` and at every earlier snapshot since its previous entry. An
+    /// account without a later entry still has the balance it had at the
+    /// snapshot.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageNMap<(AssetId, SnapshotId, AccountId), u128>`
+    /// - **Default**: `None` (no change since the snapshot)
+    /// - **Mutability**: Written by every mint, burn and transfer
+    ///
+    /// # Querying
+    ///
+    /// Use [`Pallet::balance_at`] or the `CladTokenApi::balance_at` runtime API.
+    #[pallet::storage]
+    pub type SnapshotBalances<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, AssetId>,
+            NMapKey<Blake2_128Concat, SnapshotId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        u128,
+        OptionQuery,
+    >;
+
+    /// Total supply recorded for snapshots, following the same rule as
+    /// [`SnapshotBalances`].
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AssetId, SnapshotId, u128>`
+    /// - **Default**: `None` (no change since the snapshot)
+    /// - **Mutability**: Written by every mint and burn
+    #[pallet::storage]
+    pub type SnapshotTotalSupply<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        SnapshotId,
+        u128,
+        OptionQuery,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Dividends
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Dividend type used in storage.
+    pub type DividendOf<T> = Dividend<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// Id the next dividend of each instrument will receive.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, DividendId>`
+    /// - **Default**: `0` (no dividend declared)
+    /// - **Mutability**: Incremented by [`declare_dividend`](Pallet::declare_dividend)
+    #[pallet::storage]
+    #[pallet::getter(fn next_dividend_id)]
+    pub type NextDividendId<T> = StorageMap<_, Blake2_128Concat, AssetId, DividendId, ValueQuery>;
+
+    /// Declared dividends and their payment progress.
+    ///
+    /// Entries are kept after the dividend is reclaimed, as the record of what
+    /// was paid, escrowed and returned.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AssetId, DividendId, Dividend>`
+    /// - **Default**: `None` (no such dividend)
+    /// - **Mutability**: Created by [`declare_dividend`](Pallet::declare_dividend),
+    ///   updated by every payment
+    #[pallet::storage]
+    #[pallet::getter(fn dividend)]
+    pub type Dividends<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        DividendId,
+        DividendOf<T>,
+        OptionQuery,
+    >;
+
+    /// How each holder's dividend share was settled.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageNMap<(AssetId, DividendId, AccountId), DividendClaim>`
+    /// - **Default**: `None` (share not yet settled)
+    /// - **Mutability**: Written when a share is paid or escrowed
+    #[pallet::storage]
+    pub type DividendClaims<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, AssetId>,
+            NMapKey<Blake2_128Concat, DividendId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        DividendClaim,
+        OptionQuery,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Corporate Actions
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Split progress stored for an instrument.
+    pub type SplitProgressOf<T> = SplitProgress<<T as frame_system::Config>::AccountId>;
+
+    /// Split being applied to each instrument.
+    ///
+    /// While an entry exists, balances of the instrument mix pre- and post-split
+    /// units, so mints, burns, transfers, partial freezes, coupons, redemptions
+    /// and snapshots fail with [`Error::SplitInProgress`].
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, SplitProgress>`
+    /// - **Default**: `None` (no split in progress)
+    /// - **Mutability**: Modified by [`split`](Pallet::split) and
+    ///   [`continue_split`](Pallet::continue_split)
+    #[pallet::storage]
+    #[pallet::getter(fn split_in_progress)]
+    pub type SplitInProgress<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, SplitProgressOf<T>, OptionQuery>;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Account Recovery
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Addresses recovered with [`recover_account`](Pallet::recover_account),
+    /// mapped to the address their position moved to.
+    ///
+    /// A recovered address is permanently blocked on the instrument: it fails
+    /// every identity check with [`Error::AccountRecovered`] and cannot be
+    /// whitelisted or minted to again.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AssetId, AccountId, AccountId>`
+    /// - **Default**: `None` (not recovered)
+    /// - **Mutability**: Set once by [`recover_account`](Pallet::recover_account),
+    ///   never cleared
+    #[pallet::storage]
+    #[pallet::getter(fn recovered_to)]
+    pub type RecoveredAccounts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    /// Recovery targets, mapped to the lost address whose position they took over.
+    ///
+    /// The reverse of [`RecoveredAccounts`], used by
+    /// [`claim_dividend`](Pallet::claim_dividend) to collect the lost address's
+    /// shares. Following it repeatedly walks back through earlier recoveries.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AssetId, AccountId, AccountId>`
+    /// - **Default**: `None` (not a recovery target)
+    /// - **Mutability**: Set once by [`recover_account`](Pallet::recover_account),
+    ///   never cleared
+    #[pallet::storage]
+    #[pallet::getter(fn recovered_from)]
+    pub type RecoveredFrom<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Time-Locked Mints
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Time-locked mint as stored.
+    pub type PendingMintOf<T> =
+        PendingMint<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// Ids of the time-locked mints due at one block.
+    pub type PendingMintIdsOf<T> = BoundedVec<PendingMintId, <T as Config>::MaxPendingMints>;
+
+    /// Id assigned to the next time-locked mint.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageValue<PendingMintId>`
+    /// - **Default**: `0`
+    /// - **Mutability**: Incremented whenever a large mint is queued
+    #[pallet::storage]
+    pub type NextPendingMintId<T> = StorageValue<_, PendingMintId, ValueQuery>;
+
+    /// Large mints waiting out [`Config::MintDelay`].
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<PendingMintId, PendingMint>`
+    /// - **Default**: `None` (executed, cancelled or unknown)
+    /// - **Mutability**: Added by [`mint`](Pallet::mint) /
+    ///   [`mint_with_schedule`](Pallet::mint_with_schedule), removed by
+    ///   [`cancel_mint`](Pallet::cancel_mint) or on execution
+    #[pallet::storage]
+    #[pallet::getter(fn pending_mint)]
+    pub type PendingMints<T: Config> =
+        StorageMap<_, Blake2_128Concat, PendingMintId, PendingMintOf<T>, OptionQuery>;
+
+    /// Time-locked mints due at each block, executed in `on_initialize`.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<BlockNumber, BoundedVec<PendingMintId, MaxPendingMints>>`
+    /// - **Default**: Empty
+    /// - **Mutability**: Appended to when a large mint is queued, taken when the
+    ///   block starts
+    #[pallet::storage]
+    pub type MintQueue<T: Config> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, PendingMintIdsOf<T>, ValueQuery>;
+
+    /// Tokens of each instrument minted without a time lock in the current window.
+    ///
+    /// A window starts with the first immediate mint after the previous one ended
+    /// and lasts [`Config::MintDelay`] blocks.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, MintWindow>`
+    /// - **Default**: `None` (nothing minted yet)
+    /// - **Mutability**: Updated by every mint executed without a time lock
+    #[pallet::storage]
+    #[pallet::getter(fn recent_mints)]
+    pub type RecentMints<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, MintWindow<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Number of [`PendingMints`] of each instrument.
+    ///
+    /// A split is refused while it is non-zero, since queued amounts are in
+    /// pre-split units.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, u32>`
+    /// - **Default**: `0`
+    /// - **Mutability**: Incremented when a large mint is queued, decremented when
+    ///   it is executed, dropped or cancelled
+    #[pallet::storage]
+    #[pallet::getter(fn pending_mint_count)]
+    pub type PendingMintCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, u32, ValueQuery>;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Roles
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Accounts holding each operator role.
+    ///
+    /// A role lets a signed account perform the calls listed in [`roles`]; the
+    /// admin origin cannot perform them. Roles are chain-wide, not per instrument.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<Role, AccountId, bool>`
+    /// - **Default**: `false` (role not held)
+    /// - **Mutability**: Modified by [`grant_role`](Pallet::grant_role) /
+    ///   [`revoke_role`](Pallet::revoke_role), and moved by
+    ///   [`set_admin`](Pallet::set_admin)
+    #[pallet::storage]
+    #[pallet::getter(fn has_role)]
+    pub type Roles<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Role,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // STORAGE ITEMS - Identity Registry
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Bounded list of claim topics.
+    pub type ClaimTopicsOf<T> = BoundedVec<ClaimTopic, <T as Config>::MaxClaimTopics>;
+
+    /// Claim stored for an account.
+    pub type ClaimOf<T> = Claim<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// Claim issuers trusted by the admin, and the topics each may attest.
+    ///
+    /// Mirrors the ERC-3643 `TrustedIssuersRegistry`. An issuer is typically a KYC
+    /// provider or transfer agent. Removing an issuer, or dropping a topic from its
+    /// list, immediately invalidates every claim it attested for that topic.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AccountId, BoundedVec<ClaimTopic, MaxClaimTopics>>`
+    /// - **Default**: Empty (not trusted)
+    /// - **Mutability**: Modified by [`add_trusted_issuer`](Pallet::add_trusted_issuer) /
+    ///   [`remove_trusted_issuer`](Pallet::remove_trusted_issuer)
+    #[pallet::storage]
+    #[pallet::getter(fn trusted_issuer_topics)]
+    pub type TrustedIssuers<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ClaimTopicsOf<T>, ValueQuery>;
+
+    /// Claims held by each account, one per topic.
+    ///
+    /// Identity is chain-wide: an investor verified once can hold any instrument
+    /// whose required topics they satisfy. A claim is valid while the current block
+    /// is below `expires_at` and its issuer is still trusted for the topic.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageDoubleMap<AccountId, ClaimTopic, Claim>`
+    /// - **Hasher**: `Blake2_128Concat`
+    /// - **Default**: `None` (no claim)
+    /// - **Mutability**: Modified by [`add_claim`](Pallet::add_claim) /
+    ///   [`remove_claim`](Pallet::remove_claim)
+    #[pallet::storage]
+    #[pallet::getter(fn claim)]
+    pub type Claims<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        ClaimTopic,
+        ClaimOf<T>,
+        OptionQuery,
+    >;
+
+    /// Claim topics an account must hold to send or receive an instrument.
+    ///
+    /// Mirrors the ERC-3643 `ClaimTopicsRegistry`. Together with [`Whitelist`] this
+    /// forms the identity check applied by [`transfer`](Pallet::transfer):
+    ///
+    /// ```text
+    /// verified(asset, account) = Whitelist[asset][account]
+    ///     && for every topic in RequiredClaimTopics[asset]:
+    ///            Claims[account][topic] exists, has not expired,
+    ///            and its issuer is trusted for topic
+    /// ```
+    ///
+    /// An instrument with no required topics only checks the whitelist.
+    ///
+    /// # Storage
+    ///
+    /// - **Type**: `StorageMap<AssetId, BoundedVec<ClaimTopic, MaxClaimTopics>>`
+    /// - **Default**: Empty (whitelist only)
+    /// - **Mutability**: Modified by
+    ///   [`set_required_claim_topics`](Pallet::set_required_claim_topics)
+    #[pallet::storage]
+    #[pallet::getter(fn required_claim_topics)]
+    pub type RequiredClaimTopics<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, ClaimTopicsOf<T>, ValueQuery>;
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // EVENTS
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Events emitted by this pallet.
+    ///
+    /// Events provide an audit trail for off-chain systems (block explorers, mobile apps,
+    /// compliance dashboards) to track token operations. Each event is stored in the
+    /// block's event log and can be queried via RPC.
+    ///
+    /// # Indexing for Off-Chain Systems
+    ///
+    /// Events are the primary mechanism for off-chain systems to track token activity.
+    /// Subscribe to events via WebSocket or poll recent blocks:
+    ///
+    /// ```text
+    /// // JavaScript: Subscribe to all CladToken events
+    /// api.query.system.events((events) => {
+    ///     events.forEach((record) => {
+    ///         if (record.event.section === 'cladToken') {
+    ///             console.log(record.event.method, record.event.data);
+    ///         }
+    ///     });
+    /// });
+    /// ```
+    ///
+    /// # Event Categories
+    ///
+    /// | Category | Events | Use Case |
+    /// |----------|--------|----------|
+    /// | Registry | `InstrumentCreated`, `MetadataUpdated`, `MaxSupplyRaised` | Discovering new bond series, identifiers, authorised amounts |
+    /// | Documents | `DocumentUpdated`, `DocumentRemoved` | Offering circulars and filings (ERC-1643) |
+    /// | Transfer | `Transferred`, `Minted` | Balance tracking, portfolio updates |
+    /// | Supply | `Burned`, `Redeemed` | Buybacks, early redemptions, supply reconciliation |
+    /// | Compliance | `Frozen`, `Unfrozen`, `TokensFrozen`, `TokensUnfrozen`, `ForcedTransfer` | Risk monitoring, alerts |
+    /// | Incident | `Paused`, `Unpaused` | Kill-switch monitoring |
+    /// | Access | `Whitelisted`, `RemovedFromWhitelist`, `WhitelistExpired`, `InvestorCountrySet` | KYC status tracking |
+    /// | Jurisdiction | `AllowedCountriesSet`, `BlockedCountriesSet` | Offering restriction changes |
+    /// | Holders | `MaxHoldersSet`, `MaxBalancePerHolderSet`, `AccountHoldingLimitSet` | Holder and concentration cap changes |
+    /// | Vesting | `VestingScheduleAdded` | Lock-up tracking |
+    /// | Bond | `BondTermsSet`, `CouponDistributionStarted`, `CouponPaid`, `CouponPaymentFailed`, `CouponDistributed`, `CouponClaimed` | Coupon servicing |
+    /// | Maturity | `MaturityRedeemed`, `MaturityRedemptionFailed`, `MaturityEscrowed`, `MaturityEscrowReleased`, `InstrumentMatured` | Principal repayment |
+    /// | Snapshot | `SnapshotTaken` | Record dates |
+    /// | Corporate action | `SplitStarted`, `BalanceSplit`, `SplitCompleted` | Splits and consolidations |
+    /// | Recovery | `Recovered` | Lost-wallet audit trail |
+    /// | Roles | `AdminChanged`, `RoleGranted`, `RoleRevoked` | Operator permission changes |
+    /// | Time lock | `MintScheduled`, `MintCancelled`, `PendingMintExecuted`, `PendingMintFailed` | Large-issuance oversight |
+    /// | Dividend | `DividendDeclared`, `DividendPaid`, `DividendPaymentFailed`, `DividendEscrowed`, `DividendDistributed`, `DividendReclaimed` | Dividend reconciliation |
+    /// | Identity | `TrustedIssuerAdded`, `TrustedIssuerRemoved`, `ClaimAdded`, `ClaimRemoved`, `RequiredClaimTopicsSet` | Investor eligibility tracking |
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Tokens were transferred between accounts.
+        ///
+        /// This event is emitted by [`Pallet::transfer`] and
+        /// [`Pallet::transfer_with_reference`] when tokens move between
+        /// whitelisted, non-frozen accounts.
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument that was transferred
+        /// - `from`: The sender's account ID (tokens debited)
+        /// - `to`: The receiver's account ID (tokens credited)
+        /// - `amount`: Number of tokens transferred (raw value, apply decimals for display)
+        /// - `reference`: Payment reference given to [`Pallet::transfer_with_reference`],
+        ///   `None` for plain transfers
+        ///
+        /// # Indexing Notes
+        ///
+        /// - Index by `from` to track outgoing transfers
+        /// - Index by `to` to track incoming transfers
+        /// - Sum `amount` values to calculate volume metrics
+        /// - Match `reference` against the end-to-end id of payment instructions
+        ///
+        /// # Example Event Data
+        ///
+        /// ```ignore
+        /// // Block explorer display
+        /// {
+        ///     "event": "Transferred",
+        ///     "assetId": 0,
+        ///     "from": "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+        ///     "to": "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty",
+        ///     "amount": "1000000000000",  // 1,000,000 tokens with 6 decimals
+        ///     "reference": "E2E-20250114-000123"
+        /// }
+        /// ```
+        Transferred {
+            /// Instrument that was transferred.
+            asset_id: AssetId,
+            /// Account that sent the tokens.
+            from: T::AccountId,
+            /// Account that received the tokens.
+            to: T::AccountId,
+            /// Amount of tokens transferred (raw u128 value).
+            amount: u128,
+            /// Payment reference supplied by the sender, if any.
+            reference: Option<TransferReferenceOf>,
+        },
+
+        /// New tokens were created and credited to an account.
+        ///
+        /// This event is emitted by [`Pallet::mint`] when an admin creates new tokens.
+        /// The total supply increases by `amount`.
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument that was minted
+        /// - `to`: The account receiving newly minted tokens
+        /// - `amount`: Number of tokens created (raw value)
+        ///
+        /// # Compliance Significance
+        ///
+        /// Minting events represent new token issuance and should be:
+        /// - Audited for authorized issuance
+        /// - Matched against official bond issuance documents
+        /// - Tracked for total supply reconciliation
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Ministry mints $100M bond tokens (6 decimals)
+        /// Minted {
+        ///     asset_id: 0,
+        ///     to: ministry_treasury_account,
+        ///     amount: 100_000_000_000_000  // 100M * 10^6
+        /// }
+        /// ```
+        Minted {
+            /// Instrument that was minted.
+            asset_id: AssetId,
+            /// Account that received the minted tokens.
+            to: T::AccountId,
+            /// Amount of tokens minted (raw u128 value).
+            amount: u128,
+        },
+
+        /// Tokens were destroyed by the admin.
+        ///
+        /// This event is emitted by [`Pallet::burn`], typically after a bond
+        /// buyback settles off-chain. The total supply decreases by `amount`.
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument that was burned
+        /// - `from`: The account whose tokens were destroyed
+        /// - `amount`: Number of tokens destroyed (raw value)
+        ///
+        /// # Compliance Significance
+        ///
+        /// Burn events should be matched against buyback settlement records so
+        /// that on-chain supply reconciles with the debt register.
+        Burned {
+            /// Instrument that was burned.
+            asset_id: AssetId,
+            /// Account whose tokens were destroyed.
+            from: T::AccountId,
+            /// Amount of tokens burned (raw u128 value).
+            amount: u128,
+        },
+
+        /// The admin moved tokens without the holder's signature.
+        ///
+        /// This event is emitted by [`Pallet::forced_transfer`]. It is distinct from
+        /// [`Event::Transferred`] so that indexers and auditors can separate
+        /// holder-initiated movements from administrative ones.
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument that was moved
+        /// - `from`: The account tokens were taken from
+        /// - `to`: The account tokens were credited to
+        /// - `amount`: Number of tokens moved (raw value)
+        /// - `reason`: Reason code and external reference
+        ///
+        /// # Compliance Significance
+        ///
+        /// Every forced transfer must be traceable to a legal basis. Auditors should
+        /// match `reason.reference` against court orders or correction tickets.
+        ForcedTransfer {
+            /// Instrument that was moved.
+            asset_id: AssetId,
+            /// Account tokens were taken from.
+            from: T::AccountId,
+            /// Account tokens were credited to.
+            to: T::AccountId,
+            /// Amount of tokens moved (raw u128 value).
+            amount: u128,
+            /// Why the transfer was forced.
+            reason: ForcedTransferReason,
+        },
+
+        /// A holder redeemed (destroyed) their own tokens.
+        ///
+        /// This event is emitted by [`Pallet::redeem`]. The total supply decreases
+        /// by `amount`; the issuer settles the redemption proceeds off-chain.
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument that was redeemed
+        /// - `who`: The holder that redeemed
+        /// - `amount`: Number of tokens redeemed (raw value)
+        Redeemed {
+            /// Instrument that was redeemed.
+            asset_id: AssetId,
+            /// Holder that redeemed the tokens.
+            who: T::AccountId,
+            /// Amount of tokens redeemed (raw u128 value).
+            amount: u128,
+        },
+
+        /// An account was frozen and can no longer send transfers.
+        ///
+        /// This event is emitted by [`Pallet::freeze`] when an admin restricts
+        /// an account's ability to transfer tokens.
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument the account was frozen on
+        /// - `account`: The account that was frozen
+        ///
+        /// # Compliance Significance
+        ///
+        /// Freeze events indicate:
+        /// - Regulatory action (sanctions, court order)
+        /// - Risk mitigation (suspected fraud)
+        /// - Operational control (preventing unauthorized transfers)
+        ///
+        /// Off-chain systems should trigger alerts when freeze events occur.
+        Frozen {
+            /// Instrument the account was frozen on.
+            asset_id: AssetId,
+            /// Account that was frozen.
+            account: T::AccountId,
+        },
+
+        /// A previously frozen account was unfrozen.
+        ///
+        /// This event is emitted by [`Pallet::unfreeze`] when an admin restores
+        /// an account's ability to transfer tokens.
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument the account was unfrozen on
+        /// - `account`: The account that was unfrozen
+        Unfrozen {
+            /// Instrument the account was unfrozen on.
+            asset_id: AssetId,
+            /// Account that was unfrozen.
+            account: T::AccountId,
+        },
+
+        /// Part of an account's balance was frozen.
+        ///
+        /// This event is emitted by [`Pallet::freeze_partial`].
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument the tokens were frozen on
+        /// - `account`: The account whose tokens were frozen
+        /// - `amount`: Number of tokens newly frozen (added to any existing freeze)
+        TokensFrozen {
+            /// Instrument the tokens were frozen on.
+            asset_id: AssetId,
+            /// Account whose tokens were frozen.
+            account: T::AccountId,
+            /// Amount of tokens newly frozen (raw u128 value).
+            amount: u128,
+        },
+
+        /// Partially frozen tokens were released.
+        ///
+        /// This event is emitted by [`Pallet::unfreeze_partial`], and by
+        /// [`Pallet::forced_transfer`] when it has to move frozen tokens.
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument the tokens were unfrozen on
+        /// - `account`: The account whose tokens were unfrozen
+        /// - `amount`: Number of tokens released
+        TokensUnfrozen {
+            /// Instrument the tokens were unfrozen on.
+            asset_id: AssetId,
+            /// Account whose tokens were unfrozen.
+            account: T::AccountId,
+            /// Amount of tokens released (raw u128 value).
+            amount: u128,
+        },
+
+        /// An instrument was paused; holder transfers are halted.
+        ///
+        /// This event is emitted by [`Pallet::pause`]. Off-chain systems should
+        /// raise an incident alert and stop accepting orders for the instrument.
+        Paused {
+            /// Instrument that was paused.
+            asset_id: AssetId,
+        },
+
+        /// A paused instrument was resumed.
+        ///
+        /// This event is emitted by [`Pallet::unpause`].
+        Unpaused {
+            /// Instrument that was resumed.
+            asset_id: AssetId,
+        },
+
+        /// An account was added to the whitelist (KYC approved).
+        ///
+        /// This event is emitted by [`Pallet::add_to_whitelist`] when an admin
+        /// approves an account for token transfers.
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument the account was approved for
+        /// - `account`: The newly whitelisted account
+        /// - `expires_at`: Block at which the approval lapses, if any
+        ///
+        /// # Workflow Context
+        ///
+        /// This typically follows successful KYC verification:
+        /// 1. Investor submits identity documents off-chain
+        /// 2. Compliance team verifies identity
+        /// 3. Admin adds account to whitelist
+        /// 4. This event is emitted
+        /// 5. Investor can now receive/send tokens
+        Whitelisted {
+            /// Instrument the account was approved for.
+            asset_id: AssetId,
+            /// Account that was added to the whitelist.
+            account: T::AccountId,
+            /// First block at which the approval lapses (`None`: never).
+            expires_at: Option<BlockNumberFor<T>>,
+        },
+
+        /// An investor's jurisdiction was recorded or changed.
+        ///
+        /// Emitted by [`Pallet::add_to_whitelist`] when the country differs from the
+        /// one previously recorded.
+        InvestorCountrySet {
+            /// Instrument the jurisdiction applies to.
+            asset_id: AssetId,
+            /// The investor.
+            account: T::AccountId,
+            /// New country code (`None`: no longer recorded).
+            country: Option<CountryCode>,
+        },
+
+        /// An account's whitelist approval lapsed and was removed.
+        ///
+        /// Emitted by the `on_idle` sweep. The account keeps its balance but needs
+        /// to be re-verified and whitelisted again before it can transfer.
+        WhitelistExpired {
+            /// Instrument the approval was for.
+            asset_id: AssetId,
+            /// Account whose approval lapsed.
+            account: T::AccountId,
+        },
+
+        /// An account was removed from the whitelist.
+        ///
+        /// This event is emitted by [`Pallet::remove_from_whitelist`] when an admin
+        /// revokes an account's transfer privileges.
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The instrument the approval was revoked for
+        /// - `account`: The account removed from whitelist
+        ///
+        /// # Important Note
+        ///
+        /// Removing from whitelist does NOT confiscate tokens. The account retains
+        /// its balance but cannot transfer it. For full offboarding, transfer tokens
+        /// to a treasury account first.
+        RemovedFromWhitelist {
+            /// Instrument the approval was revoked for.
+            asset_id: AssetId,
+            /// Account that was removed from the whitelist.
+            account: T::AccountId,
+        },
+
+        /// The admin account was changed.
+        ///
+        /// This event is emitted by [`Pallet::set_admin`] when the admin rotates
+        /// privileges to a new account (typically a new multi-sig address).
+        ///
+        /// # Fields
+        ///
+        /// - `old_admin`: The previous admin account (None if first time setting)
+        /// - `new_admin`: The new admin account
+        ///
+        /// # Audit Significance
+        ///
+        /// Admin changes are critical governance events. Off-chain systems should:
+        /// - Alert compliance officers immediately
+        /// - Log the change with timestamp for audit trail
+        /// - Verify the new admin matches expected multi-sig address
+        ///
+        /// # Example Event Data
+        ///
+        /// ```ignore
+        /// // Multi-sig rotation (personnel change)
+        /// AdminChanged {
+        ///     old_admin: Some("5MultiSigOld..."),
+        ///     new_admin: "5MultiSigNew..."
+        /// }
+        ///
+        /// // First-time admin setup (genesis fallback → explicit admin)
+        /// AdminChanged {
+        ///     old_admin: None,
+        ///     new_admin: "5MultiSig..."
+        /// }
+        /// ```
+        AdminChanged {
+            /// The previous admin account, if any.
+            old_admin: Option<T::AccountId>,
+            /// The new admin account.
+            new_admin: T::AccountId,
+        },
+
+        /// A new instrument was registered.
+        ///
+        /// This event is emitted by [`Pallet::create_instrument`]. The instrument starts
+        /// with zero supply; tokens are issued afterwards with [`Pallet::mint`].
+        ///
+        /// # Fields
+        ///
+        /// - `asset_id`: The id assigned to the instrument
+        /// - `symbol`: Trading symbol of the instrument
+        /// - `decimals`: Decimal precision of the instrument
+        /// - `max_supply`: Authorised amount, if capped
+        ///
+        /// # Indexing Notes
+        ///
+        /// Off-chain systems should register the new `asset_id` and fetch the full
+        /// metadata (`tokenName`, `tokenSymbol`, `decimals`) from storage.
+        InstrumentCreated {
+            /// Id assigned to the new instrument.
+            asset_id: AssetId,
+            /// Trading symbol of the new instrument.
+            symbol: TokenSymbolOf,
+            /// Decimal precision of the new instrument.
+            decimals: u8,
+            /// Authorised amount of the new instrument, or `None` if uncapped.
+            max_supply: Option<u128>,
+        },
+
+        /// A claim issuer was registered or its topics were updated.
+        TrustedIssuerAdded {
+            /// The trusted issuer.
+            issuer: T::AccountId,
+            /// Topics the issuer may attest.
+            topics: ClaimTopicsOf<T>,
+        },
+
+        /// A claim issuer was revoked; its claims are no longer valid.
+        TrustedIssuerRemoved {
+            /// The revoked issuer.
+            issuer: T::AccountId,
+        },
+
+        /// A claim was attested (or renewed) for an account.
+        ClaimAdded {
+            /// Account the claim is about.
+            subject: T::AccountId,
+            /// Topic of the claim.
+            topic: ClaimTopic,
+            /// Issuer that attested the claim.
+            issuer: T::AccountId,
+            /// First block at which the claim is no longer valid.
+            expires_at: BlockNumberFor<T>,
+        },
+
+        /// A claim was revoked.
+        ClaimRemoved {
+            /// Account the claim was about.
+            subject: T::AccountId,
+            /// Topic of the revoked claim.
+            topic: ClaimTopic,
+        },
+
+        /// The claim topics required to hold an instrument changed.
+        RequiredClaimTopicsSet {
+            /// Instrument whose requirements changed.
+            asset_id: AssetId,
+            /// Topics now required (empty: whitelist only).
+            topics: ClaimTopicsOf<T>,
+        },
+
+        /// The jurisdictions allowed to receive an instrument changed.
+        AllowedCountriesSet {
+            /// Instrument whose allow list changed.
+            asset_id: AssetId,
+            /// Allowed countries (empty: all allowed).
+            countries: CountriesOf<T>,
+        },
+
+        /// The jurisdictions barred from receiving an instrument changed.
+        BlockedCountriesSet {
+            /// Instrument whose block list changed.
+            asset_id: AssetId,
+            /// Blocked countries (empty: none blocked).
+            countries: CountriesOf<T>,
+        },
+
+        /// The holder cap of an instrument changed.
+        MaxHoldersSet {
+            /// Instrument whose cap changed.
+            asset_id: AssetId,
+            /// New cap (`None`: uncapped).
+            max_holders: Option<u32>,
+        },
+
+        /// The per-holder balance limit of an instrument changed.
+        MaxBalancePerHolderSet {
+            /// Instrument whose limit changed.
+            asset_id: AssetId,
+            /// New limit (`None`: unlimited).
+            limit: Option<HoldingLimit>,
+        },
+
+        /// An account's holding limit override changed.
+        AccountHoldingLimitSet {
+            /// Instrument the override applies to.
+            asset_id: AssetId,
+            /// The investor.
+            account: T::AccountId,
+            /// New override (`None`: instrument-wide limit applies).
+            limit: Option<HoldingLimit>,
+        },
+
+        /// Tokens were minted under a vesting schedule.
+        ///
+        /// Emitted after the corresponding [`Event::Minted`].
+        VestingScheduleAdded {
+            /// Instrument minted.
+            asset_id: AssetId,
+            /// The beneficiary.
+            account: T::AccountId,
+            /// Number of tokens under the schedule.
+            amount: u128,
+            /// Release schedule.
+            schedule: VestingScheduleOf<T>,
+        },
+
+        /// The bond terms of an instrument were set.
+        BondTermsSet {
+            /// Instrument configured.
+            asset_id: AssetId,
+            /// New terms.
+            terms: BondTermsOf<T>,
+        },
+
+        /// Payment of a coupon began.
+        CouponDistributionStarted {
+            /// Instrument whose coupon is paid.
+            asset_id: AssetId,
+            /// Index of the coupon date.
+            coupon_index: u32,
+            /// Native currency owed to all holders together.
+            total: u128,
+        },
+
+        /// A holder received a coupon.
+        CouponPaid {
+            /// Instrument whose coupon was paid.
+            asset_id: AssetId,
+            /// Index of the coupon date.
+            coupon_index: u32,
+            /// The holder.
+            account: T::AccountId,
+            /// Native currency paid.
+            amount: u128,
+        },
+
+        /// A coupon could not be paid to a holder and stays in the pot.
+        ///
+        /// Usually the amount is below the native currency's existential deposit
+        /// for an account that has none. The amount is recorded in
+        /// [`UnpaidCoupons`] until [`Pallet::claim_coupon`] pays it.
+        CouponPaymentFailed {
+            /// Instrument whose coupon was due.
+            asset_id: AssetId,
+            /// Index of the coupon date.
+            coupon_index: u32,
+            /// The holder.
+            account: T::AccountId,
+            /// Native currency owed.
+            amount: u128,
+        },
+
+        /// Every holder has been visited for a coupon.
+        CouponDistributed {
+            /// Instrument whose coupon was paid.
+            asset_id: AssetId,
+            /// Index of the coupon date.
+            coupon_index: u32,
+            /// Native currency actually paid out.
+            total_paid: u128,
+        },
+
+        /// A holder's tokens were burned against repayment of principal.
+        MaturityRedeemed {
+            /// Instrument redeemed.
+            asset_id: AssetId,
+            /// The holder.
+            account: T::AccountId,
+            /// Number of tokens burned (raw value).
+            amount: u128,
+            /// Native currency paid.
+            payout: u128,
+        },
+
+        /// A holder's principal could not be paid during the admin's paged
+        /// redemption; its tokens were burned and the principal escrowed, see
+        /// [`Event::MaturityEscrowed`].
+        MaturityRedemptionFailed {
+            /// Instrument being redeemed.
+            asset_id: AssetId,
+            /// The holder.
+            account: T::AccountId,
+            /// Number of tokens burned (raw value).
+            amount: u128,
+        },
+
+        /// A holder's tokens were burned at maturity and its principal withheld in
+        /// [`MaturityEscrow`].
+        MaturityEscrowed {
+            /// Instrument redeemed.
+            asset_id: AssetId,
+            /// The holder.
+            account: T::AccountId,
+            /// Number of tokens burned (raw value).
+            amount: u128,
+            /// Native currency withheld.
+            payout: u128,
+        },
+
+        /// Principal withheld at maturity was paid out.
+        MaturityEscrowReleased {
+            /// Instrument redeemed.
+            asset_id: AssetId,
+            /// The holder the principal was withheld from.
+            account: T::AccountId,
+            /// Account paid; differs from `account` if it was recovered.
+            payee: T::AccountId,
+            /// Native currency paid.
+            payout: u128,
+        },
+
+        /// Every token of an instrument has been redeemed; it can no longer move.
+        InstrumentMatured {
+            /// Instrument that matured.
+            asset_id: AssetId,
+        },
+
+        /// A record-date snapshot was taken; the block of this event is the
+        /// record date.
+        SnapshotTaken {
+            /// Instrument snapshotted.
+            asset_id: AssetId,
+            /// Id of the new snapshot.
+            snapshot_id: SnapshotId,
+        },
+
+        /// A dividend was deposited into its pot.
+        DividendDeclared {
+            /// Instrument paying the dividend.
+            asset_id: AssetId,
+            /// Id of the new dividend.
+            dividend_id: DividendId,
+            /// Account that funded it.
+            issuer: T::AccountId,
+            /// Snapshot whose balances entitle holders.
+            snapshot_id: SnapshotId,
+            /// Native currency deposited.
+            amount: u128,
+            /// Total supply at the snapshot.
+            supply: u128,
+            /// Last block at which shares can be claimed.
+            deadline: BlockNumberFor<T>,
+        },
+
+        /// A holder's dividend share was paid.
+        DividendPaid {
+            /// Instrument paying the dividend.
+            asset_id: AssetId,
+            /// Dividend paid.
+            dividend_id: DividendId,
+            /// Holder paid.
+            account: T::AccountId,
+            /// Native currency paid.
+            amount: u128,
+        },
+
+        /// A holder's dividend share could not be transferred (for example it is
+        /// below the existential deposit); the holder can still claim it.
+        DividendPaymentFailed {
+            /// Instrument paying the dividend.
+            asset_id: AssetId,
+            /// Dividend being paid.
+            dividend_id: DividendId,
+            /// Holder that was not paid.
+            account: T::AccountId,
+            /// Share owed.
+            amount: u128,
+        },
+
+        /// A frozen holder's dividend share was set aside until it is unfrozen.
+        DividendEscrowed {
+            /// Instrument paying the dividend.
+            asset_id: AssetId,
+            /// Dividend being paid.
+            dividend_id: DividendId,
+            /// Frozen holder, who claims the share once unfrozen.
+            account: T::AccountId,
+            /// Share held in the pot.
+            amount: u128,
+        },
+
+        /// [`Pallet::distribute_dividend`] has visited every holder.
+        DividendDistributed {
+            /// Instrument paying the dividend.
+            asset_id: AssetId,
+            /// Dividend distributed.
+            dividend_id: DividendId,
+            /// Native currency paid to holders, including earlier claims.
+            paid: u128,
+            /// Native currency escrowed for frozen holders.
+            escrowed: u128,
+        },
+
+        /// The unclaimed remainder of a dividend was returned to the issuer.
+        DividendReclaimed {
+            /// Instrument paying the dividend.
+            asset_id: AssetId,
+            /// Dividend reclaimed.
+            dividend_id: DividendId,
+            /// Account refunded.
+            issuer: T::AccountId,
+            /// Native currency returned, including rounding remainders.
+            amount: u128,
+        },
+
+        /// A split or reverse split of an instrument began.
+        SplitStarted {
+            /// Instrument being split.
+            asset_id: AssetId,
+            /// New tokens per `denominator` old tokens.
+            numerator: u32,
+            /// Old tokens per `numerator` new tokens.
+            denominator: u32,
+        },
+
+        /// A holder's balance was rescaled by a split.
+        BalanceSplit {
+            /// Instrument being split.
+            asset_id: AssetId,
+            /// Holder rescaled.
+            account: T::AccountId,
+            /// Balance before the split.
+            old_balance: u128,
+            /// Balance after the split, rounded down.
+            new_balance: u128,
+        },
+
+        /// Every holder has been rescaled and the new supply is in effect.
+        SplitCompleted {
+            /// Instrument split.
+            asset_id: AssetId,
+            /// New tokens per `denominator` old tokens.
+            numerator: u32,
+            /// Old tokens per `numerator` new tokens.
+            denominator: u32,
+            /// Total supply before the split.
+            old_supply: u128,
+            /// Total supply after the split; the sum of the rescaled balances.
+            new_supply: u128,
+        },
+
+        /// A lost address's position was moved to a new address, and the lost
+        /// address blocked.
+        Recovered {
+            /// Instrument recovered.
+            asset_id: AssetId,
+            /// Address whose keys were lost.
+            lost: T::AccountId,
+            /// Address now holding the position.
+            new: T::AccountId,
+            /// Tokens moved.
+            amount: u128,
+        },
+
+        /// An account was given an operator role.
+        RoleGranted {
+            /// Role granted.
+            role: Role,
+            /// Account now holding the role.
+            account: T::AccountId,
+        },
+
+        /// An operator role was taken away from an account.
+        RoleRevoked {
+            /// Role revoked.
+            role: Role,
+            /// Account no longer holding the role.
+            account: T::AccountId,
+        },
+
+        /// A large mint was queued and can be cancelled until it executes.
+        ///
+        /// This event is emitted by [`Pallet::mint`], [`Pallet::mint_with_schedule`]
+        /// and [`Pallet::batch_mint`] when the instrument's mints in the current
+        /// window reach [`Config::LargeMintThreshold`]. Overseers should review it
+        /// before `execute_at`.
+        MintScheduled {
+            /// Id of the pending mint, passed to [`Pallet::cancel_mint`].
+            id: PendingMintId,
+            /// Instrument to mint.
+            asset_id: AssetId,
+            /// Recipient of the new tokens.
+            to: T::AccountId,
+            /// Number of tokens to create (raw value).
+            amount: u128,
+            /// Block at whose start the mint executes.
+            execute_at: BlockNumberFor<T>,
+        },
+
+        /// A time-locked mint was vetoed.
+        MintCancelled {
+            /// Id of the cancelled mint.
+            id: PendingMintId,
+        },
+
+        /// A time-locked mint was executed; [`Event::Minted`] carries the details.
+        ///
+        /// If the recipient was recovered while the mint was queued, the tokens go
+        /// to the address it was recovered to.
+        PendingMintExecuted {
+            /// Id of the executed mint.
+            id: PendingMintId,
+        },
+
+        /// A time-locked mint was due but failed the mint checks (for example the
+        /// instrument was paused or the holder cap reached) and was dropped.
+        PendingMintFailed {
+            /// Id of the dropped mint.
+            id: PendingMintId,
+            /// Why the mint failed.
+            error: DispatchError,
+        },
+
+        /// An instrument's authorised amount was raised, or first set.
+        ///
+        /// This event is emitted by [`Pallet::raise_max_supply`].
+        MaxSupplyRaised {
+            /// Instrument whose cap was raised.
+            asset_id: AssetId,
+            /// Previous cap (raw value), `None` if the instrument was uncapped.
+            old_max_supply: Option<u128>,
+            /// New cap (raw value).
+            new_max_supply: u128,
+        },
+
+        /// An instrument's name, symbol or identifiers changed.
+        ///
+        /// This event is emitted by [`Pallet::set_metadata`] and carries the full new
+        /// metadata, so indexers need not query storage.
+        MetadataUpdated {
+            /// Instrument whose metadata changed.
+            asset_id: AssetId,
+            /// New name.
+            name: TokenNameOf,
+            /// New trading symbol.
+            symbol: TokenSymbolOf,
+            /// New identifiers and prospectus.
+            metadata: InstrumentMetadata,
+        },
+
+        /// A legal document was attached to an instrument or replaced.
+        ///
+        /// This event is emitted by [`Pallet::set_document`] (ERC-1643
+        /// `DocumentUpdated`).
+        DocumentUpdated {
+            /// Instrument the document governs.
+            asset_id: AssetId,
+            /// Name of the document.
+            name: DocumentNameOf,
+            /// Where the document can be downloaded.
+            uri: DocumentUriOf,
+            /// Hash of the document's content.
+            content_hash: [u8; 32],
+        },
+
+        /// A legal document was withdrawn.
+        ///
+        /// This event is emitted by [`Pallet::remove_document`] (ERC-1643
+        /// `DocumentRemoved`) and carries the last registered version.
+        DocumentRemoved {
+            /// Instrument the document governed.
+            asset_id: AssetId,
+            /// Name of the document.
+            name: DocumentNameOf,
+            /// Where the document could be downloaded.
+            uri: DocumentUriOf,
+            /// Hash of the document's content.
+            content_hash: [u8; 32],
+        },
+
+        /// A coupon that could not be paid was claimed.
+        CouponClaimed {
+            /// Instrument whose coupon was due.
+            asset_id: AssetId,
+            /// Index of the coupon date.
+            coupon_index: u32,
+            /// The holder the coupon was owed to.
+            account: T::AccountId,
+            /// Account paid; differs from `account` if it was recovered.
+            payee: T::AccountId,
+            /// Native currency paid.
+            amount: u128,
+        },
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // ERRORS
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Errors that can occur when interacting with this pallet.
+    ///
+    /// Errors are returned when an extrinsic cannot complete successfully.
+    /// They provide information about why the operation failed, allowing
+    /// callers to handle failures appropriately.
+    ///
+    /// # Error Handling in Clients
+    ///
+    /// ```text
+    /// // JavaScript: Check for specific errors
+    /// try {
+    ///     await api.tx.cladToken.transfer(to, amount).signAndSend(sender);
+    /// } catch (error) {
+    ///     if (error.message.includes('NotWhitelisted')) {
+    ///         console.log('Recipient needs KYC approval first');
+    ///     } else if (error.message.includes('InsufficientBalance')) {
+    ///         console.log('Not enough tokens in account');
+    ///     }
+    /// }
+    /// ```
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The sender does not have enough tokens to complete the transfer.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::transfer`] when `amount > sender_balance`
+        ///   (see [`Error::InsufficientUnfrozenBalance`] for partially frozen balances)
+        /// - [`Pallet::burn`] when `amount > from_balance`
+        /// - [`Pallet::forced_transfer`] when `amount > from_balance`
+        /// - [`Pallet::redeem`] when `amount > holder_balance`
+        ///
+        /// # Resolution
+        ///
+        /// 1. Check current balance: `api.query.cladToken.balances(account)`
+        /// 2. Reduce transfer amount or acquire more tokens
+        /// 3. Account for decimals when calculating amounts
+        ///
+        /// # Example
+        ///
+        /// ```text
+        /// Account balance: 1,000,000 (with 6 decimals = 1.0 tokens)
+        /// Transfer amount: 2,000,000 (2.0 tokens)
+        /// Result: InsufficientBalance error
+        /// ```
+        InsufficientBalance,
+
+        /// The sender or receiver is not on the whitelist.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::transfer`] when sender is not whitelisted
+        /// - [`Pallet::transfer`] when receiver is not whitelisted
+        /// - [`Pallet::burn`] / [`Pallet::redeem`] when the holder is not whitelisted
+        /// - [`Pallet::forced_transfer`] when the receiver is not whitelisted
+        ///
+        /// # Resolution
+        ///
+        /// 1. Verify both accounts are whitelisted:
+        ///    - `api.query.cladToken.whitelist(sender)`
+        ///    - `api.query.cladToken.whitelist(receiver)`
+        /// 2. Contact admin to whitelist non-approved accounts
+        /// 3. Complete KYC process before requesting whitelist
+        ///
+        /// # ERC-3643 Context
+        ///
+        /// This error enforces the identity verification requirement of compliant
+        /// security tokens. Both parties must be verified investors.
+        NotWhitelisted,
+
+        /// The sender's account is frozen and cannot initiate transfers.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::transfer`] when sender is frozen
+        /// - [`Pallet::burn`] / [`Pallet::redeem`] when the holder is frozen
+        /// - [`Pallet::claim_matured_principal`] while the holder is frozen
+        ///
+        /// # Resolution
+        ///
+        /// 1. Check freeze status: `api.query.cladToken.frozen(account)`
+        /// 2. Contact admin to understand why account was frozen
+        /// 3. Resolve underlying compliance issue
+        /// 4. Request unfreeze via admin
+        ///
+        /// # Note
+        ///
+        /// Frozen accounts can still **receive** tokens. Only outgoing transfers
+        /// are blocked. This allows court-ordered asset returns while preventing
+        /// the frozen party from moving their holdings.
+        AccountFrozen,
+
+        /// Arithmetic overflow would occur (balance or supply exceeds u128 max).
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::mint`] when `total_supply + amount > u128::MAX`
+        /// - [`Pallet::mint`] when `recipient_balance + amount > u128::MAX`
+        /// - [`Pallet::transfer`] when `recipient_balance + amount > u128::MAX`
+        /// - [`Pallet::forced_transfer`] when `recipient_balance + amount > u128::MAX`
+        /// - [`Pallet::snapshot`] when the snapshot id would exceed `u32::MAX`
+        /// - [`Pallet::declare_dividend`] when the dividend id would exceed `u32::MAX`
+        /// - [`Pallet::split`] when the rescaled supply would exceed `u128::MAX`
+        /// - [`Pallet::mint`] when a time-locked mint id would exceed `u32::MAX`
+        ///
+        /// # Resolution
+        ///
+        /// This error is extremely rare in practice (u128 max is ~340 undecillion).
+        /// If encountered:
+        ///
+        /// 1. Review minting amounts for errors (extra zeros?)
+        /// 2. Check for bugs in amount calculation logic
+        /// 3. Consider using smaller denominations (more decimals)
+        ///
+        /// # Technical Note
+        ///
+        /// The pallet uses `checked_add()` to detect overflow before modifying
+        /// storage, ensuring no partial state changes occur on overflow.
+        Overflow,
+
+        /// The referenced instrument does not exist.
+        ///
+        /// # Triggered By
+        ///
+        /// - Any instrument-scoped extrinsic when `asset_id >= NextAssetId`
+        ///
+        /// # Resolution
+        ///
+        /// 1. Check registered instruments: `api.query.cladToken.nextAssetId()`
+        /// 2. Use the `asset_id` reported by the `InstrumentCreated` event
+        UnknownInstrument,
+
+        /// The maximum number of instruments has been reached.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::create_instrument`] when `NextAssetId == MaxInstruments`
+        ///
+        /// # Resolution
+        ///
+        /// Raise [`Config::MaxInstruments`] via a runtime upgrade.
+        TooManyInstruments,
+
+        /// The account's free (non-frozen) balance is too low.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::transfer`], [`Pallet::burn`] and [`Pallet::redeem`] when
+        ///   `amount > balance - frozen_amount`
+        /// - [`Pallet::freeze_partial`] when freezing more than the free balance;
+        ///   vesting-locked tokens count as free here and can be frozen
+        /// - [`Pallet::claim_matured_principal`] while part of the holder's
+        ///   balance is still frozen
+        ///
+        /// # Resolution
+        ///
+        /// 1. Check the frozen portion: `api.query.cladToken.frozenAmount(assetId, account)`
+        /// 2. Reduce the amount, or ask the admin to release collateral via `unfreeze_partial`
+        InsufficientUnfrozenBalance,
+
+        /// More tokens were requested to be unfrozen than are frozen.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::unfreeze_partial`] when `amount > frozen_amount`
+        InsufficientFrozenBalance,
+
+        /// The instrument is paused.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::transfer`] and [`Pallet::redeem`] while the instrument is paused
+        /// - [`Pallet::mint`] while paused, if [`Config::PauseBlocksMinting`] is `true`
+        ///
+        /// # Resolution
+        ///
+        /// Wait for the admin to resolve the incident and call `unpause`.
+        TokenPaused,
+
+        /// An account lacks a valid claim for a topic the instrument requires.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::transfer`] when sender or receiver is missing a claim
+        /// - [`Pallet::burn`], [`Pallet::redeem`] and [`Pallet::forced_transfer`]
+        ///   under the same rule as the whitelist check
+        ///
+        /// The claim may be absent, expired, or attested by an issuer that is no
+        /// longer trusted for the topic.
+        ///
+        /// # Resolution
+        ///
+        /// 1. Check `api.query.cladToken.requiredClaimTopics(assetId)`
+        /// 2. Check `api.query.cladToken.claims(account, topic)` for each topic
+        /// 3. Ask a trusted issuer to (re-)attest the claim
+        MissingClaim,
+
+        /// The caller is not a trusted issuer for the claim topic.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::add_claim`] by an account not trusted for `topic`
+        /// - [`Pallet::remove_trusted_issuer`] for an account that is not an issuer
+        NotTrustedIssuer,
+
+        /// The claim expiry is not in the future.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::add_claim`] with `expires_at <= current block`
+        InvalidClaimExpiry,
+
+        /// No claim exists for the account and topic.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::remove_claim`]
+        ClaimNotFound,
+
+        /// The account's whitelist approval has expired.
+        ///
+        /// # Triggered By
+        ///
+        /// - Any call that requires a whitelisted account (see [`Error::NotWhitelisted`])
+        ///   once the entry's `expires_at` block has been reached
+        ///
+        /// # Resolution
+        ///
+        /// Re-verify the investor and call `add_to_whitelist` with a new expiry.
+        KycExpired,
+
+        /// The whitelist expiry is not in the future.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::add_to_whitelist`] with `expires_at <= current block`
+        InvalidWhitelistExpiry,
+
+        /// The receiver's jurisdiction is blocked for this instrument.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::transfer`] or [`Pallet::forced_transfer`] when the
+        ///   receiver's [`InvestorCountry`] is in [`BlockedCountries`]
+        ///
+        /// # Resolution
+        ///
+        /// The instrument's prospectus excludes investors from this jurisdiction;
+        /// the transfer cannot be made.
+        CountryBlocked,
+
+        /// The receiver's jurisdiction is not on the instrument's allow list.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::transfer`] or [`Pallet::forced_transfer`] when
+        ///   [`AllowedCountries`] is non-empty and the receiver's country is not
+        ///   listed or not recorded
+        ///
+        /// # Resolution
+        ///
+        /// Check the receiver's country with `api.query.cladToken.investorCountry`;
+        /// if it was never recorded, re-whitelist the receiver with a country code.
+        CountryNotAllowed,
+
+        /// The instrument already has the maximum number of holders.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::mint`], [`Pallet::transfer`] or [`Pallet::forced_transfer`]
+        ///   to an account with a zero balance while [`HolderCount`] has reached
+        ///   [`MaxHolders`]
+        ///
+        /// # Resolution
+        ///
+        /// Transfer to an existing holder, wait for a holder to exit, or raise the
+        /// cap with [`Pallet::set_max_holders`] if the offering terms allow it.
+        TooManyHolders,
+
+        /// The receiver would hold more than its holding limit.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::mint`], [`Pallet::transfer`] or [`Pallet::forced_transfer`]
+        ///   when the receiver's new balance exceeds its [`AccountHoldingLimit`] or, absent an override, the
+        ///   instrument's [`MaxBalancePerHolder`]
+        ///
+        /// # Resolution
+        ///
+        /// Reduce the amount. For treasury or anchor-investor accounts, the admin
+        /// can grant an override with [`Pallet::set_account_holding_limit`].
+        HoldingLimitExceeded,
+
+        /// The schedule's blocks are out of order.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::mint_with_schedule`] unless `start <= cliff <= end`
+        InvalidVestingSchedule,
+
+        /// The account already has the maximum number of unvested schedules.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::mint_with_schedule`] when [`Config::MaxVestingSchedules`]
+        ///   schedules are still locking tokens
+        ///
+        /// # Resolution
+        ///
+        /// Wait for a schedule to vest fully, or mint the allocation without a
+        /// schedule.
+        TooManyVestingSchedules,
+
+        /// The amount exceeds the sender's vested (unlocked) balance.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::transfer`], [`Pallet::burn`] or [`Pallet::redeem`] when
+        ///   `amount` would touch tokens still locked by a vesting schedule
+        /// - [`Pallet::claim_matured_principal`] before the holder's schedules
+        ///   have run out
+        ///
+        /// # Resolution
+        ///
+        /// Query `CladTokenApi::vesting_balance` for the unlocked amount, or wait
+        /// for more tokens to vest.
+        InsufficientVestedBalance,
+
+        /// The bond terms are inconsistent.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::set_bond_terms`] when coupon dates do not strictly increase,
+        ///   a coupon date falls after maturity, or fewer dates remain than
+        ///   coupons already paid
+        InvalidBondTerms,
+
+        /// The instrument has no bond terms.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::distribute_coupon`] before [`Pallet::set_bond_terms`]
+        NoBondTerms,
+
+        /// Every coupon of the instrument has been paid.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::distribute_coupon`] after the last coupon date was served
+        NoCouponDue,
+
+        /// The next coupon date has not been reached.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::distribute_coupon`] before the next coupon date
+        CouponNotDue,
+
+        /// The coupon pot cannot cover the coupon owed to all holders.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::distribute_coupon`] when starting a coupon
+        ///
+        /// # Resolution
+        ///
+        /// Transfer the shortfall to [`Pallet::coupon_pot`] and call again.
+        InsufficientCouponFunds,
+
+        /// A coupon of the instrument is being paid.
+        ///
+        /// # Triggered By
+        ///
+        /// - Mints, burns and transfers of the instrument, and
+        ///   [`Pallet::set_bond_terms`], while [`CouponDistribution`] is open
+        ///
+        /// # Resolution
+        ///
+        /// Call [`Pallet::distribute_coupon`] until the payment completes.
+        CouponDistributionInProgress,
+
+        /// The instrument has not reached its maturity block.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::redeem_at_maturity`] before [`BondTerms::maturity`]
+        NotMatured,
+
+        /// Coupons due before maturity have not all been paid.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::redeem_at_maturity`] while [`NextCoupon`] is below the number
+        ///   of coupon dates
+        ///
+        /// # Resolution
+        ///
+        /// Pay the outstanding coupons with [`Pallet::distribute_coupon`] first.
+        CouponsOutstanding,
+
+        /// The redemption pot cannot cover the principal.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::redeem_at_maturity`] by a holder when the pot holds less
+        ///   than the holder's principal
+        /// - [`Pallet::redeem_at_maturity`] by the admin when the pot holds less
+        ///   than the principal of the whole outstanding supply
+        ///
+        /// Principal withheld in [`MaturityEscrow`] is not available to either.
+        ///
+        /// # Resolution
+        ///
+        /// Transfer the shortfall to [`Pallet::redemption_pot`] and call again.
+        InsufficientRedemptionFunds,
+
+        /// The instrument has matured and been fully redeemed.
+        ///
+        /// # Triggered By
+        ///
+        /// - Mints and transfers of the instrument, and
+        ///   [`Pallet::redeem_at_maturity`], once [`Matured`] is set
+        InstrumentMatured,
+
+        /// No snapshot with this id has been taken.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::declare_dividend`] with snapshot id `0` or above
+        ///   [`CurrentSnapshotId`]
+        ///
+        /// # Resolution
+        ///
+        /// Take the record-date snapshot with [`Pallet::snapshot`] first.
+        UnknownSnapshot,
+
+        /// The dividend parameters are unusable.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::declare_dividend`] with a zero amount, a snapshot with zero
+        ///   supply, or a deadline that is not in the future
+        InvalidDividend,
+
+        /// No dividend with this id exists for the instrument.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::claim_dividend`], [`Pallet::distribute_dividend`] and
+        ///   [`Pallet::reclaim_dividend`]
+        UnknownDividend,
+
+        /// The account held nothing at the dividend's snapshot, or its share
+        /// rounds down to zero.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::claim_dividend`]
+        NoDividendShare,
+
+        /// The account's share has already been paid.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::claim_dividend`]
+        DividendAlreadyClaimed,
+
+        /// The claim deadline has passed.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::claim_dividend`] after the deadline, unless the share was
+        ///   escrowed
+        DividendExpired,
+
+        /// Every holder has already been visited.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::distribute_dividend`]
+        DividendAlreadyDistributed,
+
+        /// The claim deadline has not passed yet.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::reclaim_dividend`] at or before the deadline
+        DividendNotExpired,
+
+        /// Some holders have not been visited, so frozen holders' shares may not
+        /// be escrowed yet.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::reclaim_dividend`]
+        ///
+        /// # Resolution
+        ///
+        /// Call [`Pallet::distribute_dividend`] until [`Event::DividendDistributed`].
+        DividendDistributionIncomplete,
+
+        /// The remainder has already been returned to the issuer.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::reclaim_dividend`]
+        DividendAlreadyReclaimed,
+
+        /// The split ratio is unusable.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::split`] with a zero numerator or denominator, or with both
+        ///   equal
+        InvalidSplitRatio,
+
+        /// A split of the instrument is being applied.
+        ///
+        /// # Triggered By
+        ///
+        /// - Mints, burns, transfers and partial freezes of the instrument, coupon
+        ///   payments, maturity redemptions, snapshots, holding-limit overrides and
+        ///   new splits, while [`SplitInProgress`] is set
+        ///
+        /// # Resolution
+        ///
+        /// Call [`Pallet::continue_split`] until [`Event::SplitCompleted`].
+        SplitInProgress,
+
+        /// No split of the instrument is being applied.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::continue_split`]
+        NoSplitInProgress,
+
+        /// The account has been recovered to another address and is blocked on
+        /// the instrument.
+        ///
+        /// # Triggered By
+        ///
+        /// - Any identity check of the account (burns, transfers)
+        /// - Mints to the account, including time-locked ones when queued
+        /// - [`Pallet::claim_dividend`] by the account
+        /// - [`Pallet::add_to_whitelist`] for the account
+        /// - [`Pallet::recover_account`] from or to the account
+        ///
+        /// # Resolution
+        ///
+        /// Use the address in [`RecoveredAccounts`] instead.
+        AccountRecovered,
+
+        /// The recovery target already holds tokens or lock-ups of the instrument,
+        /// already took over a recovered position, or is the lost address itself.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::recover_account`]
+        ///
+        /// # Resolution
+        ///
+        /// Recover to a fresh address.
+        RecoveryTargetInUse,
+
+        /// The account already holds the role.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::grant_role`]
+        RoleAlreadyGranted,
+
+        /// The account does not hold the role.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::revoke_role`]
+        RoleNotGranted,
+
+        /// Too many time-locked mints already fall due in the same block.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::mint`], [`Pallet::mint_with_schedule`] and
+        ///   [`Pallet::batch_mint`] for large amounts
+        ///
+        /// # Resolution
+        ///
+        /// Retry in the next block.
+        MintQueueFull,
+
+        /// No time-locked mint has this id; it was executed, cancelled or never
+        /// queued.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::cancel_mint`]
+        UnknownPendingMint,
+
+        /// The mint would take the total supply above the instrument's
+        /// [`MaxSupply`].
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::mint`] and [`Pallet::mint_with_schedule`]
+        /// - Time-locked mints, reported in [`Event::PendingMintFailed`]
+        ///
+        /// # Resolution
+        ///
+        /// Mint less, burn first, or have [`Config::SupplyCapOrigin`] raise the cap.
+        SupplyCapExceeded,
+
+        /// The new cap does not raise the instrument's current [`MaxSupply`], or
+        /// a first cap is below the instrument's total supply.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::raise_max_supply`]
+        ///
+        /// # Resolution
+        ///
+        /// Pass a cap above [`Pallet::max_supply`], or for an uncapped instrument
+        /// at least [`Pallet::total_supply`]. Caps cannot be lowered.
+        InvalidSupplyCap,
+
+        /// An identifier does not have the format of its standard.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::set_metadata`]
+        ///
+        /// # Resolution
+        ///
+        /// Pass codes in upper-case ASCII; see [`InstrumentMetadata::is_well_formed`].
+        InvalidMetadata,
+
+        /// The instrument has no document with this name.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::remove_document`]
+        UnknownDocument,
+
+        /// Large mints of the instrument are still waiting out their delay.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::split`] while [`PendingMintCount`] is non-zero, since the
+        ///   queued amounts would not be rescaled
+        ///
+        /// # Resolution
+        ///
+        /// Wait for the queued mints to execute, or have [`Config::VetoOrigin`]
+        /// cancel them and re-issue them after the split.
+        MintsPending,
+
+        /// No principal is withheld for the account.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::claim_matured_principal`] for an account without a
+        ///   [`MaturityEscrow`] entry
+        NothingEscrowed,
+
+        /// The instrument has bond terms and cannot be split.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::split`] on an instrument with [`Bonds`] terms, whose face
+        ///   value per token would no longer match the rescaled balances
+        ///
+        /// # Resolution
+        ///
+        /// Only equity (instruments without bond terms) can be split.
+        BondNotSplittable,
+
+        /// No unpaid coupon is recorded for the account.
+        ///
+        /// # Triggered By
+        ///
+        /// - [`Pallet::claim_coupon`] for a coupon without an [`UnpaidCoupons`]
+        ///   entry, because it was paid or already claimed
+        NoUnpaidCoupon,
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // HOOKS
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Execute the time-locked mints due at block `n`.
+        ///
+        /// Each mint runs in its own storage layer; a mint failing its checks emits
+        /// [`Event::PendingMintFailed`] and does not affect the others.
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let due = MintQueue::<T>::take(n);
+            let count = due.len() as u32;
+            for id in due {
+                let Some(pending) = PendingMints::<T>::take(id) else { continue };
+                PendingMintCount::<T>::mutate(pending.asset_id, |count| {
+                    *count = count.saturating_sub(1)
+                });
+                // A recipient recovered in the meantime is paid at its new address
+                let to = Self::recovered_address(pending.asset_id, &pending.to);
+                let result =
+                    frame_support::storage::with_storage_layer(|| match pending.schedule {
+                        Some(schedule) => Self::do_mint_with_schedule(
+                            pending.asset_id,
+                            to,
+                            pending.amount,
+                            schedule,
+                        ),
+                        None => Self::do_mint(pending.asset_id, &to, pending.amount),
+                    });
+                match result {
+                    Ok(()) => Self::deposit_event(Event::PendingMintExecuted { id }),
+                    Err(error) => Self::deposit_event(Event::PendingMintFailed { id, error }),
+                }
+            }
+            T::WeightInfo::execute_pending_mints(count)
+        }
+
+        /// Remove lapsed whitelist approvals using leftover block weight.
+        ///
+        /// Inspects at most [`Config::MaxWhitelistSweep`] entries, resuming from
+        /// [`WhitelistSweepCursor`], and emits [`Event::WhitelistExpired`] for each
+        /// approval removed. Expired approvals are already rejected by the transfer
+        /// checks; the sweep only makes the lapse visible to indexers and frees
+        /// the storage.
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let base = T::WeightInfo::sweep_whitelist(0);
+            let per_entry = T::WeightInfo::sweep_whitelist(1).saturating_sub(base);
+            if remaining_weight.any_lt(base.saturating_add(per_entry)) {
+                return Weight::zero();
+            }
+
+            let budget = remaining_weight.saturating_sub(base);
+            let limit = [
+                budget.ref_time().checked_div(per_entry.ref_time()),
+                budget.proof_size().checked_div(per_entry.proof_size()),
+            ]
+            .into_iter()
+            .flatten()
+            .fold(T::MaxWhitelistSweep::get() as u64, u64::min) as u32;
+
+            let visited = Self::sweep_whitelist(limit);
+            T::WeightInfo::sweep_whitelist(visited)
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // DISPATCHABLE FUNCTIONS (EXTRINSICS)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Mint new tokens and credit them to an account.
+        ///
+        /// Creates `amount` new tokens and adds them to the `to` account's balance.
+        /// This increases the total supply by `amount`.
+        ///
+        /// # Permissions
+        ///
+        /// **Minter** - Requires a signed holder of [`Role::Minter`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::Minter`] |
+        /// | `asset_id` | `AssetId` | Instrument to mint |
+        /// | `to` | `T::AccountId` | Recipient account for new tokens |
+        /// | `amount` | `u128` | Number of tokens to create (raw value) |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Minted`] on success
+        /// - [`Event::MintScheduled`] instead, for a time-locked mint
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::MintQueueFull`] if a time-locked mint cannot be queued
+        /// - [`Error::AccountRecovered`] if `to` was recovered to another address
+        /// - [`Error::InstrumentMatured`] once the instrument has been fully redeemed
+        /// - [`Error::CouponDistributionInProgress`] while a coupon is being paid
+        /// - [`Error::TokenPaused`] if paused and [`Config::PauseBlocksMinting`] is set
+        /// - [`Error::TooManyHolders`] if `to` is a new holder and the cap is reached
+        /// - [`Error::HoldingLimitExceeded`] if `to` would exceed its holding limit
+        /// - [`Error::SupplyCapExceeded`] if `total_supply + amount > max_supply`
+        /// - [`Error::Overflow`] if `total_supply + amount > u128::MAX`
+        /// - [`Error::Overflow`] if `recipient_balance + amount > u128::MAX`
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        /// - `BadOrigin` if caller is not a minter
+        ///
+        /// # Time Lock
+        ///
+        /// If `amount` takes the instrument's immediate mints in the current window
+        /// (see [`RecentMints`]) to [`Config::LargeMintThreshold`] or above, the mint
+        /// is only queued in [`PendingMints`] and executed at the start of the block
+        /// [`Config::MintDelay`] blocks later, unless [`Config::VetoOrigin`] cancels
+        /// it with [`cancel_mint`](Pallet::cancel_mint). The checks above then run
+        /// at execution.
+        ///
+        /// # Use Cases
+        ///
+        /// 1. **Initial bond issuance**: Ministry mints total bond value to treasury
+        /// 2. **Supplemental issuance**: Additional tokens for reopened bond series
+        /// 3. **Error correction**: Minting to compensate for system errors (rare)
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Mint $100M bond tokens (6 decimals) to treasury account
+        /// // Raw amount = 100,000,000 * 10^6 = 100_000_000_000_000
+        /// CladToken::mint(
+        ///     RuntimeOrigin::signed(minter),
+        ///     GENESIS_ASSET_ID,
+        ///     treasury_account,
+        ///     100_000_000_000_000
+        /// )?;
+        /// ```
+        ///
+        /// # Security Considerations
+        ///
+        /// - Over-issuance can only be undone with [`Pallet::burn`], which is itself
+        ///   an audited admin action
+        /// - Verify `amount` calculations carefully (account for decimals)
+        /// - Consider multi-sig admin for production deployments
+        /// - Log all minting operations for audit trail
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::mint().max(T::WeightInfo::schedule_mint()))]
+        pub fn mint(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            to: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::Minter)?;
+            Self::mint_or_schedule(asset_id, to, amount, None)
+        }
+
+        /// Transfer tokens from the caller to another account.
+        ///
+        /// Moves `amount` tokens from the caller's account to the `to` account.
+        /// Both accounts must be whitelisted, and the caller must not be frozen.
+        ///
+        /// # Permissions
+        ///
+        /// **Signed** - Any account can call, but compliance checks apply.
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed origin (the sender) |
+        /// | `asset_id` | `AssetId` | Instrument to transfer |
+        /// | `to` | `T::AccountId` | Recipient account |
+        /// | `amount` | `u128` | Number of tokens to transfer (raw value) |
+        ///
+        /// # Pre-conditions
+        ///
+        /// All of the following must be true:
+        /// - Sender is whitelisted (KYC approved) on `asset_id`
+        /// - Receiver is whitelisted (KYC approved) on `asset_id`
+        /// - Both hold valid claims for every topic in [`RequiredClaimTopics`]
+        /// - Receiver's jurisdiction is not blocked, and is allowed if the
+        ///   instrument has an allow list
+        /// - Sender is not frozen on `asset_id`
+        /// - Sender has sufficient balance (`balance >= amount`)
+        /// - A new receiver does not take the instrument past [`MaxHolders`]
+        /// - Receiver stays within its holding limit ([`MaxBalancePerHolder`])
+        /// - [`Config::Compliance`] allows the transfer
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Transferred`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InstrumentMatured`] once the instrument has been fully redeemed
+        /// - [`Error::CouponDistributionInProgress`] while a coupon is being paid
+        /// - [`Error::TokenPaused`] if the instrument is paused
+        /// - [`Error::NotWhitelisted`] if sender or receiver not on whitelist
+        /// - [`Error::MissingClaim`] if sender or receiver lacks a required claim
+        /// - [`Error::CountryBlocked`] if the receiver's country is blocked
+        /// - [`Error::CountryNotAllowed`] if the receiver's country is not allowed
+        /// - [`Error::AccountFrozen`] if sender is frozen
+        /// - [`Error::InsufficientUnfrozenBalance`] if `amount` exceeds the sender's
+        ///   non-frozen balance
+        /// - [`Error::InsufficientVestedBalance`] if `amount` exceeds the sender's
+        ///   vested balance
+        /// - [`Error::InsufficientBalance`] if sender has less than `amount`
+        /// - [`Error::TooManyHolders`] if the receiver would be a holder beyond the cap
+        /// - [`Error::HoldingLimitExceeded`] if the receiver would exceed its holding
+        ///   limit
+        /// - [`Error::Overflow`] if receiver balance would overflow (extremely rare)
+        /// - Any error returned by [`Config::Compliance`]
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        ///
+        /// # Use Cases
+        ///
+        /// 1. **Primary distribution**: Treasury transfers to institutional investors
+        /// 2. **Secondary trading**: Investors trade tokens among themselves
+        /// 3. **Settlement**: Off-chain OTC trades settled on-chain
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Transfer 1,000 tokens (6 decimals) to another investor
+        /// // Raw amount = 1,000 * 10^6 = 1_000_000_000
+        /// CladToken::transfer(
+        ///     RuntimeOrigin::signed(sender_account),
+        ///     GENESIS_ASSET_ID,
+        ///     receiver_account,
+        ///     1_000_000_000
+        /// )?;
+        /// ```
+        ///
+        /// # Self-Transfer
+        ///
+        /// Transferring to yourself (`sender == to`) is allowed and emits a
+        /// `Transferred` event, but does not modify balances. This can be used
+        /// for accounting purposes or to verify account status.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn transfer(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            to: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_transfer(asset_id, sender, to, amount, None)
+        }
+
+        /// Freeze an account, preventing it from sending transfers.
+        ///
+        /// Frozen accounts retain their balance and can still receive tokens,
+        /// but cannot initiate outgoing transfers until unfrozen.
+        ///
+        /// # Permissions
+        ///
+        /// **Freezer** - Requires a signed holder of [`Role::Freezer`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::Freezer`] |
+        /// | `asset_id` | `AssetId` | Instrument the change applies to |
+        /// | `account` | `T::AccountId` | Account to freeze |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Frozen`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not a freezer
+        ///
+        /// # Use Cases
+        ///
+        /// 1. **Sanctions compliance**: Freeze accounts matching sanctions lists
+        /// 2. **Fraud prevention**: Halt transfers during investigation
+        /// 3. **Legal hold**: Preserve assets per court order
+        /// 4. **Account recovery**: Prevent unauthorized transfers after key compromise
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Freeze a suspicious account pending investigation
+        /// CladToken::freeze(RuntimeOrigin::signed(freezer), GENESIS_ASSET_ID, suspicious_account)?;
+        /// ```
+        ///
+        /// # Idempotency
+        ///
+        /// Freezing an already-frozen account is a no-op (succeeds without error).
+        /// This simplifies batch operations and retry logic.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::freeze())]
+        pub fn freeze(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::Freezer)?;
+            Self::ensure_instrument(asset_id)?;
+            Frozen::<T>::insert(asset_id, &account, true);
+            Self::deposit_event(Event::Frozen { asset_id, account });
+            Ok(())
+        }
+
+        /// Unfreeze an account, restoring its ability to send transfers.
+        ///
+        /// Removes the freeze flag from an account, allowing it to resume
+        /// normal transfer operations (assuming it remains whitelisted).
+        ///
+        /// # Permissions
+        ///
+        /// **Freezer** - Requires a signed holder of [`Role::Freezer`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::Freezer`] |
+        /// | `asset_id` | `AssetId` | Instrument the change applies to |
+        /// | `account` | `T::AccountId` | Account to unfreeze |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Unfrozen`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not a freezer
+        ///
+        /// # Use Cases
+        ///
+        /// 1. **Investigation cleared**: Restore access after compliance review
+        /// 2. **Sanctions delisted**: Account no longer on restricted lists
+        /// 3. **Legal release**: Court order lifted
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Unfreeze account after compliance review
+        /// CladToken::unfreeze(RuntimeOrigin::signed(freezer), GENESIS_ASSET_ID, cleared_account)?;
+        /// ```
+        ///
+        /// # Idempotency
+        ///
+        /// Unfreezing a non-frozen account is a no-op (succeeds without error).
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::unfreeze())]
+        pub fn unfreeze(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::Freezer)?;
+            Self::ensure_instrument(asset_id)?;
+            Frozen::<T>::remove(asset_id, &account);
+            Self::deposit_event(Event::Unfrozen { asset_id, account });
+            Ok(())
+        }
+
+        /// Add an account to the whitelist, allowing it to participate in transfers.
+        ///
+        /// Whitelisting represents KYC (Know Your Customer) approval. Only whitelisted
+        /// accounts can send or receive tokens, enforcing the identity verification
+        /// requirement of ERC-3643 compliant security tokens.
+        ///
+        /// # Permissions
+        ///
+        /// **Compliance agent** - Requires a signed holder of
+        /// [`Role::ComplianceAgent`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::ComplianceAgent`] |
+        /// | `asset_id` | `AssetId` | Instrument the change applies to |
+        /// | `account` | `T::AccountId` | Account to whitelist |
+        /// | `expires_at` | `Option<BlockNumberFor<T>>` | Block at which the approval lapses; `None` for no expiry |
+        /// | `country` | `Option<CountryCode>` | Investor's ISO 3166-1 numeric country; `None` if not applicable |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Whitelisted`] on success
+        /// - [`Event::InvestorCountrySet`] if the recorded country changed
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InvalidWhitelistExpiry`] if `expires_at` is not in the future
+        /// - [`Error::AccountRecovered`] if `account` was recovered to another address
+        /// - `BadOrigin` if caller is not a compliance agent
+        ///
+        /// # Use Cases
+        ///
+        /// 1. **KYC approval**: Approve investor after identity verification
+        /// 2. **Institutional onboarding**: Add new institutional investors
+        /// 3. **Treasury setup**: Whitelist ministry/issuer accounts
+        ///
+        /// # Typical Workflow
+        ///
+        /// ```text
+        /// 1. Investor submits KYC documents via off-chain process
+        /// 2. Compliance team verifies identity and eligibility
+        /// 3. Admin adds investor to whitelist
+        /// 4. Investor can now receive tokens from treasury
+        /// 5. Investor can trade with other whitelisted accounts
+        /// ```
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Whitelist a new institutional investor for one year (6s blocks)
+        /// let expires_at = frame_system::Pallet::<T>::block_number() + 5_256_000;
+        /// CladToken::add_to_whitelist(
+        ///     RuntimeOrigin::signed(compliance_agent),
+        ///     GENESIS_ASSET_ID,
+        ///     investor_account,
+        ///     Some(expires_at),
+        ///     Some(398), // Kazakhstan
+        /// )?;
+        /// ```
+        ///
+        /// # Renewal
+        ///
+        /// Whitelisting an already-whitelisted account replaces its expiry and
+        /// country. This is how periodic KYC re-verification is recorded; a change of
+        /// residence moves the investor between [`HoldersPerCountry`] buckets.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::add_to_whitelist())]
+        pub fn add_to_whitelist(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            account: T::AccountId,
+            expires_at: Option<BlockNumberFor<T>>,
+            country: Option<CountryCode>,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::ComplianceAgent)?;
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_whitelist_expiry(expires_at)?;
+            Self::do_add_to_whitelist(asset_id, account, expires_at, country)
+        }
+
+        /// Remove an account from the whitelist, preventing it from participating in transfers.
+        ///
+        /// The account will no longer be able to send or receive tokens. However,
+        /// any existing balance is preserved—tokens are not confiscated.
+        ///
+        /// # Permissions
+        ///
+        /// **Compliance agent** - Requires a signed holder of
+        /// [`Role::ComplianceAgent`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::ComplianceAgent`] |
+        /// | `asset_id` | `AssetId` | Instrument the change applies to |
+        /// | `account` | `T::AccountId` | Account to remove from whitelist |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::RemovedFromWhitelist`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not a compliance agent
+        ///
+        /// # Use Cases
+        ///
+        /// 1. **KYC expiration**: Remove investors with expired verification
+        /// 2. **Voluntary exit**: Investor requests removal from platform
+        /// 3. **Compliance failure**: Investor no longer meets eligibility criteria
+        ///
+        /// # Important: Token Preservation
+        ///
+        /// Removing from whitelist does **NOT** confiscate tokens. The account
+        /// retains its balance but cannot move it. For full offboarding:
+        ///
+        /// ```text
+        /// 1. Coordinate with investor to transfer tokens to treasury
+        /// 2. Remove account from whitelist
+        /// 3. Process any fiat redemption off-chain
+        /// ```
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Remove investor with expired KYC
+        /// CladToken::remove_from_whitelist(RuntimeOrigin::signed(compliance_agent), GENESIS_ASSET_ID, expired_investor)?;
+        /// ```
+        ///
+        /// # Idempotency
+        ///
+        /// Removing a non-whitelisted account is a no-op.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::remove_from_whitelist())]
+        pub fn remove_from_whitelist(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::ComplianceAgent)?;
+            Self::ensure_instrument(asset_id)?;
+            Whitelist::<T>::remove(asset_id, &account);
+            Self::deposit_event(Event::RemovedFromWhitelist { asset_id, account });
+            Ok(())
+        }
+
+        /// Transfer admin privileges to a new account.
+        ///
+        /// Sets a new admin account in storage, allowing admin rotation without
+        /// a runtime upgrade. This is essential for production deployments where
+        /// ministry committees have personnel changes.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// In practice, this means:
+        /// - Root origin can always set a new admin (emergency recovery)
+        /// - The current multi-sig admin can rotate to a new multi-sig
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `new_admin` | `T::AccountId` | Account to become the new admin |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::AdminChanged`] on success
+        /// - [`Event::Whitelisted`] for the new admin, once per registered instrument
+        ///   (auto-whitelisted)
+        ///
+        /// # Errors
+        ///
+        /// - `BadOrigin` if caller is not current admin or root
+        ///
+        /// # Use Cases
+        ///
+        /// 1. **Personnel change**: Ministry official retires, committee rotates keys
+        /// 2. **Threshold change**: Upgrade from 2-of-3 to 3-of-5 multi-sig
+        /// 3. **Emergency recovery**: Root sets new admin after key compromise
+        /// 4. **Initial setup**: Set explicit admin after genesis (from None)
+        ///
+        /// # Workflow Example
+        ///
+        /// ```text
+        /// // Current admin: 2-of-3 multi-sig (Alice, Bob, Charlie)
+        /// // New admin: 3-of-5 multi-sig (Alice, Bob, Charlie, Dave, Eve)
+        ///
+        /// 1. Create new 3-of-5 multi-sig address off-chain
+        /// 2. Current 2-of-3 multi-sig approves set_admin(new_multisig)
+        /// 3. AdminChanged event emitted
+        /// 4. New multi-sig is auto-whitelisted on every instrument
+        /// 5. Old multi-sig remains whitelisted (can still receive tokens)
+        /// ```
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Rotate to new multi-sig address
+        /// CladToken::set_admin(
+        ///     RuntimeOrigin::signed(current_multisig),  // Via multi-sig approval
+        ///     new_multisig_account
+        /// )?;
+        /// ```
+        ///
+        /// # Security Considerations
+        ///
+        /// - The new admin is automatically whitelisted on every registered instrument
+        ///   to ensure it can receive tokens if needed (e.g., treasury operations).
+        ///   The work is bounded by [`Config::MaxInstruments`].
+        /// - The old admin is NOT automatically removed from whitelist—this
+        ///   preserves their ability to hold tokens they may already have
+        /// - Roles are independent of the admin: the old admin keeps every
+        ///   [`Role`] it holds until a role admin revokes it
+        /// - Consider implementing a timelock for admin changes in high-security
+        ///   deployments (future enhancement)
+        /// - Root origin should be protected by sudo or similar mechanism
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_admin(T::MaxInstruments::get()))]
+        pub fn set_admin(origin: OriginFor<T>, new_admin: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            // Get current admin for event
+            let old_admin = Admin::<T>::get();
+
+            // Set new admin in storage
+            Admin::<T>::put(&new_admin);
+
+            // Emit events
+            Self::deposit_event(Event::AdminChanged { old_admin, new_admin: new_admin.clone() });
+
+            // Auto-whitelist new admin on every instrument so they can receive tokens if needed
+            for asset_id in 0..NextAssetId::<T>::get() {
+                Whitelist::<T>::insert(asset_id, &new_admin, WhitelistEntry::permanent());
+                Self::deposit_event(Event::Whitelisted {
+                    asset_id,
+                    account: new_admin.clone(),
+                    expires_at: None,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Register a new instrument (bond series, T-bill, equity class).
+        ///
+        /// Assigns the next free [`AssetId`] and stores the instrument metadata and
+        /// authorised amount. The instrument starts with zero supply and an empty
+        /// whitelist, except for the current storage admin (if any), which is
+        /// auto-whitelisted so it can act as the issuing treasury.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `name` | `BoundedVec<u8, 64>` | Human-readable instrument name |
+        /// | `symbol` | `BoundedVec<u8, 16>` | Trading symbol |
+        /// | `decimals` | `u8` | Decimal precision |
+        /// | `max_supply` | `Option<u128>` | Authorised amount (raw value), `None` for uncapped |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::InstrumentCreated`] on success
+        /// - [`Event::Whitelisted`] for the current admin, if one is set
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::TooManyInstruments`] if [`Config::MaxInstruments`] is reached
+        /// - `BadOrigin` if caller is not admin
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Open a new 10-year series alongside the genesis 5-year bond
+        /// CladToken::create_instrument(
+        ///     RawOrigin::Root.into(),
+        ///     b"Kazakhstan Sovereign Bond 2035".to_vec().try_into().unwrap(),
+        ///     b"KZT-BOND-2035".to_vec().try_into().unwrap(),
+        ///     6,
+        ///     // 500M authorised by the prospectus
+        ///     Some(500_000_000_000_000),
+        /// )?;
+        /// // The new asset id is reported in `Event::InstrumentCreated`.
+        /// ```
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::create_instrument())]
+        pub fn create_instrument(
+            origin: OriginFor<T>,
+            name: TokenNameOf,
+            symbol: TokenSymbolOf,
+            decimals: u8,
+            max_supply: Option<u128>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let asset_id = NextAssetId::<T>::get();
+            ensure!(asset_id < T::MaxInstruments::get(), Error::<T>::TooManyInstruments);
+
+            TokenName::<T>::insert(asset_id, name);
+            TokenSymbol::<T>::insert(asset_id, &symbol);
+            Decimals::<T>::insert(asset_id, decimals);
+            MaxSupply::<T>::set(asset_id, max_supply);
+            NextAssetId::<T>::put(asset_id + 1);
+            Self::deposit_event(Event::InstrumentCreated {
+                asset_id,
+                symbol,
+                decimals,
+                max_supply,
+            });
+
+            // Auto-whitelist the current admin, mirroring genesis behaviour
+            if let Some(admin) = Admin::<T>::get() {
+                Whitelist::<T>::insert(asset_id, &admin, WhitelistEntry::permanent());
+                Self::deposit_event(Event::Whitelisted {
+                    asset_id,
+                    account: admin,
+                    expires_at: None,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Destroy tokens held by an account.
+        ///
+        /// Debits `amount` from `from` and reduces the total supply by the same
+        /// amount. Used to cancel bonds the issuer has bought back.
+        ///
+        /// # Permissions
+        ///
+        /// **Minter** - Requires a signed holder of [`Role::Minter`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::Minter`] |
+        /// | `asset_id` | `AssetId` | Instrument to burn |
+        /// | `from` | `T::AccountId` | Account whose tokens are destroyed |
+        /// | `amount` | `u128` | Number of tokens to destroy (raw value) |
+        ///
+        /// # Compliance Checks
+        ///
+        /// The same holder checks as an outgoing transfer apply:
+        /// - `from` is whitelisted on `asset_id`
+        /// - `from` is not frozen on `asset_id`
+        ///
+        /// A frozen holding therefore cannot be burned until it is unfrozen.
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Burned`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::CouponDistributionInProgress`] while a coupon is being paid
+        /// - [`Error::NotWhitelisted`] if `from` is not on the whitelist
+        /// - [`Error::MissingClaim`] if `from` lacks a claim the instrument requires
+        /// - [`Error::AccountFrozen`] if `from` is frozen
+        /// - [`Error::InsufficientBalance`] if `amount > balance`
+        /// - [`Error::InsufficientUnfrozenBalance`] if `amount` exceeds the non-frozen balance
+        /// - [`Error::InsufficientVestedBalance`] if `amount` exceeds the vested balance
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        /// - `BadOrigin` if caller is not a minter
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Cancel $5M of bonds bought back into the treasury (6 decimals)
+        /// CladToken::burn(
+        ///     RuntimeOrigin::signed(minter),
+        ///     GENESIS_ASSET_ID,
+        ///     treasury_account,
+        ///     5_000_000_000_000
+        /// )?;
+        /// ```
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::burn())]
+        pub fn burn(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            from: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::Minter)?;
+            Self::do_burn(asset_id, &from, amount)?;
+            Self::deposit_event(Event::Burned { asset_id, from, amount });
+            Ok(())
+        }
+
+        /// Redeem (destroy) tokens held by the caller.
+        ///
+        /// Holder-initiated counterpart of [`burn`](Pallet::burn), used for early
+        /// redemptions. The issuer pays out the redemption proceeds off-chain
+        /// against the [`Event::Redeemed`] record.
+        ///
+        /// # Permissions
+        ///
+        /// **Signed** - Any whitelisted, non-frozen holder.
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed origin (the holder) |
+        /// | `asset_id` | `AssetId` | Instrument to redeem |
+        /// | `amount` | `u128` | Number of tokens to redeem (raw value) |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Redeemed`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::CouponDistributionInProgress`] while a coupon is being paid
+        /// - [`Error::TokenPaused`] if the instrument is paused
+        /// - [`Error::NotWhitelisted`] if the caller is not on the whitelist
+        /// - [`Error::MissingClaim`] if the caller lacks a claim the instrument requires
+        /// - [`Error::AccountFrozen`] if the caller is frozen
+        /// - [`Error::InsufficientBalance`] if `amount > balance`
+        /// - [`Error::InsufficientUnfrozenBalance`] if `amount` exceeds the non-frozen balance
+        /// - [`Error::InsufficientVestedBalance`] if `amount` exceeds the vested balance
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Investor redeems 1,000 tokens (6 decimals) early
+        /// CladToken::redeem(
+        ///     RuntimeOrigin::signed(investor_account),
+        ///     GENESIS_ASSET_ID,
+        ///     1_000_000_000
+        /// )?;
+        /// ```
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::redeem())]
+        pub fn redeem(origin: OriginFor<T>, asset_id: AssetId, amount: u128) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused(asset_id)?;
+            Self::do_burn(asset_id, &who, amount)?;
+            Self::deposit_event(Event::Redeemed { asset_id, who, amount });
+            Ok(())
+        }
+
+        /// Move tokens between accounts without the holder's signature.
+        ///
+        /// Implements ERC-3643 `forcedTransfer`. Used to execute court orders,
+        /// settle inheritance cases and reverse mistaken transfers without a
+        /// burn-and-remint cycle that would distort supply history.
+        ///
+        /// # Permissions
+        ///
+        /// **Compliance agent** - Requires a signed holder of
+        /// [`Role::ComplianceAgent`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::ComplianceAgent`] |
+        /// | `asset_id` | `AssetId` | Instrument to move |
+        /// | `from` | `T::AccountId` | Account tokens are taken from |
+        /// | `to` | `T::AccountId` | Account tokens are credited to |
+        /// | `amount` | `u128` | Number of tokens to move (raw value) |
+        /// | `reason` | `ForcedTransferReason` | Reason code and external reference |
+        ///
+        /// # Compliance Checks
+        ///
+        /// - `to` must be whitelisted on `asset_id`; tokens are never forced onto an
+        ///   account without KYC approval
+        /// - `to` must pass the same receiver checks as a transfer: its country must
+        ///   not be blocked (or must be allowed), it must not push the instrument
+        ///   over [`MaxHolders`], and its new balance must respect its holding limit
+        /// - `from` may be frozen or removed from the whitelist; that is usually the
+        ///   reason the transfer has to be forced
+        /// - Partially frozen tokens may be moved; `FrozenAmount` of `from` is reduced
+        ///   to its remaining balance and [`Event::TokensUnfrozen`] is emitted
+        /// - [`Config::Compliance`] is not asked for permission, but is notified via
+        ///   `transferred` so its state stays in sync
+        ///
+        /// # Events
+        ///
+        /// - [`Event::ForcedTransfer`] on success
+        /// - [`Event::TokensUnfrozen`] if frozen tokens had to be moved
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InstrumentMatured`] once the instrument has been fully redeemed
+        /// - [`Error::CouponDistributionInProgress`] while a coupon is being paid
+        /// - [`Error::NotWhitelisted`] if `to` is not on the whitelist
+        /// - [`Error::MissingClaim`] if `to` lacks a claim the instrument requires
+        /// - [`Error::CountryBlocked`] if `to`'s country is blocked
+        /// - [`Error::CountryNotAllowed`] if an allow-list is set and `to`'s country
+        ///   is not on it
+        /// - [`Error::TooManyHolders`] if `to` would exceed [`MaxHolders`]
+        /// - [`Error::HoldingLimitExceeded`] if `to` would exceed its holding limit
+        /// - [`Error::InsufficientBalance`] if `amount > from_balance`
+        /// - [`Error::Overflow`] if `to_balance + amount > u128::MAX`
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        /// - `BadOrigin` if caller is not a compliance agent
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Court order: move a frozen holder's position to the estate account
+        /// CladToken::forced_transfer(
+        ///     RuntimeOrigin::signed(compliance_agent),
+        ///     GENESIS_ASSET_ID,
+        ///     frozen_holder,
+        ///     estate_account,
+        ///     1_000_000_000,
+        ///     ForcedTransferReason {
+        ///         code: ForcedTransferCode::CourtOrder,
+        ///         reference: b"ALM-2025-CV-0142".to_vec().try_into().unwrap(),
+        ///     },
+        /// )?;
+        /// ```
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::forced_transfer())]
+        pub fn forced_transfer(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: u128,
+            reason: ForcedTransferReason,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::ComplianceAgent)?;
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_not_matured(asset_id)?;
+            Self::ensure_no_coupon_distribution(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+            Self::ensure_verified(asset_id, &to)?;
+            Self::ensure_country_allowed(asset_id, &to)?;
+            Self::ensure_holder_capacity(asset_id, Some(&from), &to, amount)?;
+            if from != to {
+                let new_balance = Balances::<T>::get(asset_id, &to).saturating_add(amount);
+                let supply = TotalSupply::<T>::get(asset_id);
+                Self::ensure_within_holding_limit(asset_id, &to, new_balance, supply)?;
+            }
+
+            Self::move_balance(asset_id, &from, &to, amount)?;
+            T::Compliance::transferred(asset_id, &from, &to, amount);
+
+            // ERC-3643: frozen tokens that were moved are no longer frozen
+            let frozen = FrozenAmount::<T>::get(asset_id, &from);
+            let remaining = Balances::<T>::get(asset_id, &from);
+            if frozen > remaining {
+                Self::set_frozen_amount(asset_id, &from, remaining);
+                Self::deposit_event(Event::TokensUnfrozen {
+                    asset_id,
+                    account: from.clone(),
+                    amount: frozen - remaining,
+                });
+            }
+
+            Self::deposit_event(Event::ForcedTransfer { asset_id, from, to, amount, reason });
+            Ok(())
+        }
+
+        /// Freeze part of an account's balance.
+        ///
+        /// Implements ERC-3643 `freezePartialTokens`. Adds `amount` to the account's
+        /// [`FrozenAmount`]; the holder keeps full use of the remaining balance.
+        ///
+        /// # Vesting
+        ///
+        /// The freeze is checked against the raw balance, so tokens still locked by a
+        /// vesting schedule can be frozen (e.g. pledged employee shares). The two
+        /// restrictions add up: while both apply, the holder can spend
+        /// `balance - frozen - locked`.
+        ///
+        /// # Permissions
+        ///
+        /// **Freezer** - Requires a signed holder of [`Role::Freezer`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::Freezer`] |
+        /// | `asset_id` | `AssetId` | Instrument the freeze applies to |
+        /// | `account` | `T::AccountId` | Account whose tokens are frozen |
+        /// | `amount` | `u128` | Number of additional tokens to freeze (raw value) |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::TokensFrozen`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InsufficientBalance`] if `amount > balance`
+        /// - [`Error::InsufficientUnfrozenBalance`] if `amount` exceeds the non-frozen
+        ///   balance (vesting locks are not deducted)
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        /// - `BadOrigin` if caller is not a freezer
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Lock 2,000 tokens pledged as margin collateral
+        /// CladToken::freeze_partial(
+        ///     RuntimeOrigin::signed(freezer),
+        ///     GENESIS_ASSET_ID,
+        ///     investor_account,
+        ///     2_000_000_000
+        /// )?;
+        /// ```
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::freeze_partial())]
+        pub fn freeze_partial(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            account: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::Freezer)?;
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+            // Vesting locks are ignored: locked tokens may be frozen as well
+            let balance = Balances::<T>::get(asset_id, &account);
+            ensure!(balance >= amount, Error::<T>::InsufficientBalance);
+            let frozen = FrozenAmount::<T>::get(asset_id, &account);
+            ensure!(
+                balance.saturating_sub(frozen) >= amount,
+                Error::<T>::InsufficientUnfrozenBalance
+            );
+
+            // Cannot overflow: frozen + amount <= balance
+            Self::set_frozen_amount(asset_id, &account, frozen + amount);
+            Self::deposit_event(Event::TokensFrozen { asset_id, account, amount });
+            Ok(())
+        }
+
+        /// Release partially frozen tokens.
+        ///
+        /// Implements ERC-3643 `unfreezePartialTokens`. Subtracts `amount` from the
+        /// account's [`FrozenAmount`].
+        ///
+        /// # Permissions
+        ///
+        /// **Freezer** - Requires a signed holder of [`Role::Freezer`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::Freezer`] |
+        /// | `asset_id` | `AssetId` | Instrument the release applies to |
+        /// | `account` | `T::AccountId` | Account whose tokens are released |
+        /// | `amount` | `u128` | Number of tokens to release (raw value) |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::TokensUnfrozen`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InsufficientFrozenBalance`] if `amount > frozen_amount`
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        /// - `BadOrigin` if caller is not a freezer
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::unfreeze_partial())]
+        pub fn unfreeze_partial(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            account: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::Freezer)?;
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+
+            let frozen = FrozenAmount::<T>::get(asset_id, &account);
+            ensure!(frozen >= amount, Error::<T>::InsufficientFrozenBalance);
+
+            Self::set_frozen_amount(asset_id, &account, frozen - amount);
+            Self::deposit_event(Event::TokensUnfrozen { asset_id, account, amount });
+            Ok(())
+        }
+
+        /// Pause an instrument (kill switch).
+        ///
+        /// Blocks [`transfer`](Pallet::transfer) and [`redeem`](Pallet::redeem) for
+        /// every holder at once, and [`mint`](Pallet::mint) if
+        /// [`Config::PauseBlocksMinting`] is set. Admin remediation calls keep working.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to pause |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Paused`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not admin
+        ///
+        /// # Note
+        ///
+        /// Pausing an already paused instrument succeeds and emits the event again.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::pause())]
+        pub fn pause(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            Paused::<T>::insert(asset_id, true);
+            Self::deposit_event(Event::Paused { asset_id });
+            Ok(())
+        }
+
+        /// Resume a paused instrument.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to resume |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Unpaused`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not admin
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::unpause())]
+        pub fn unpause(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            Paused::<T>::remove(asset_id);
+            Self::deposit_event(Event::Unpaused { asset_id });
+            Ok(())
+        }
+
+        /// Register a trusted claim issuer, or replace the topics it may attest.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `issuer` | `T::AccountId` | KYC provider or transfer agent |
+        /// | `topics` | `BoundedVec<ClaimTopic, MaxClaimTopics>` | Topics the issuer may attest |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::TrustedIssuerAdded`] on success
+        ///
+        /// # Errors
+        ///
+        /// - `BadOrigin` if caller is not admin
+        ///
+        /// # Note
+        ///
+        /// Claims the issuer attested for topics no longer in `topics` stop being
+        /// valid immediately.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::add_trusted_issuer())]
+        pub fn add_trusted_issuer(
+            origin: OriginFor<T>,
+            issuer: T::AccountId,
+            topics: ClaimTopicsOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            TrustedIssuers::<T>::insert(&issuer, &topics);
+            Self::deposit_event(Event::TrustedIssuerAdded { issuer, topics });
+            Ok(())
+        }
+
+        /// Revoke a trusted claim issuer.
+        ///
+        /// Every claim attested by `issuer` stops being valid immediately; the claims
+        /// themselves stay in storage until overwritten or removed.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Events
+        ///
+        /// - [`Event::TrustedIssuerRemoved`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::NotTrustedIssuer`] if `issuer` is not registered
+        /// - `BadOrigin` if caller is not admin
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::remove_trusted_issuer())]
+        pub fn remove_trusted_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(TrustedIssuers::<T>::contains_key(&issuer), Error::<T>::NotTrustedIssuer);
+            TrustedIssuers::<T>::remove(&issuer);
+            Self::deposit_event(Event::TrustedIssuerRemoved { issuer });
+            Ok(())
+        }
+
+        /// Attest a claim about an account.
+        ///
+        /// Replaces any existing claim on the same topic, so issuers renew a claim by
+        /// calling this again with a later expiry.
+        ///
+        /// # Permissions
+        ///
+        /// **Trusted issuer** - The caller must be trusted for `topic`.
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed origin (the issuer) |
+        /// | `subject` | `T::AccountId` | Account the claim is about |
+        /// | `topic` | `ClaimTopic` | Claim topic (e.g. KYC, accredited investor) |
+        /// | `expires_at` | `BlockNumberFor<T>` | First block at which the claim is invalid |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::ClaimAdded`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::NotTrustedIssuer`] if the caller is not trusted for `topic`
+        /// - [`Error::InvalidClaimExpiry`] if `expires_at` is not in the future
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::add_claim())]
+        pub fn add_claim(
+            origin: OriginFor<T>,
+            subject: T::AccountId,
+            topic: ClaimTopic,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let issuer = ensure_signed(origin)?;
+            ensure!(
+                TrustedIssuers::<T>::get(&issuer).contains(&topic),
+                Error::<T>::NotTrustedIssuer
+            );
+            ensure!(
+                expires_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidClaimExpiry
+            );
+
+            Claims::<T>::insert(&subject, topic, Claim { issuer: issuer.clone(), expires_at });
+            Self::deposit_event(Event::ClaimAdded { subject, topic, issuer, expires_at });
+            Ok(())
+        }
+
+        /// Revoke a claim about an account.
+        ///
+        /// # Permissions
+        ///
+        /// **Issuer or Admin** - The issuer that attested the claim, or
+        /// [`Config::AdminOrigin`].
+        ///
+        /// # Events
+        ///
+        /// - [`Event::ClaimRemoved`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::ClaimNotFound`] if `subject` has no claim on `topic`
+        /// - `BadOrigin` if caller is neither the claim's issuer nor admin
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::remove_claim())]
+        pub fn remove_claim(
+            origin: OriginFor<T>,
+            subject: T::AccountId,
+            topic: ClaimTopic,
+        ) -> DispatchResult {
+            let caller = match T::AdminOrigin::try_origin(origin) {
+                Ok(_) => None,
+                Err(origin) => Some(ensure_signed(origin)?),
+            };
+            let claim = Claims::<T>::get(&subject, topic).ok_or(Error::<T>::ClaimNotFound)?;
+            if let Some(caller) = caller {
+                ensure!(caller == claim.issuer, DispatchError::BadOrigin);
+            }
+
+            Claims::<T>::remove(&subject, topic);
+            Self::deposit_event(Event::ClaimRemoved { subject, topic });
+            Ok(())
+        }
+
+        /// Set the claim topics required to hold an instrument.
+        ///
+        /// Applies to every subsequent transfer, burn, redemption and forced
+        /// transfer. Existing holders without the new claims keep their balance but
+        /// cannot move it until they obtain them.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to configure |
+        /// | `topics` | `BoundedVec<ClaimTopic, MaxClaimTopics>` | Required topics; empty for whitelist only |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::RequiredClaimTopicsSet`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not admin
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::set_required_claim_topics())]
+        pub fn set_required_claim_topics(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            topics: ClaimTopicsOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            if topics.is_empty() {
+                RequiredClaimTopics::<T>::remove(asset_id);
+            } else {
+                RequiredClaimTopics::<T>::insert(asset_id, &topics);
+            }
+            Self::deposit_event(Event::RequiredClaimTopicsSet { asset_id, topics });
+            Ok(())
+        }
+
+        /// Restrict an instrument to the listed jurisdictions.
+        ///
+        /// Replaces the whole allow list. Only affects future transfers; existing
+        /// holders outside the list keep their tokens.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to configure |
+        /// | `countries` | `BoundedVec<CountryCode, MaxCountries>` | Allowed countries; empty to allow all |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::AllowedCountriesSet`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not admin
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::set_allowed_countries())]
+        pub fn set_allowed_countries(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            countries: CountriesOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            if countries.is_empty() {
+                AllowedCountries::<T>::remove(asset_id);
+            } else {
+                AllowedCountries::<T>::insert(asset_id, &countries);
+            }
+            Self::deposit_event(Event::AllowedCountriesSet { asset_id, countries });
+            Ok(())
+        }
+
+        /// Bar the listed jurisdictions from receiving an instrument.
+        ///
+        /// Replaces the whole block list. Only affects future transfers; existing
+        /// holders in a blocked jurisdiction keep their tokens and may still sell
+        /// them to eligible investors.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to configure |
+        /// | `countries` | `BoundedVec<CountryCode, MaxCountries>` | Blocked countries; empty to block none |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::BlockedCountriesSet`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not admin
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_blocked_countries())]
+        pub fn set_blocked_countries(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            countries: CountriesOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            if countries.is_empty() {
+                BlockedCountries::<T>::remove(asset_id);
+            } else {
+                BlockedCountries::<T>::insert(asset_id, &countries);
+            }
+            Self::deposit_event(Event::BlockedCountriesSet { asset_id, countries });
+            Ok(())
+        }
+
+        /// Cap the number of holders of an instrument.
+        ///
+        /// Applies to future mints and transfers only. A cap below the current
+        /// [`HolderCount`] is accepted: existing holders keep their tokens, and no
+        /// new holder can join until enough have exited.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to configure |
+        /// | `max_holders` | `Option<u32>` | Holder cap; `None` to remove it |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::MaxHoldersSet`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not admin
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::set_max_holders())]
+        pub fn set_max_holders(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            max_holders: Option<u32>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            MaxHolders::<T>::set(asset_id, max_holders);
+            Self::deposit_event(Event::MaxHoldersSet { asset_id, max_holders });
+            Ok(())
+        }
+
+        /// Limit the balance any single account may hold of an instrument.
+        ///
+        /// Applies to future mints and transfers only; holders already above the
+        /// limit keep their tokens but cannot receive more. Grant the treasury an
+        /// override with [`Pallet::set_account_holding_limit`] before minting the
+        /// issue to it.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to configure |
+        /// | `limit` | `Option<HoldingLimit>` | Absolute amount or share of supply; `None` to remove |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::MaxBalancePerHolderSet`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not admin
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Retail tranche: no investor above 2% of the issue
+        /// CladToken::set_max_balance_per_holder(
+        ///     admin_origin,
+        ///     asset_id,
+        ///     Some(HoldingLimit::ShareOfSupply(Perbill::from_percent(2))),
+        /// )?;
+        /// ```
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::set_max_balance_per_holder())]
+        pub fn set_max_balance_per_holder(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            limit: Option<HoldingLimit>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            MaxBalancePerHolder::<T>::set(asset_id, limit);
+            Self::deposit_event(Event::MaxBalancePerHolderSet { asset_id, limit });
+            Ok(())
+        }
+
+        /// Override the holding limit for one account.
+        ///
+        /// The override replaces [`MaxBalancePerHolder`] for `account`, whether it
+        /// is higher (anchor investor, treasury) or lower. Setting it does not make
+        /// `account` a holder; a later [`Pallet::split`] rescales it either way.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument the override applies to |
+        /// | `account` | `T::AccountId` | Investor to override |
+        /// | `limit` | `Option<HoldingLimit>` | Override; `None` to fall back to the instrument limit |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::AccountHoldingLimitSet`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        /// - `BadOrigin` if caller is not admin
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::set_account_holding_limit())]
+        pub fn set_account_holding_limit(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            account: T::AccountId,
+            limit: Option<HoldingLimit>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+            AccountHoldingLimit::<T>::set(asset_id, &account, limit);
+            Self::deposit_event(Event::AccountHoldingLimitSet { asset_id, account, limit });
+            Ok(())
+        }
+
+        /// Mint tokens that are locked up and released over time.
+        ///
+        /// Behaves like [`Pallet::mint`], and additionally records a vesting lock
+        /// on the minted amount. Until released by `schedule`, the tokens count
+        /// towards the holder's balance (and holder limits) but cannot be
+        /// transferred, burned or redeemed.
+        ///
+        /// # Permissions
+        ///
+        /// **Minter** - Requires a signed holder of [`Role::Minter`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::Minter`] |
+        /// | `asset_id` | `AssetId` | Instrument to mint |
+        /// | `to` | `T::AccountId` | Beneficiary |
+        /// | `amount` | `u128` | Number of tokens to create (raw value) |
+        /// | `schedule` | `VestingSchedule` | Start, cliff and end blocks |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Minted`] and [`Event::VestingScheduleAdded`] on success
+        /// - [`Event::MintScheduled`] instead, for a time-locked mint
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::InvalidVestingSchedule`] unless `start <= cliff <= end`
+        /// - [`Error::TooManyVestingSchedules`] if `to` already has
+        ///   [`Config::MaxVestingSchedules`] unvested schedules
+        /// - Any error of [`Pallet::mint`]
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        ///
+        /// # Time Lock
+        ///
+        /// Large amounts are time-locked exactly like in [`Pallet::mint`]; the lock-up
+        /// is recorded when the mint executes.
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // Employee allocation: one-year cliff, then monthly-equivalent linear
+        /// // release over four years (6-second blocks)
+        /// const YEAR: u32 = 5_256_000;
+        /// CladToken::mint_with_schedule(
+        ///     RuntimeOrigin::signed(minter),
+        ///     equity_id,
+        ///     employee,
+        ///     10_000_000_000,
+        ///     VestingSchedule { start: now, cliff: now + YEAR, end: now + 4 * YEAR },
+        /// )?;
+        /// ```
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::mint_with_schedule().max(T::WeightInfo::schedule_mint()))]
+        pub fn mint_with_schedule(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            to: T::AccountId,
+            amount: u128,
+            schedule: VestingScheduleOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::Minter)?;
+            ensure!(schedule.is_valid(), Error::<T>::InvalidVestingSchedule);
+            Self::mint_or_schedule(asset_id, to, amount, Some(schedule))
+        }
+
+        /// Set the bond terms of an instrument.
+        ///
+        /// Terms can be amended (e.g. after a restructuring), but coupons already
+        /// paid keep their index in `coupon_dates`, so the amended list must be at
+        /// least as long as [`NextCoupon`].
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to configure |
+        /// | `terms` | `BondTerms` | Face value, coupon rate, coupon dates and maturity |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::BondTermsSet`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InvalidBondTerms`] if the dates are out of order or too few
+        /// - [`Error::CouponDistributionInProgress`] while a coupon is being paid
+        /// - `BadOrigin` if caller is not admin
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // 2-year note, 6% a year paid semi-annually, face value 1 native token
+        /// // (12 decimals) per token (6 decimals); 6-second blocks
+        /// const HALF_YEAR: u32 = 2_628_000;
+        /// CladToken::set_bond_terms(
+        ///     admin_origin,
+        ///     asset_id,
+        ///     BondTerms {
+        ///         face_value: 1_000_000,
+        ///         coupon_rate: Perbill::from_percent(3),
+        ///         coupon_dates: (1..=4).map(|i| now + i * HALF_YEAR).collect::<Vec<_>>().try_into()?,
+        ///         maturity: now + 4 * HALF_YEAR,
+        ///     },
+        /// )?;
+        /// ```
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::set_bond_terms())]
+        pub fn set_bond_terms(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            terms: BondTermsOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            ensure!(
+                terms.is_valid()
+                    && terms.coupon_dates.len() as u32 >= NextCoupon::<T>::get(asset_id),
+                Error::<T>::InvalidBondTerms
+            );
+            Self::ensure_no_coupon_distribution(asset_id)?;
+
+            Bonds::<T>::insert(asset_id, &terms);
+            Self::deposit_event(Event::BondTermsSet { asset_id, terms });
+            Ok(())
+        }
+
+        /// Pay the due coupon to the next [`Config::MaxHoldersPerPage`] holders.
+        ///
+        /// The first call on or after a coupon date checks that the coupon pot
+        /// covers the coupon on the whole supply and opens the payment. Each call
+        /// then pays the next page of holders `coupon_rate * face_value * balance`
+        /// from [`Pallet::coupon_pot`]; the call that reaches the last holder
+        /// closes the payment. Balances of the instrument cannot change until then.
+        ///
+        /// Permissionless, so a keeper bot or the scheduler can drive it; only the
+        /// weight actually used is charged.
+        ///
+        /// # Permissions
         ///
-        /// # Fields
+        /// **Any signed account**.
         ///
-        /// - `from`: The sender's account ID (tokens debited)
-        /// - `to`: The receiver's account ID (tokens credited)
-        /// - `amount`: Number of tokens transferred (raw value, apply decimals for display)
+        /// # Parameters
         ///
-        /// # Indexing Notes
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Any signed account |
+        /// | `asset_id` | `AssetId` | Instrument whose coupon is paid |
         ///
-        /// - Index by `from` to track outgoing transfers
-        /// - Index by `to` to track incoming transfers
-        /// - Sum `amount` values to calculate volume metrics
+        /// # Events
         ///
-        /// # Example Event Data
+        /// - [`Event::CouponDistributionStarted`] when the payment opens
+        /// - [`Event::CouponPaid`] for every holder paid
+        /// - [`Event::CouponPaymentFailed`] for every holder that could not be paid;
+        ///   the coupon is recorded in [`UnpaidCoupons`]
+        /// - [`Event::CouponDistributed`] when the last holder has been visited
         ///
-        /// ```ignore
-        /// // Block explorer display
-        /// {
-        ///     "event": "Transferred",
-        ///     "from": "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
-        ///     "to": "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty",
-        ///     "amount": "1000000000000"  // 1,000,000 tokens with 6 decimals
-        /// }
-        /// ```
-        Transferred {
-            /// Account that sent the tokens.
-            from: T::AccountId,
-            /// Account that received the tokens.
-            to: T::AccountId,
-            /// Amount of tokens transferred (raw u128 value).
-            amount: u128,
-        },
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::NoBondTerms`] if the instrument has no bond terms
+        /// - [`Error::NoCouponDue`] if every coupon has been paid
+        /// - [`Error::CouponNotDue`] before the next coupon date
+        /// - [`Error::InsufficientCouponFunds`] if the pot cannot cover the coupon
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::distribute_coupon(T::MaxHoldersPerPage::get()))]
+        pub fn distribute_coupon(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            let terms = Bonds::<T>::get(asset_id).ok_or(Error::<T>::NoBondTerms)?;
+            let progress = match CouponDistribution::<T>::get(asset_id) {
+                Some(progress) => progress,
+                None => Self::open_coupon(asset_id, &terms)?,
+            };
 
-        /// New tokens were created and credited to an account.
+            let visited =
+                Self::pay_coupons(asset_id, &terms, progress, T::MaxHoldersPerPage::get());
+            Ok(Some(T::WeightInfo::distribute_coupon(visited)).into())
+        }
+
+        /// Repay principal at maturity and burn the redeemed tokens.
+        ///
+        /// Each redeemed token pays [`BondTerms::face_value`] of native currency
+        /// from [`Pallet::redemption_pot`], which the issuer funds beforehand.
+        ///
+        /// - A **holder** redeems its whole balance. It must not be frozen or have
+        ///   tokens locked by vesting, but an expired whitelist entry or claim does
+        ///   not block repayment: the holder was verified when it acquired the
+        ///   tokens.
+        /// - The **admin** redeems the next [`Config::MaxHoldersPerPage`] holders,
+        ///   resuming from [`RedemptionCursor`]. Tokens of frozen holders and of
+        ///   holders with partially frozen or vesting-locked tokens are burned too,
+        ///   but their principal is withheld in [`MaturityEscrow`] until
+        ///   [`claim_matured_principal`](Pallet::claim_matured_principal) releases
+        ///   it. So is the principal of a holder that cannot be paid, reported with
+        ///   [`Event::MaturityRedemptionFailed`].
+        ///
+        /// Once the supply reaches zero the instrument is marked [`Matured`], so a
+        /// full admin pass always completes the redemption.
         ///
-        /// This event is emitted by [`Pallet::mint`] when an admin creates new tokens.
-        /// The total supply increases by `amount`.
+        /// # Permissions
         ///
-        /// # Fields
+        /// **Holder** (signed) or **Admin** ([`Config::AdminOrigin`]).
         ///
-        /// - `to`: The account receiving newly minted tokens
-        /// - `amount`: Number of tokens created (raw value)
+        /// # Parameters
         ///
-        /// # Compliance Significance
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | The holder, or `AdminOrigin` to page through holders |
+        /// | `asset_id` | `AssetId` | Instrument to redeem |
         ///
-        /// Minting events represent new token issuance and should be:
-        /// - Audited for authorized issuance
-        /// - Matched against official bond issuance documents
-        /// - Tracked for total supply reconciliation
+        /// # Events
         ///
-        /// # Example
+        /// - [`Event::MaturityRedeemed`] for every holder redeemed
+        /// - [`Event::MaturityRedemptionFailed`] for every holder that could not be paid
+        /// - [`Event::MaturityEscrowed`] for every holder whose principal is withheld
+        /// - [`Event::InstrumentMatured`] when the supply reaches zero
         ///
-        /// ```ignore
-        /// // Ministry mints $100M bond tokens (6 decimals)
-        /// Minted {
-        ///     to: ministry_treasury_account,
-        ///     amount: 100_000_000_000_000  // 100M * 10^6
-        /// }
-        /// ```
-        Minted {
-            /// Account that received the minted tokens.
-            to: T::AccountId,
-            /// Amount of tokens minted (raw u128 value).
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InstrumentMatured`] if the instrument is already fully redeemed
+        /// - [`Error::NoBondTerms`] if the instrument has no bond terms
+        /// - [`Error::NotMatured`] before the maturity block
+        /// - [`Error::CouponsOutstanding`] if a coupon has not been paid
+        /// - [`Error::InsufficientRedemptionFunds`] if the pot cannot cover the principal
+        /// - [`Error::InsufficientBalance`] if a holder calls with no balance
+        /// - [`Error::AccountRecovered`], [`Error::AccountFrozen`],
+        ///   [`Error::InsufficientUnfrozenBalance`] or
+        ///   [`Error::InsufficientVestedBalance`] if a holder cannot spend its balance
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::redeem_at_maturity(T::MaxHoldersPerPage::get()))]
+        pub fn redeem_at_maturity(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+        ) -> DispatchResultWithPostInfo {
+            let holder = match T::AdminOrigin::try_origin(origin) {
+                Ok(_) => None,
+                Err(origin) => Some(ensure_signed(origin)?),
+            };
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_not_matured(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+            let terms = Bonds::<T>::get(asset_id).ok_or(Error::<T>::NoBondTerms)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= terms.maturity,
+                Error::<T>::NotMatured
+            );
+            ensure!(
+                NextCoupon::<T>::get(asset_id) as usize >= terms.coupon_dates.len(),
+                Error::<T>::CouponsOutstanding
+            );
+
+            let visited = match holder {
+                Some(holder) => {
+                    Self::ensure_not_paused(asset_id)?;
+                    Self::redeem_holder_at_maturity(asset_id, &terms, &holder)?;
+                    1
+                }
+                None => {
+                    let principal =
+                        terms.face_value.saturating_mul(TotalSupply::<T>::get(asset_id));
+                    ensure!(
+                        Self::redemption_funds(asset_id) >= principal,
+                        Error::<T>::InsufficientRedemptionFunds
+                    );
+                    Self::redeem_matured_holders(asset_id, &terms, T::MaxHoldersPerPage::get())
+                }
+            };
+
+            if TotalSupply::<T>::get(asset_id) == 0 {
+                RedemptionCursor::<T>::remove(asset_id);
+                Matured::<T>::insert(asset_id, true);
+                Self::deposit_event(Event::InstrumentMatured { asset_id });
+            }
+            Ok(Some(T::WeightInfo::redeem_at_maturity(visited)).into())
+        }
+
+        /// Take a record-date snapshot of an instrument's balances.
+        ///
+        /// Balances and supply at this block can afterwards be read with
+        /// [`Pallet::balance_at`] and [`Pallet::total_supply_at`]. Constant cost:
+        /// balances are recorded lazily, when they next change.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to snapshot |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::SnapshotTaken`] with the new snapshot id
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::Overflow`] if the snapshot id would exceed `u32::MAX`
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        /// - `BadOrigin` if caller is not admin
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::snapshot())]
+        pub fn snapshot(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+            let snapshot_id =
+                CurrentSnapshotId::<T>::get(asset_id).checked_add(1).ok_or(Error::<T>::Overflow)?;
+            CurrentSnapshotId::<T>::insert(asset_id, snapshot_id);
+            Self::deposit_event(Event::SnapshotTaken { asset_id, snapshot_id });
+            Ok(())
+        }
+
+        /// Deposit a dividend against a snapshot.
+        ///
+        /// Moves `amount` of native currency from `issuer` into
+        /// [`Pallet::dividend_pot`]. Each holder at the snapshot is entitled to
+        /// `amount * balance / supply`, rounded down, until `deadline`.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`], dispatched by a signed
+        /// account that is also `issuer`: the dividend is always funded by the
+        /// signer. Admin origins without a signer (Root, a collective) cannot
+        /// declare dividends, so no origin can debit an unrelated account.
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument paying the dividend |
+        /// | `snapshot_id` | `SnapshotId` | Record-date snapshot of holders |
+        /// | `amount` | `u128` | Native currency to distribute |
+        /// | `deadline` | `BlockNumberFor<T>` | Last block at which shares can be claimed |
+        /// | `issuer` | `T::AccountId` | The signer; funds the dividend and receives the unclaimed remainder |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::DividendDeclared`] with the new dividend id
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::UnknownSnapshot`] if the snapshot has not been taken
+        /// - [`Error::InvalidDividend`] if `amount` or the snapshot supply is zero, or
+        ///   `deadline` is not in the future
+        /// - [`Error::Overflow`] if the dividend id would exceed `u32::MAX`
+        /// - Currency errors if `issuer` cannot fund the dividend
+        /// - `BadOrigin` if caller is not admin, is not signed, or is not `issuer`
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::declare_dividend())]
+        pub fn declare_dividend(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            snapshot_id: SnapshotId,
             amount: u128,
-        },
+            deadline: BlockNumberFor<T>,
+            issuer: T::AccountId,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin.clone())?;
+            ensure!(ensure_signed(origin)? == issuer, DispatchError::BadOrigin);
+            Self::ensure_instrument(asset_id)?;
+            let supply =
+                Self::total_supply_at(asset_id, snapshot_id).ok_or(Error::<T>::UnknownSnapshot)?;
+            ensure!(
+                amount > 0 && supply > 0 && deadline > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidDividend
+            );
+            let dividend_id = NextDividendId::<T>::get(asset_id);
+            let next_id = dividend_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
 
-        /// An account was frozen and can no longer send transfers.
+            T::Currency::transfer(
+                &issuer,
+                &Self::dividend_pot(asset_id, dividend_id),
+                amount.unique_saturated_into(),
+                Preservation::Preserve,
+            )?;
+            NextDividendId::<T>::insert(asset_id, next_id);
+            Dividends::<T>::insert(
+                asset_id,
+                dividend_id,
+                Dividend {
+                    issuer: issuer.clone(),
+                    snapshot_id,
+                    amount,
+                    supply,
+                    deadline,
+                    paid: 0,
+                    escrowed: 0,
+                    last_holder: None,
+                    distributed: false,
+                    reclaimed: false,
+                },
+            );
+            Self::deposit_event(Event::DividendDeclared {
+                asset_id,
+                dividend_id,
+                issuer,
+                snapshot_id,
+                amount,
+                supply,
+                deadline,
+            });
+            Ok(())
+        }
+
+        /// Collect the caller's share of a dividend.
         ///
-        /// This event is emitted by [`Pallet::freeze`] when an admin restricts
-        /// an account's ability to transfer tokens.
+        /// Shares can be claimed until the deadline. A share escrowed while the
+        /// holder was frozen can be claimed at any time once it is unfrozen.
         ///
-        /// # Fields
+        /// The caller also collects the unsettled shares of every address recovered
+        /// to it (see [`RecoveredFrom`]), and is paid their sum.
         ///
-        /// - `account`: The account that was frozen
+        /// # Permissions
         ///
-        /// # Compliance Significance
+        /// **Holder** - any signed account that held the instrument at the snapshot.
         ///
-        /// Freeze events indicate:
-        /// - Regulatory action (sanctions, court order)
-        /// - Risk mitigation (suspected fraud)
-        /// - Operational control (preventing unauthorized transfers)
+        /// # Parameters
         ///
-        /// Off-chain systems should trigger alerts when freeze events occur.
-        Frozen {
-            /// Account that was frozen.
-            account: T::AccountId,
-        },
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | The holder |
+        /// | `asset_id` | `AssetId` | Instrument paying the dividend |
+        /// | `dividend_id` | `DividendId` | Dividend to claim |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::DividendPaid`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::UnknownDividend`] if the dividend does not exist
+        /// - [`Error::AccountRecovered`] if the caller was recovered to another
+        ///   address, which collects its shares instead
+        /// - [`Error::AccountFrozen`] if the caller is frozen
+        ///
+        /// If nothing is owed, the error for the caller's own share:
+        ///
+        /// - [`Error::DividendAlreadyClaimed`] if the share has been paid
+        /// - [`Error::DividendExpired`] after the deadline
+        /// - [`Error::NoDividendShare`] if the share is zero
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::claim_dividend())]
+        pub fn claim_dividend(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            dividend_id: DividendId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_not_recovered(asset_id, &who)?;
+            let mut dividend =
+                Dividends::<T>::get(asset_id, dividend_id).ok_or(Error::<T>::UnknownDividend)?;
+            ensure!(!Frozen::<T>::get(asset_id, &who), Error::<T>::AccountFrozen);
 
-        /// A previously frozen account was unfrozen.
+            // The caller's own share, then those of the addresses recovered to it
+            let mut amount: u128 = 0;
+            let mut own_error = None;
+            let mut holder = Some(who.clone());
+            while let Some(account) = holder {
+                match Self::unsettled_dividend(asset_id, dividend_id, &mut dividend, &account) {
+                    Ok(share) => {
+                        amount = amount.saturating_add(share);
+                        DividendClaims::<T>::insert(
+                            (asset_id, dividend_id, &account),
+                            DividendClaim::Paid(share),
+                        );
+                    }
+                    Err(error) => {
+                        own_error.get_or_insert(error);
+                    }
+                }
+                holder = RecoveredFrom::<T>::get(asset_id, &account);
+            }
+            if amount == 0 {
+                return Err(own_error.unwrap_or_else(|| Error::<T>::NoDividendShare.into()));
+            }
+
+            T::Currency::transfer(
+                &Self::dividend_pot(asset_id, dividend_id),
+                &who,
+                amount.unique_saturated_into(),
+                Preservation::Expendable,
+            )?;
+            dividend.paid = dividend.paid.saturating_add(amount);
+            Dividends::<T>::insert(asset_id, dividend_id, dividend);
+            Self::deposit_event(Event::DividendPaid {
+                asset_id,
+                dividend_id,
+                account: who,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Push a dividend to the next [`Config::MaxHoldersPerPage`] holders.
         ///
-        /// This event is emitted by [`Pallet::unfreeze`] when an admin restores
-        /// an account's ability to transfer tokens.
+        /// Holders are visited in storage order, resuming after
+        /// [`Dividend::last_holder`]. Each unsettled share is paid, or escrowed if
+        /// the holder is frozen. The share of a recovered address is paid to (or
+        /// escrowed for) the address it was recovered to. After the deadline shares are no longer paid, but
+        /// frozen holders' shares are still escrowed, so the walk must complete
+        /// before [`Pallet::reclaim_dividend`].
         ///
-        /// # Fields
+        /// Permissionless, so a keeper bot or the scheduler can drive it; only the
+        /// weight actually used is charged.
         ///
-        /// - `account`: The account that was unfrozen
-        Unfrozen {
-            /// Account that was unfrozen.
-            account: T::AccountId,
-        },
+        /// # Permissions
+        ///
+        /// **Any signed account**.
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Any signed account |
+        /// | `asset_id` | `AssetId` | Instrument paying the dividend |
+        /// | `dividend_id` | `DividendId` | Dividend to distribute |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::DividendPaid`] for every holder paid
+        /// - [`Event::DividendPaymentFailed`] for every holder that could not be paid
+        /// - [`Event::DividendEscrowed`] for every frozen holder
+        /// - [`Event::DividendDistributed`] when the last holder has been visited
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::UnknownDividend`] if the dividend does not exist
+        /// - [`Error::DividendAlreadyDistributed`] if every holder has been visited
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::distribute_dividend(T::MaxHoldersPerPage::get()))]
+        pub fn distribute_dividend(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            dividend_id: DividendId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            let dividend =
+                Dividends::<T>::get(asset_id, dividend_id).ok_or(Error::<T>::UnknownDividend)?;
+            ensure!(!dividend.distributed, Error::<T>::DividendAlreadyDistributed);
 
-        /// An account was added to the whitelist (KYC approved).
+            let visited =
+                Self::pay_dividends(asset_id, dividend_id, dividend, T::MaxHoldersPerPage::get());
+            Ok(Some(T::WeightInfo::distribute_dividend(visited)).into())
+        }
+
+        /// Return a dividend's unclaimed remainder to the issuer.
         ///
-        /// This event is emitted by [`Pallet::add_to_whitelist`] when an admin
-        /// approves an account for token transfers.
+        /// Everything in the pot except escrowed shares goes back to
+        /// [`Dividend::issuer`], including rounding remainders. Unsettled shares
+        /// can no longer be claimed afterwards.
         ///
-        /// # Fields
+        /// # Permissions
         ///
-        /// - `account`: The newly whitelisted account
+        /// **Any signed account**.
         ///
-        /// # Workflow Context
+        /// # Parameters
         ///
-        /// This typically follows successful KYC verification:
-        /// 1. Investor submits identity documents off-chain
-        /// 2. Compliance team verifies identity
-        /// 3. Admin adds account to whitelist
-        /// 4. This event is emitted
-        /// 5. Investor can now receive/send tokens
-        Whitelisted {
-            /// Account that was added to the whitelist.
-            account: T::AccountId,
-        },
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Any signed account |
+        /// | `asset_id` | `AssetId` | Instrument paying the dividend |
+        /// | `dividend_id` | `DividendId` | Dividend to close |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::DividendReclaimed`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::UnknownDividend`] if the dividend does not exist
+        /// - [`Error::DividendAlreadyReclaimed`] if already reclaimed
+        /// - [`Error::DividendNotExpired`] at or before the deadline
+        /// - [`Error::DividendDistributionIncomplete`] until every holder is visited
+        /// - Currency errors if escrowed shares would leave the pot below the
+        ///   existential deposit; top up the pot and call again
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::reclaim_dividend())]
+        pub fn reclaim_dividend(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            dividend_id: DividendId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            let mut dividend =
+                Dividends::<T>::get(asset_id, dividend_id).ok_or(Error::<T>::UnknownDividend)?;
+            ensure!(!dividend.reclaimed, Error::<T>::DividendAlreadyReclaimed);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > dividend.deadline,
+                Error::<T>::DividendNotExpired
+            );
+            ensure!(dividend.distributed, Error::<T>::DividendDistributionIncomplete);
 
-        /// An account was removed from the whitelist.
+            let amount =
+                dividend.amount.saturating_sub(dividend.paid).saturating_sub(dividend.escrowed);
+            if amount > 0 {
+                // Escrowed shares must survive in the pot
+                let preservation = if dividend.escrowed == 0 {
+                    Preservation::Expendable
+                } else {
+                    Preservation::Preserve
+                };
+                T::Currency::transfer(
+                    &Self::dividend_pot(asset_id, dividend_id),
+                    &dividend.issuer,
+                    amount.unique_saturated_into(),
+                    preservation,
+                )?;
+            }
+            dividend.reclaimed = true;
+            let issuer = dividend.issuer.clone();
+            Dividends::<T>::insert(asset_id, dividend_id, dividend);
+            Self::deposit_event(Event::DividendReclaimed { asset_id, dividend_id, issuer, amount });
+            Ok(())
+        }
+
+        /// Split or consolidate an instrument's tokens.
+        ///
+        /// Every balance, partial freeze and vesting lock is multiplied by
+        /// `numerator / denominator` and rounded down; the new total supply is the
+        /// sum of the rescaled balances. Absolute [`AccountHoldingLimit`] overrides
+        /// are rescaled the same way once every holder is done, whether or not the
+        /// account holds tokens. This call rescales the first
+        /// [`Config::MaxHoldersPerPage`] holders and overrides;
+        /// [`Pallet::continue_split`] rescales the rest. Until the last override is
+        /// done the instrument is locked (see [`SplitInProgress`]).
+        ///
+        /// The instrument's [`MaxSupply`], instrument-wide absolute holding limit
+        /// and the tokens counted in [`RecentMints`] are rescaled at once. A split is refused while large mints of the instrument
+        /// are queued, since their amounts are in old units. Instruments with
+        /// [`Bonds`] terms cannot be split: coupons and redemptions are paid per
+        /// token at the face value, which a split would scale with the balances.
+        ///
+        /// # Permissions
+        ///
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to split |
+        /// | `numerator` | `u32` | New tokens per `denominator` old tokens |
+        /// | `denominator` | `u32` | Old tokens per `numerator` new tokens |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::SplitStarted`] on success
+        /// - [`Event::BalanceSplit`] for every holder rescaled
+        /// - [`Event::SplitCompleted`] when the last override has been rescaled
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InstrumentMatured`] if the instrument has matured
+        /// - [`Error::BondNotSplittable`] if the instrument has bond terms
+        /// - [`Error::InvalidSplitRatio`] if either part is zero or both are equal
+        /// - [`Error::CouponDistributionInProgress`] while a coupon is being paid
+        /// - [`Error::SplitInProgress`] while another split is being applied
+        /// - [`Error::MintsPending`] while large mints of the instrument are queued
+        /// - [`Error::Overflow`] if the new supply would exceed `u128::MAX`
+        /// - `BadOrigin` if caller is not admin
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // 2-for-1 split: 150 tokens become 300
+        /// CladToken::split(admin_origin, asset_id, 2, 1)?;
+        ///
+        /// // 1-for-10 reverse split: 155 tokens become 15
+        /// CladToken::split(admin_origin, asset_id, 1, 10)?;
+        /// ```
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::split(T::MaxHoldersPerPage::get()))]
+        pub fn split(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            numerator: u32,
+            denominator: u32,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_not_matured(asset_id)?;
+            ensure!(!Bonds::<T>::contains_key(asset_id), Error::<T>::BondNotSplittable);
+            ensure!(
+                numerator > 0 && denominator > 0 && numerator != denominator,
+                Error::<T>::InvalidSplitRatio
+            );
+            Self::ensure_no_coupon_distribution(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+            ensure!(PendingMintCount::<T>::get(asset_id) == 0, Error::<T>::MintsPending);
+
+            let old_supply = TotalSupply::<T>::get(asset_id);
+            // Every balance is at most the supply, so no rescaled balance overflows
+            multiply_by_rational_with_rounding(
+                old_supply,
+                numerator.into(),
+                denominator.into(),
+                Rounding::Down,
+            )
+            .ok_or(Error::<T>::Overflow)?;
+            let progress = SplitProgress {
+                numerator,
+                denominator,
+                rescaling_limits: false,
+                last_holder: None,
+                old_supply,
+                new_supply: 0,
+            };
+            Self::record_snapshot_supply(asset_id);
+            // Rounding down keeps the cap at or above the sum of rescaled balances,
+            // and every rescaled balance within its rescaled limit
+            MaxSupply::<T>::mutate_extant(asset_id, |cap| *cap = progress.rescale(*cap));
+            MaxBalancePerHolder::<T>::mutate_extant(asset_id, |limit| {
+                *limit = progress.rescale_limit(*limit)
+            });
+            RecentMints::<T>::mutate_extant(asset_id, |window| {
+                window.minted = progress.rescale(window.minted)
+            });
+
+            Self::deposit_event(Event::SplitStarted { asset_id, numerator, denominator });
+            let visited = Self::rescale_holders(asset_id, progress, T::MaxHoldersPerPage::get());
+            Ok(Some(T::WeightInfo::split(visited)).into())
+        }
+
+        /// Rescale the next [`Config::MaxHoldersPerPage`] holders or holding-limit
+        /// overrides of a split.
+        ///
+        /// Permissionless, so a keeper bot or the scheduler can drive it; only the
+        /// weight actually used is charged.
+        ///
+        /// # Permissions
+        ///
+        /// **Any signed account**.
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Any signed account |
+        /// | `asset_id` | `AssetId` | Instrument being split |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::BalanceSplit`] for every holder rescaled
+        /// - [`Event::SplitCompleted`] when the last override has been rescaled
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::NoSplitInProgress`] if no split is being applied
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::continue_split(T::MaxHoldersPerPage::get()))]
+        pub fn continue_split(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            let progress =
+                SplitInProgress::<T>::get(asset_id).ok_or(Error::<T>::NoSplitInProgress)?;
+
+            let visited = Self::rescale_holders(asset_id, progress, T::MaxHoldersPerPage::get());
+            Ok(Some(T::WeightInfo::continue_split(visited)).into())
+        }
+
+        /// Move a lost wallet's position to a new address.
         ///
-        /// This event is emitted by [`Pallet::remove_from_whitelist`] when an admin
-        /// revokes an account's transfer privileges.
+        /// Implements ERC-3643 `recoveryAddress`. In one step, `lost`'s balance,
+        /// full and partial freezes, whitelist approval, country, vesting locks and
+        /// holding-limit override on `asset_id` move to `new`, and `lost` is
+        /// recorded in [`RecoveredAccounts`], which blocks it on the instrument for
+        /// good.
         ///
-        /// # Fields
+        /// Entitlements earned by `lost` move too, without touching its history:
+        /// its dividend shares for earlier snapshots, escrowed or not, are paid to
+        /// `new` by [`Pallet::distribute_dividend`] and [`Pallet::claim_dividend`],
+        /// and time-locked mints queued for `lost` execute to `new`.
         ///
-        /// - `account`: The account removed from whitelist
+        /// Holding caps are not re-checked: the number of holders is unchanged and
+        /// the position is the same.
         ///
-        /// # Important Note
+        /// # Permissions
         ///
-        /// Removing from whitelist does NOT confiscate tokens. The account retains
-        /// its balance but cannot transfer it. For full offboarding, transfer tokens
-        /// to a treasury account first.
-        RemovedFromWhitelist {
-            /// Account that was removed from the whitelist.
-            account: T::AccountId,
-        },
-
-        /// The admin account was changed.
+        /// **Compliance agent** - Requires a signed holder of
+        /// [`Role::ComplianceAgent`].
         ///
-        /// This event is emitted by [`Pallet::set_admin`] when the admin rotates
-        /// privileges to a new account (typically a new multi-sig address).
+        /// # Parameters
         ///
-        /// # Fields
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::ComplianceAgent`] |
+        /// | `asset_id` | `AssetId` | Instrument to recover |
+        /// | `lost` | `T::AccountId` | Address whose keys were lost |
+        /// | `new` | `T::AccountId` | Fresh address of the same investor |
         ///
-        /// - `old_admin`: The previous admin account (None if first time setting)
-        /// - `new_admin`: The new admin account
+        /// # Compliance Checks
         ///
-        /// # Audit Significance
+        /// - `new` must hold a valid claim for every required topic; `lost`'s
+        ///   whitelist approval replaces any approval `new` had
+        /// - [`Config::Compliance`] is notified via `transferred`
         ///
-        /// Admin changes are critical governance events. Off-chain systems should:
-        /// - Alert compliance officers immediately
-        /// - Log the change with timestamp for audit trail
-        /// - Verify the new admin matches expected multi-sig address
+        /// # Events
         ///
-        /// # Example Event Data
+        /// - [`Event::Recovered`] on success
+        /// - [`Event::InvestorCountrySet`] if `new`'s country changed
         ///
-        /// ```ignore
-        /// // Multi-sig rotation (personnel change)
-        /// AdminChanged {
-        ///     old_admin: Some("5MultiSigOld..."),
-        ///     new_admin: "5MultiSigNew..."
-        /// }
+        /// # Errors
         ///
-        /// // First-time admin setup (genesis fallback → explicit admin)
-        /// AdminChanged {
-        ///     old_admin: None,
-        ///     new_admin: "5MultiSig..."
-        /// }
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::AccountRecovered`] if either address was already recovered
+        /// - [`Error::RecoveryTargetInUse`] if `new` is `lost`, holds tokens or
+        ///   vesting locks of the instrument, or already took over a recovered
+        ///   position
+        /// - [`Error::CouponDistributionInProgress`] while a coupon is being paid
+        /// - [`Error::SplitInProgress`] while a split is being applied
+        /// - [`Error::MissingClaim`] if `new` lacks a required claim
+        /// - `BadOrigin` if caller is not a compliance agent
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// // An official lost their phone; the issuer has re-verified them
+        /// CladToken::recover_account(RuntimeOrigin::signed(compliance_agent), GENESIS_ASSET_ID, lost, new)?;
         /// ```
-        AdminChanged {
-            /// The previous admin account, if any.
-            old_admin: Option<T::AccountId>,
-            /// The new admin account.
-            new_admin: T::AccountId,
-        },
-    }
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::recover_account())]
+        pub fn recover_account(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            lost: T::AccountId,
+            new: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::ComplianceAgent)?;
+            Self::ensure_instrument(asset_id)?;
+            ensure!(
+                !RecoveredAccounts::<T>::contains_key(asset_id, &lost)
+                    && !RecoveredAccounts::<T>::contains_key(asset_id, &new),
+                Error::<T>::AccountRecovered
+            );
+            ensure!(
+                lost != new
+                    && Balances::<T>::get(asset_id, &new) == 0
+                    && Vesting::<T>::get(asset_id, &new).is_empty()
+                    && !RecoveredFrom::<T>::contains_key(asset_id, &new),
+                Error::<T>::RecoveryTargetInUse
+            );
+            Self::ensure_no_coupon_distribution(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            for topic in RequiredClaimTopics::<T>::get(asset_id) {
+                ensure!(Self::has_valid_claim(&new, topic, now), Error::<T>::MissingClaim);
+            }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    // ERRORS
-    // ═══════════════════════════════════════════════════════════════════════════
+            // Leave `lost`'s country bucket before the country moves
+            let amount = Balances::<T>::get(asset_id, &lost);
+            Self::set_balance(asset_id, &lost, amount, 0);
+            if let Some(entry) = Whitelist::<T>::take(asset_id, &lost) {
+                Whitelist::<T>::insert(asset_id, &new, entry);
+            }
+            if let Some(country) = InvestorCountry::<T>::take(asset_id, &lost) {
+                Self::set_investor_country(asset_id, &new, Some(country));
+            }
+            Self::set_balance(asset_id, &new, 0, amount);
 
-    /// Errors that can occur when interacting with this pallet.
-    ///
-    /// Errors are returned when an extrinsic cannot complete successfully.
-    /// They provide information about why the operation failed, allowing
-    /// callers to handle failures appropriately.
-    ///
-    /// # Error Handling in Clients
-    ///
-    /// ```text
-    /// // JavaScript: Check for specific errors
-    /// try {
-    ///     await api.tx.cladToken.transfer(to, amount).signAndSend(sender);
-    /// } catch (error) {
-    ///     if (error.message.includes('NotWhitelisted')) {
-    ///         console.log('Recipient needs KYC approval first');
-    ///     } else if (error.message.includes('InsufficientBalance')) {
-    ///         console.log('Not enough tokens in account');
-    ///     }
-    /// }
-    /// ```
-    #[pallet::error]
-    pub enum Error<T> {
-        /// The sender does not have enough tokens to complete the transfer.
+            if Frozen::<T>::take(asset_id, &lost) {
+                Frozen::<T>::insert(asset_id, &new, true);
+            }
+            Self::set_frozen_amount(asset_id, &new, FrozenAmount::<T>::take(asset_id, &lost));
+            let locks = Vesting::<T>::take(asset_id, &lost);
+            if !locks.is_empty() {
+                Vesting::<T>::insert(asset_id, &new, locks);
+            }
+            if let Some(limit) = AccountHoldingLimit::<T>::take(asset_id, &lost) {
+                AccountHoldingLimit::<T>::insert(asset_id, &new, limit);
+            }
+            RecoveredAccounts::<T>::insert(asset_id, &lost, &new);
+            RecoveredFrom::<T>::insert(asset_id, &new, &lost);
+
+            T::Compliance::transferred(asset_id, &lost, &new, amount);
+            Self::deposit_event(Event::Recovered { asset_id, lost, new, amount });
+            Ok(())
+        }
+
+        /// Give `account` an operator role.
         ///
-        /// # Triggered By
+        /// # Permissions
         ///
-        /// - [`Pallet::transfer`] when `amount > sender_balance`
+        /// **Role admin** - Requires a signed holder of [`Role::RoleAdmin`], or
+        /// [`Config::AdminOrigin`] to grant [`Role::RoleAdmin`] itself.
         ///
-        /// # Resolution
+        /// # Parameters
         ///
-        /// 1. Check current balance: `api.query.cladToken.balances(account)`
-        /// 2. Reduce transfer amount or acquire more tokens
-        /// 3. Account for decimals when calculating amounts
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | A [`Role::RoleAdmin`] holder, or `AdminOrigin` for `Role::RoleAdmin` |
+        /// | `role` | `Role` | Role to grant |
+        /// | `account` | `T::AccountId` | Operator receiving the role |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::RoleGranted`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::RoleAlreadyGranted`] if `account` already holds `role`
+        /// - `BadOrigin` if caller may not grant `role`
         ///
         /// # Example
         ///
-        /// ```text
-        /// Account balance: 1,000,000 (with 6 decimals = 1.0 tokens)
-        /// Transfer amount: 2,000,000 (2.0 tokens)
-        /// Result: InsufficientBalance error
+        /// ```ignore
+        /// // The admin appoints the transfer agent's desk as role admin...
+        /// CladToken::grant_role(admin_origin, Role::RoleAdmin, ta_desk)?;
+        /// // ...which lets one of its clerks onboard investors, and nothing else
+        /// CladToken::grant_role(RuntimeOrigin::signed(ta_desk), Role::ComplianceAgent, clerk)?;
         /// ```
-        InsufficientBalance,
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::grant_role())]
+        pub fn grant_role(
+            origin: OriginFor<T>,
+            role: Role,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_role_manager(origin, role)?;
+            ensure!(!Roles::<T>::get(role, &account), Error::<T>::RoleAlreadyGranted);
+            Roles::<T>::insert(role, &account, true);
+            Self::deposit_event(Event::RoleGranted { role, account });
+            Ok(())
+        }
 
-        /// The sender or receiver is not on the whitelist.
-        ///
-        /// # Triggered By
+        /// Take an operator role away from `account`.
         ///
-        /// - [`Pallet::transfer`] when sender is not whitelisted
-        /// - [`Pallet::transfer`] when receiver is not whitelisted
+        /// # Permissions
         ///
-        /// # Resolution
+        /// **Role admin** - Requires a signed holder of [`Role::RoleAdmin`], or
+        /// [`Config::AdminOrigin`] to revoke [`Role::RoleAdmin`] itself.
         ///
-        /// 1. Verify both accounts are whitelisted:
-        ///    - `api.query.cladToken.whitelist(sender)`
-        ///    - `api.query.cladToken.whitelist(receiver)`
-        /// 2. Contact admin to whitelist non-approved accounts
-        /// 3. Complete KYC process before requesting whitelist
+        /// # Parameters
         ///
-        /// # ERC-3643 Context
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | A [`Role::RoleAdmin`] holder, or `AdminOrigin` for `Role::RoleAdmin` |
+        /// | `role` | `Role` | Role to revoke |
+        /// | `account` | `T::AccountId` | Operator losing the role |
         ///
-        /// This error enforces the identity verification requirement of compliant
-        /// security tokens. Both parties must be verified investors.
-        NotWhitelisted,
-
-        /// The sender's account is frozen and cannot initiate transfers.
+        /// # Events
         ///
-        /// # Triggered By
+        /// - [`Event::RoleRevoked`] on success
         ///
-        /// - [`Pallet::transfer`] when sender is frozen
+        /// # Errors
         ///
-        /// # Resolution
+        /// - [`Error::RoleNotGranted`] if `account` does not hold `role`
+        /// - `BadOrigin` if caller may not revoke `role`
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::revoke_role())]
+        pub fn revoke_role(
+            origin: OriginFor<T>,
+            role: Role,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_role_manager(origin, role)?;
+            ensure!(Roles::<T>::take(role, &account), Error::<T>::RoleNotGranted);
+            Self::deposit_event(Event::RoleRevoked { role, account });
+            Ok(())
+        }
+
+        /// Cancel a time-locked mint before it executes.
         ///
-        /// 1. Check freeze status: `api.query.cladToken.frozen(account)`
-        /// 2. Contact admin to understand why account was frozen
-        /// 3. Resolve underlying compliance issue
-        /// 4. Request unfreeze via admin
+        /// # Permissions
         ///
-        /// # Note
+        /// **Veto only** - Requires [`Config::VetoOrigin`].
         ///
-        /// Frozen accounts can still **receive** tokens. Only outgoing transfers
-        /// are blocked. This allows court-ordered asset returns while preventing
-        /// the frozen party from moving their holdings.
-        AccountFrozen,
-
-        /// Arithmetic overflow would occur (balance or supply exceeds u128 max).
+        /// # Parameters
         ///
-        /// # Triggered By
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Must satisfy `VetoOrigin` |
+        /// | `id` | `PendingMintId` | Id from [`Event::MintScheduled`] |
         ///
-        /// - [`Pallet::mint`] when `total_supply + amount > u128::MAX`
-        /// - [`Pallet::mint`] when `recipient_balance + amount > u128::MAX`
-        /// - [`Pallet::transfer`] when `recipient_balance + amount > u128::MAX`
+        /// # Events
         ///
-        /// # Resolution
+        /// - [`Event::MintCancelled`] on success
         ///
-        /// This error is extremely rare in practice (u128 max is ~340 undecillion).
-        /// If encountered:
+        /// # Errors
         ///
-        /// 1. Review minting amounts for errors (extra zeros?)
-        /// 2. Check for bugs in amount calculation logic
-        /// 3. Consider using smaller denominations (more decimals)
+        /// - [`Error::UnknownPendingMint`] if the mint was executed, cancelled or
+        ///   never queued
+        /// - `BadOrigin` if caller is not the veto origin
         ///
-        /// # Technical Note
+        /// # Example
         ///
-        /// The pallet uses `checked_add()` to detect overflow before modifying
-        /// storage, ensuring no partial state changes occur on overflow.
-        Overflow,
-    }
-
-    // ═══════════════════════════════════════════════════════════════════════════
-    // DISPATCHABLE FUNCTIONS (EXTRINSICS)
-    // ═══════════════════════════════════════════════════════════════════════════
+        /// ```ignore
+        /// // The audit office finds no board resolution behind mint 7
+        /// CladToken::cancel_mint(audit_office_origin, 7)?;
+        /// ```
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::cancel_mint())]
+        pub fn cancel_mint(origin: OriginFor<T>, id: PendingMintId) -> DispatchResult {
+            T::VetoOrigin::ensure_origin(origin)?;
+            let pending = PendingMints::<T>::take(id).ok_or(Error::<T>::UnknownPendingMint)?;
+            MintQueue::<T>::mutate(pending.execute_at, |due| due.retain(|due_id| *due_id != id));
+            PendingMintCount::<T>::mutate(pending.asset_id, |count| {
+                *count = count.saturating_sub(1)
+            });
+            Self::deposit_event(Event::MintCancelled { id });
+            Ok(())
+        }
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Mint new tokens and credit them to an account.
+        /// Raise the authorised amount of an instrument.
         ///
-        /// Creates `amount` new tokens and adds them to the `to` account's balance.
-        /// This increases the total supply by `amount`.
+        /// Used when a bond series is reopened beyond its original prospectus. The
+        /// cap can only go up. An uncapped instrument can be given a first cap of
+        /// at least its total supply, e.g. once its prospectus is registered.
         ///
         /// # Permissions
         ///
-        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        /// **Governance only** - Requires [`Config::SupplyCapOrigin`].
         ///
         /// # Parameters
         ///
         /// | Parameter | Type | Description |
         /// |-----------|------|-------------|
-        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
-        /// | `to` | `T::AccountId` | Recipient account for new tokens |
-        /// | `amount` | `u128` | Number of tokens to create (raw value) |
+        /// | `origin` | `OriginFor<T>` | Must satisfy `SupplyCapOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument whose cap is raised or set |
+        /// | `new_max_supply` | `u128` | New authorised amount (raw value) |
         ///
         /// # Events
         ///
-        /// - [`Event::Minted`] on success
+        /// - [`Event::MaxSupplyRaised`] on success
         ///
         /// # Errors
         ///
-        /// - [`Error::Overflow`] if `total_supply + amount > u128::MAX`
-        /// - [`Error::Overflow`] if `recipient_balance + amount > u128::MAX`
-        /// - `BadOrigin` if caller is not admin
-        ///
-        /// # Use Cases
-        ///
-        /// 1. **Initial bond issuance**: Ministry mints total bond value to treasury
-        /// 2. **Supplemental issuance**: Additional tokens for reopened bond series
-        /// 3. **Error correction**: Minting to compensate for system errors (rare)
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InvalidSupplyCap`] if `new_max_supply` is not above the
+        ///   current cap or, for an uncapped instrument, is below the total supply
+        /// - `BadOrigin` if caller is not the supply cap origin
         ///
         /// # Example
         ///
         /// ```ignore
-        /// // Mint $100M bond tokens (6 decimals) to treasury account
-        /// // Raw amount = 100,000,000 * 10^6 = 100_000_000_000_000
-        /// CladToken::mint(
-        ///     RawOrigin::Root.into(),
-        ///     treasury_account,
-        ///     100_000_000_000_000
-        /// )?;
+        /// // Parliament authorises a 200M tap of the 2035 series
+        /// CladToken::raise_max_supply(parliament_origin, asset_id, 700_000_000_000_000)?;
         /// ```
-        ///
-        /// # Security Considerations
-        ///
-        /// - Minting is irreversible; there is no "burn" function
-        /// - Verify `amount` calculations carefully (account for decimals)
-        /// - Consider multi-sig admin for production deployments
-        /// - Log all minting operations for audit trail
-        #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::mint())]
-        pub fn mint(origin: OriginFor<T>, to: T::AccountId, amount: u128) -> DispatchResult {
-            T::AdminOrigin::ensure_origin(origin)?;
-
-            // Check for overflow in total supply
-            let new_supply =
-                TotalSupply::<T>::get().checked_add(amount).ok_or(Error::<T>::Overflow)?;
-
-            // Check for overflow in recipient balance
-            let new_balance =
-                Balances::<T>::get(&to).checked_add(amount).ok_or(Error::<T>::Overflow)?;
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::raise_max_supply())]
+        pub fn raise_max_supply(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            new_max_supply: u128,
+        ) -> DispatchResult {
+            T::SupplyCapOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            let old_max_supply = MaxSupply::<T>::get(asset_id);
+            let valid = match old_max_supply {
+                Some(old) => new_max_supply > old,
+                None => new_max_supply >= TotalSupply::<T>::get(asset_id),
+            };
+            ensure!(valid, Error::<T>::InvalidSupplyCap);
 
-            // Apply changes only after all checks pass
-            TotalSupply::<T>::put(new_supply);
-            Balances::<T>::insert(&to, new_balance);
-            Self::deposit_event(Event::Minted { to, amount });
+            MaxSupply::<T>::insert(asset_id, new_max_supply);
+            Self::deposit_event(Event::MaxSupplyRaised {
+                asset_id,
+                old_max_supply,
+                new_max_supply,
+            });
             Ok(())
         }
 
-        /// Transfer tokens from the caller to another account.
+        /// Update an instrument's name, symbol and regulatory identifiers.
         ///
-        /// Moves `amount` tokens from the caller's account to the `to` account.
-        /// Both accounts must be whitelisted, and the caller must not be frozen.
+        /// Replaces the whole [`InstrumentMetadata`]; fields left `None` are cleared.
+        /// Decimals cannot be changed.
         ///
         /// # Permissions
         ///
-        /// **Signed** - Any account can call, but compliance checks apply.
+        /// **Admin only** - Requires [`Config::AdminOrigin`].
         ///
         /// # Parameters
         ///
         /// | Parameter | Type | Description |
         /// |-----------|------|-------------|
-        /// | `origin` | `OriginFor<T>` | Signed origin (the sender) |
-        /// | `to` | `T::AccountId` | Recipient account |
-        /// | `amount` | `u128` | Number of tokens to transfer (raw value) |
-        ///
-        /// # Pre-conditions
-        ///
-        /// All of the following must be true:
-        /// - Sender is whitelisted (KYC approved)
-        /// - Receiver is whitelisted (KYC approved)
-        /// - Sender is not frozen
-        /// - Sender has sufficient balance (`balance >= amount`)
+        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
+        /// | `asset_id` | `AssetId` | Instrument to update |
+        /// | `name` | `BoundedVec<u8, 64>` | Human-readable instrument name |
+        /// | `symbol` | `BoundedVec<u8, 16>` | Trading symbol |
+        /// | `metadata` | `InstrumentMetadata` | ISIN, CFI, issuer LEI, currency and prospectus |
         ///
         /// # Events
         ///
-        /// - [`Event::Transferred`] on success
-        ///
-        /// # Errors
-        ///
-        /// - [`Error::NotWhitelisted`] if sender or receiver not on whitelist
-        /// - [`Error::AccountFrozen`] if sender is frozen
-        /// - [`Error::InsufficientBalance`] if sender has less than `amount`
-        /// - [`Error::Overflow`] if receiver balance would overflow (extremely rare)
+        /// - [`Event::MetadataUpdated`] on success
         ///
-        /// # Use Cases
+        /// # Errors
         ///
-        /// 1. **Primary distribution**: Treasury transfers to institutional investors
-        /// 2. **Secondary trading**: Investors trade tokens among themselves
-        /// 3. **Settlement**: Off-chain OTC trades settled on-chain
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InvalidMetadata`] if an identifier is malformed
+        /// - `BadOrigin` if caller is not admin
         ///
         /// # Example
         ///
         /// ```ignore
-        /// // Transfer 1,000 tokens (6 decimals) to another investor
-        /// // Raw amount = 1,000 * 10^6 = 1_000_000_000
-        /// CladToken::transfer(
-        ///     RuntimeOrigin::signed(sender_account),
-        ///     receiver_account,
-        ///     1_000_000_000
+        /// // Record the ISIN assigned by the national numbering agency
+        /// CladToken::set_metadata(
+        ///     admin_origin,
+        ///     asset_id,
+        ///     b"Kazakhstan Sovereign Bond 2035".to_vec().try_into().unwrap(),
+        ///     b"KZT-BOND-2035".to_vec().try_into().unwrap(),
+        ///     InstrumentMetadata {
+        ///         isin: Some(*b"KZ2C00012345"),
+        ///         cfi: Some(*b"DBFTFR"),
+        ///         currency: Some(*b"KZT"),
+        ///         prospectus_hash: Some(sha256_of_prospectus),
+        ///         prospectus_uri: b"ipfs://bafy...".to_vec().try_into().unwrap(),
+        ///         ..Default::default()
+        ///     },
         /// )?;
         /// ```
-        ///
-        /// # Self-Transfer
-        ///
-        /// Transferring to yourself (`sender == to`) is allowed and emits a
-        /// `Transferred` event, but does not modify balances. This can be used
-        /// for accounting purposes or to verify account status.
-        #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::transfer())]
-        pub fn transfer(origin: OriginFor<T>, to: T::AccountId, amount: u128) -> DispatchResult {
-            let sender = ensure_signed(origin)?;
-            ensure!(Whitelist::<T>::get(&sender), Error::<T>::NotWhitelisted);
-            ensure!(Whitelist::<T>::get(&to), Error::<T>::NotWhitelisted);
-            ensure!(!Frozen::<T>::get(&sender), Error::<T>::AccountFrozen);
-
-            let sender_balance = Balances::<T>::get(&sender);
-            ensure!(sender_balance >= amount, Error::<T>::InsufficientBalance);
-
-            // Handle self-transfer: no overflow check needed, balance unchanged
-            if sender == to {
-                Self::deposit_event(Event::Transferred { from: sender, to, amount });
-                return Ok(());
-            }
-
-            // Check for overflow in receiver balance (defensive - should not happen with capped supply)
-            let new_receiver_balance =
-                Balances::<T>::get(&to).checked_add(amount).ok_or(Error::<T>::Overflow)?;
+        #[pallet::call_index(41)]
+        #[pallet::weight(T::WeightInfo::set_metadata())]
+        pub fn set_metadata(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            name: TokenNameOf,
+            symbol: TokenSymbolOf,
+            metadata: InstrumentMetadata,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            ensure!(metadata.is_well_formed(), Error::<T>::InvalidMetadata);
 
-            // Apply changes only after all checks pass
-            Balances::<T>::insert(&sender, sender_balance - amount);
-            Balances::<T>::insert(&to, new_receiver_balance);
-            Self::deposit_event(Event::Transferred { from: sender, to, amount });
+            TokenName::<T>::insert(asset_id, &name);
+            TokenSymbol::<T>::insert(asset_id, &symbol);
+            Metadata::<T>::insert(asset_id, &metadata);
+            Self::deposit_event(Event::MetadataUpdated { asset_id, name, symbol, metadata });
             Ok(())
         }
 
-        /// Freeze an account, preventing it from sending transfers.
+        /// Attach a legal document to an instrument, or replace the one with the
+        /// same name (ERC-1643 `setDocument`).
         ///
-        /// Frozen accounts retain their balance and can still receive tokens,
-        /// but cannot initiate outgoing transfers until unfrozen.
+        /// The document's last-modified block is set to the current block.
         ///
         /// # Permissions
         ///
@@ -1056,47 +6772,55 @@ pub mod pallet {
         /// | Parameter | Type | Description |
         /// |-----------|------|-------------|
         /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
-        /// | `account` | `T::AccountId` | Account to freeze |
+        /// | `asset_id` | `AssetId` | Instrument the document governs |
+        /// | `name` | `BoundedVec<u8, 32>` | Name of the document |
+        /// | `uri` | `BoundedVec<u8, 256>` | Where the document can be downloaded |
+        /// | `content_hash` | `[u8; 32]` | Hash of the document's content |
         ///
         /// # Events
         ///
-        /// - [`Event::Frozen`] on success
+        /// - [`Event::DocumentUpdated`] on success
         ///
         /// # Errors
         ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
         /// - `BadOrigin` if caller is not admin
         ///
-        /// # Use Cases
-        ///
-        /// 1. **Sanctions compliance**: Freeze accounts matching sanctions lists
-        /// 2. **Fraud prevention**: Halt transfers during investigation
-        /// 3. **Legal hold**: Preserve assets per court order
-        /// 4. **Account recovery**: Prevent unauthorized transfers after key compromise
-        ///
         /// # Example
         ///
         /// ```ignore
-        /// // Freeze a suspicious account pending investigation
-        /// CladToken::freeze(RawOrigin::Root.into(), suspicious_account)?;
+        /// // Register the first amendment to the offering circular
+        /// CladToken::set_document(
+        ///     admin_origin,
+        ///     asset_id,
+        ///     b"amendment-1".to_vec().try_into().unwrap(),
+        ///     b"https://minfin.gov.kz/bonds/2035/amendment-1.pdf".to_vec().try_into().unwrap(),
+        ///     sha256_of_pdf,
+        /// )?;
         /// ```
-        ///
-        /// # Idempotency
-        ///
-        /// Freezing an already-frozen account is a no-op (succeeds without error).
-        /// This simplifies batch operations and retry logic.
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::freeze())]
-        pub fn freeze(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+        #[pallet::call_index(42)]
+        #[pallet::weight(T::WeightInfo::set_document())]
+        pub fn set_document(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            name: DocumentNameOf,
+            uri: DocumentUriOf,
+            content_hash: [u8; 32],
+        ) -> DispatchResult {
             T::AdminOrigin::ensure_origin(origin)?;
-            Frozen::<T>::insert(&account, true);
-            Self::deposit_event(Event::Frozen { account });
+            Self::ensure_instrument(asset_id)?;
+
+            let document = Document {
+                uri: uri.clone(),
+                content_hash,
+                last_modified: frame_system::Pallet::<T>::block_number(),
+            };
+            Documents::<T>::insert(asset_id, &name, document);
+            Self::deposit_event(Event::DocumentUpdated { asset_id, name, uri, content_hash });
             Ok(())
         }
 
-        /// Unfreeze an account, restoring its ability to send transfers.
-        ///
-        /// Removes the freeze flag from an account, allowing it to resume
-        /// normal transfer operations (assuming it remains whitelisted).
+        /// Withdraw a legal document from an instrument (ERC-1643 `removeDocument`).
         ///
         /// # Permissions
         ///
@@ -1107,253 +6831,1486 @@ pub mod pallet {
         /// | Parameter | Type | Description |
         /// |-----------|------|-------------|
         /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
-        /// | `account` | `T::AccountId` | Account to unfreeze |
+        /// | `asset_id` | `AssetId` | Instrument the document governs |
+        /// | `name` | `BoundedVec<u8, 32>` | Name of the document |
         ///
         /// # Events
         ///
-        /// - [`Event::Unfrozen`] on success
+        /// - [`Event::DocumentRemoved`] on success
         ///
         /// # Errors
         ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::UnknownDocument`] if the instrument has no such document
         /// - `BadOrigin` if caller is not admin
+        #[pallet::call_index(43)]
+        #[pallet::weight(T::WeightInfo::remove_document())]
+        pub fn remove_document(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            name: DocumentNameOf,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::ensure_instrument(asset_id)?;
+
+            let document =
+                Documents::<T>::take(asset_id, &name).ok_or(Error::<T>::UnknownDocument)?;
+            Self::deposit_event(Event::DocumentRemoved {
+                asset_id,
+                name,
+                uri: document.uri,
+                content_hash: document.content_hash,
+            });
+            Ok(())
+        }
+
+        /// Mint tokens to several accounts in one call.
         ///
-        /// # Use Cases
+        /// Each entry is handled exactly like a [`Pallet::mint`], in order. Entries
+        /// count towards the time-lock window one after the other, so once the batch
+        /// reaches [`Config::LargeMintThreshold`] the remaining entries are queued.
+        /// The batch is atomic: if any entry fails, no tokens are minted.
         ///
-        /// 1. **Investigation cleared**: Restore access after compliance review
-        /// 2. **Sanctions delisted**: Account no longer on restricted lists
-        /// 3. **Legal release**: Court order lifted
+        /// # Permissions
+        ///
+        /// **Minter** - Requires a signed holder of [`Role::Minter`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::Minter`] |
+        /// | `asset_id` | `AssetId` | Instrument to mint |
+        /// | `mints` | `BoundedVec<(T::AccountId, u128), T::MaxBatchSize>` | Recipients and amounts (raw value) |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Minted`] or [`Event::MintScheduled`] for every entry
+        ///
+        /// # Errors
+        ///
+        /// - Any error of [`Pallet::mint`], for the first entry that fails
+        /// - `BadOrigin` if caller is not a minter
         ///
         /// # Example
         ///
         /// ```ignore
-        /// // Unfreeze account after compliance review
-        /// CladToken::unfreeze(RawOrigin::Root.into(), cleared_account)?;
+        /// // Settle a primary-market allocation
+        /// CladToken::batch_mint(
+        ///     RuntimeOrigin::signed(minter),
+        ///     asset_id,
+        ///     vec![(bank_a, 250_000_000_000), (bank_b, 100_000_000_000)].try_into().unwrap(),
+        /// )?;
         /// ```
+        #[pallet::call_index(44)]
+        #[pallet::weight(T::WeightInfo::batch_mint(mints.len() as u32))]
+        pub fn batch_mint(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            mints: BoundedVec<(T::AccountId, u128), T::MaxBatchSize>,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::Minter)?;
+            for (to, amount) in mints {
+                Self::mint_or_schedule(asset_id, to, amount, None)?;
+            }
+            Ok(())
+        }
+
+        /// Whitelist several accounts in one call.
         ///
-        /// # Idempotency
+        /// Each entry is handled exactly like an [`Pallet::add_to_whitelist`], with
+        /// the same expiry for every account. The batch is atomic: if any entry
+        /// fails, no account is whitelisted.
         ///
-        /// Unfreezing a non-frozen account is a no-op (succeeds without error).
-        #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::unfreeze())]
-        pub fn unfreeze(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
-            T::AdminOrigin::ensure_origin(origin)?;
-            Frozen::<T>::remove(&account);
-            Self::deposit_event(Event::Unfrozen { account });
+        /// # Permissions
+        ///
+        /// **Compliance agent** - Requires a signed holder of
+        /// [`Role::ComplianceAgent`].
+        ///
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::ComplianceAgent`] |
+        /// | `asset_id` | `AssetId` | Instrument the change applies to |
+        /// | `investors` | `BoundedVec<(T::AccountId, Option<CountryCode>), T::MaxBatchSize>` | Accounts and their countries |
+        /// | `expires_at` | `Option<BlockNumberFor<T>>` | Block at which the approvals lapse; `None` for no expiry |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Whitelisted`] for every entry
+        /// - [`Event::InvestorCountrySet`] for every entry whose country changed
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::InvalidWhitelistExpiry`] if `expires_at` is not in the future
+        /// - [`Error::AccountRecovered`] if an account was recovered to another address
+        /// - `BadOrigin` if caller is not a compliance agent
+        #[pallet::call_index(45)]
+        #[pallet::weight(T::WeightInfo::batch_whitelist(investors.len() as u32))]
+        pub fn batch_whitelist(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            investors: BoundedVec<(T::AccountId, Option<CountryCode>), T::MaxBatchSize>,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::ComplianceAgent)?;
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_whitelist_expiry(expires_at)?;
+            for (account, country) in investors {
+                Self::do_add_to_whitelist(asset_id, account, expires_at, country)?;
+            }
             Ok(())
         }
 
-        /// Add an account to the whitelist, allowing it to participate in transfers.
+        /// Freeze several accounts in one call.
         ///
-        /// Whitelisting represents KYC (Know Your Customer) approval. Only whitelisted
-        /// accounts can send or receive tokens, enforcing the identity verification
-        /// requirement of ERC-3643 compliant security tokens.
+        /// Each entry is handled exactly like a [`Pallet::freeze`].
         ///
         /// # Permissions
         ///
-        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        /// **Freezer** - Requires a signed holder of [`Role::Freezer`].
         ///
         /// # Parameters
         ///
         /// | Parameter | Type | Description |
         /// |-----------|------|-------------|
-        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
-        /// | `account` | `T::AccountId` | Account to whitelist |
+        /// | `origin` | `OriginFor<T>` | Signed holder of [`Role::Freezer`] |
+        /// | `asset_id` | `AssetId` | Instrument the freeze applies to |
+        /// | `accounts` | `BoundedVec<T::AccountId, T::MaxBatchSize>` | Accounts to freeze |
         ///
         /// # Events
         ///
-        /// - [`Event::Whitelisted`] on success
+        /// - [`Event::Frozen`] for every entry
         ///
         /// # Errors
         ///
-        /// - `BadOrigin` if caller is not admin
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - `BadOrigin` if caller is not a freezer
+        #[pallet::call_index(46)]
+        #[pallet::weight(T::WeightInfo::batch_freeze(accounts.len() as u32))]
+        pub fn batch_freeze(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            accounts: BoundedVec<T::AccountId, T::MaxBatchSize>,
+        ) -> DispatchResult {
+            Self::ensure_role(origin, Role::Freezer)?;
+            Self::ensure_instrument(asset_id)?;
+            for account in accounts {
+                Frozen::<T>::insert(asset_id, &account, true);
+                Self::deposit_event(Event::Frozen { asset_id, account });
+            }
+            Ok(())
+        }
+
+        /// Transfer tokens from the caller to another account, quoting a payment
+        /// reference.
         ///
-        /// # Use Cases
+        /// Behaves exactly like [`Pallet::transfer`], and additionally records
+        /// `reference` in the [`Event::Transferred`] event so settlement teams can
+        /// match the transfer to the SWIFT or ISO 20022 instruction it settles.
         ///
-        /// 1. **KYC approval**: Approve investor after identity verification
-        /// 2. **Institutional onboarding**: Add new institutional investors
-        /// 3. **Treasury setup**: Whitelist ministry/issuer accounts
+        /// # Permissions
         ///
-        /// # Typical Workflow
+        /// **Signed** - Any account can call, but compliance checks apply.
         ///
-        /// ```text
-        /// 1. Investor submits KYC documents via off-chain process
-        /// 2. Compliance team verifies identity and eligibility
-        /// 3. Admin adds investor to whitelist
-        /// 4. Investor can now receive tokens from treasury
-        /// 5. Investor can trade with other whitelisted accounts
-        /// ```
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Signed origin (the sender) |
+        /// | `asset_id` | `AssetId` | Instrument to transfer |
+        /// | `to` | `T::AccountId` | Recipient account |
+        /// | `amount` | `u128` | Number of tokens to transfer (raw value) |
+        /// | `reference` | `TransferReferenceOf` | Payment reference, e.g. an ISO 20022 end-to-end id (max 35 bytes) |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::Transferred`] with `reference` set, on success
+        ///
+        /// # Errors
+        ///
+        /// - Any error of [`Pallet::transfer`]
+        /// - `BadOrigin` if not signed
         ///
         /// # Example
         ///
         /// ```ignore
-        /// // Whitelist a new institutional investor
-        /// CladToken::add_to_whitelist(RawOrigin::Root.into(), investor_account)?;
+        /// CladToken::transfer_with_reference(
+        ///     RuntimeOrigin::signed(sender_account),
+        ///     GENESIS_ASSET_ID,
+        ///     receiver_account,
+        ///     1_000_000_000,
+        ///     b"E2E-20250114-000123".to_vec().try_into().unwrap(),
+        /// )?;
         /// ```
-        ///
-        /// # Idempotency
-        ///
-        /// Whitelisting an already-whitelisted account is a no-op.
-        #[pallet::call_index(4)]
-        #[pallet::weight(T::WeightInfo::add_to_whitelist())]
-        pub fn add_to_whitelist(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
-            T::AdminOrigin::ensure_origin(origin)?;
-            Whitelist::<T>::insert(&account, true);
-            Self::deposit_event(Event::Whitelisted { account });
-            Ok(())
+        #[pallet::call_index(47)]
+        #[pallet::weight(T::WeightInfo::transfer_with_reference())]
+        pub fn transfer_with_reference(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            to: T::AccountId,
+            amount: u128,
+            reference: TransferReferenceOf,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::do_transfer(asset_id, sender, to, amount, Some(reference))
         }
 
-        /// Remove an account from the whitelist, preventing it from participating in transfers.
+        /// Pay out principal withheld from `account` at maturity.
         ///
-        /// The account will no longer be able to send or receive tokens. However,
-        /// any existing balance is preserved—tokens are not confiscated.
+        /// [`redeem_at_maturity`](Pallet::redeem_at_maturity) burns restricted
+        /// holders' tokens but keeps their principal in [`MaturityEscrow`]. Once
+        /// `account` is unfrozen, has no partially frozen amount left and its
+        /// vesting schedules have run out, the principal is paid to it, or to the
+        /// address it was recovered to.
+        ///
+        /// Permissionless, like [`distribute_coupon`](Pallet::distribute_coupon):
+        /// the principal can only go to the holder.
         ///
         /// # Permissions
         ///
-        /// **Admin only** - Requires [`Config::AdminOrigin`].
+        /// **Any signed account**.
         ///
         /// # Parameters
         ///
         /// | Parameter | Type | Description |
         /// |-----------|------|-------------|
-        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
-        /// | `account` | `T::AccountId` | Account to remove from whitelist |
+        /// | `origin` | `OriginFor<T>` | Any signed account |
+        /// | `asset_id` | `AssetId` | Matured instrument |
+        /// | `account` | `T::AccountId` | Holder whose principal was withheld |
         ///
         /// # Events
         ///
-        /// - [`Event::RemovedFromWhitelist`] on success
+        /// - [`Event::MaturityEscrowReleased`] on success
         ///
         /// # Errors
         ///
-        /// - `BadOrigin` if caller is not admin
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::NothingEscrowed`] if no principal is withheld for `account`
+        /// - [`Error::AccountFrozen`] if `account` is frozen
+        /// - [`Error::InsufficientUnfrozenBalance`] if part of its balance is still
+        ///   frozen
+        /// - [`Error::InsufficientVestedBalance`] if a vesting schedule has not
+        ///   run out
+        /// - Any error of the native currency transfer
+        #[pallet::call_index(49)]
+        #[pallet::weight(T::WeightInfo::claim_matured_principal())]
+        pub fn claim_matured_principal(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            let payout =
+                MaturityEscrow::<T>::get(asset_id, &account).ok_or(Error::<T>::NothingEscrowed)?;
+            ensure!(!Frozen::<T>::get(asset_id, &account), Error::<T>::AccountFrozen);
+            ensure!(
+                FrozenAmount::<T>::get(asset_id, &account) == 0,
+                Error::<T>::InsufficientUnfrozenBalance
+            );
+            ensure!(
+                Self::vesting_locked(asset_id, &account) == 0,
+                Error::<T>::InsufficientVestedBalance
+            );
+
+            let payee = Self::recovered_address(asset_id, &account);
+            T::Currency::transfer(
+                &Self::redemption_pot(asset_id),
+                &payee,
+                payout.unique_saturated_into(),
+                Preservation::Expendable,
+            )?;
+            MaturityEscrow::<T>::remove(asset_id, &account);
+            MaturityEscrowTotal::<T>::mutate(asset_id, |total| {
+                *total = total.saturating_sub(payout)
+            });
+            Self::deposit_event(Event::MaturityEscrowReleased { asset_id, account, payee, payout });
+            Ok(())
+        }
+
+        /// Pay out a coupon that [`distribute_coupon`](Pallet::distribute_coupon)
+        /// could not pay to `account`.
         ///
-        /// # Use Cases
+        /// The coupon recorded in [`UnpaidCoupons`] is paid from
+        /// [`Pallet::coupon_pot`] to `account`, or to the address it was recovered
+        /// to. Typically called once the account holds the native currency's
+        /// existential deposit.
         ///
-        /// 1. **KYC expiration**: Remove investors with expired verification
-        /// 2. **Voluntary exit**: Investor requests removal from platform
-        /// 3. **Compliance failure**: Investor no longer meets eligibility criteria
+        /// Permissionless, like [`distribute_coupon`](Pallet::distribute_coupon):
+        /// the coupon can only go to the holder.
         ///
-        /// # Important: Token Preservation
+        /// # Permissions
         ///
-        /// Removing from whitelist does **NOT** confiscate tokens. The account
-        /// retains its balance but cannot move it. For full offboarding:
+        /// **Any signed account**.
         ///
-        /// ```text
-        /// 1. Coordinate with investor to transfer tokens to treasury
-        /// 2. Remove account from whitelist
-        /// 3. Process any fiat redemption off-chain
-        /// ```
+        /// # Parameters
+        ///
+        /// | Parameter | Type | Description |
+        /// |-----------|------|-------------|
+        /// | `origin` | `OriginFor<T>` | Any signed account |
+        /// | `asset_id` | `AssetId` | Instrument whose coupon was due |
+        /// | `coupon_index` | `u32` | Index of the coupon date |
+        /// | `account` | `T::AccountId` | Holder the coupon is owed to |
+        ///
+        /// # Events
+        ///
+        /// - [`Event::CouponClaimed`] on success
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::UnknownInstrument`] if `asset_id` is not registered
+        /// - [`Error::NoUnpaidCoupon`] if no unpaid coupon is recorded for `account`
+        /// - Any error of the native currency transfer; the coupon stays recorded
+        #[pallet::call_index(50)]
+        #[pallet::weight(T::WeightInfo::claim_coupon())]
+        pub fn claim_coupon(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            coupon_index: u32,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_instrument(asset_id)?;
+            let amount = UnpaidCoupons::<T>::get((asset_id, coupon_index, &account))
+                .ok_or(Error::<T>::NoUnpaidCoupon)?;
+
+            let payee = Self::recovered_address(asset_id, &account);
+            T::Currency::transfer(
+                &Self::coupon_pot(asset_id),
+                &payee,
+                amount.unique_saturated_into(),
+                Preservation::Expendable,
+            )?;
+            UnpaidCoupons::<T>::remove((asset_id, coupon_index, &account));
+            UnpaidCouponTotal::<T>::mutate(asset_id, |total| *total = total.saturating_sub(amount));
+            Self::deposit_event(Event::CouponClaimed {
+                asset_id,
+                coupon_index,
+                account,
+                payee,
+                amount,
+            });
+            Ok(())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // INTERNAL HELPERS
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Ensure `asset_id` refers to a registered instrument.
+        ///
+        /// Asset IDs are assigned sequentially, so any ID below [`NextAssetId`]
+        /// has been created.
+        pub fn ensure_instrument(asset_id: AssetId) -> DispatchResult {
+            ensure!(asset_id < NextAssetId::<T>::get(), Error::<T>::UnknownInstrument);
+            Ok(())
+        }
+
+        /// Ensure `who` was not recovered to another address on `asset_id`.
+        ///
+        /// See [`RecoveredAccounts`].
+        pub fn ensure_not_recovered(asset_id: AssetId, who: &T::AccountId) -> DispatchResult {
+            ensure!(
+                !RecoveredAccounts::<T>::contains_key(asset_id, who),
+                Error::<T>::AccountRecovered
+            );
+            Ok(())
+        }
+
+        /// The address `who`'s position on `asset_id` was last recovered to, or
+        /// `who` itself if it was never recovered.
+        pub fn recovered_address(asset_id: AssetId, who: &T::AccountId) -> T::AccountId {
+            let mut address = who.clone();
+            while let Some(new) = RecoveredAccounts::<T>::get(asset_id, &address) {
+                address = new;
+            }
+            address
+        }
+
+        /// Ensure `who` passes the identity check for `asset_id`.
+        ///
+        /// The account must not have been recovered, must be whitelisted and must
+        /// hold a valid claim for every topic in [`RequiredClaimTopics`]. See that
+        /// item for the exact rule.
+        pub fn ensure_verified(asset_id: AssetId, who: &T::AccountId) -> DispatchResult {
+            Self::ensure_not_recovered(asset_id, who)?;
+            let entry = Whitelist::<T>::get(asset_id, who).ok_or(Error::<T>::NotWhitelisted)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(!entry.is_expired(&now), Error::<T>::KycExpired);
+            for topic in RequiredClaimTopics::<T>::get(asset_id) {
+                ensure!(Self::has_valid_claim(who, topic, now), Error::<T>::MissingClaim);
+            }
+            Ok(())
+        }
+
+        /// Whether `who` holds an unexpired whitelist approval for `asset_id`.
+        pub fn is_whitelisted(asset_id: AssetId, who: &T::AccountId) -> bool {
+            let now = frame_system::Pallet::<T>::block_number();
+            Whitelist::<T>::get(asset_id, who).is_some_and(|entry| !entry.is_expired(&now))
+        }
+
+        /// Walk up to `limit` whitelist entries from the sweep cursor, removing
+        /// expired ones. Returns the number of entries inspected.
+        pub(crate) fn sweep_whitelist(limit: u32) -> u32 {
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut iter = match WhitelistSweepCursor::<T>::get() {
+                Some((asset_id, account)) => {
+                    Whitelist::<T>::iter_from(Whitelist::<T>::hashed_key_for(asset_id, account))
+                }
+                None => Whitelist::<T>::iter(),
+            };
+
+            let mut visited = 0;
+            let mut expired = Vec::new();
+            let mut cursor = None;
+            while visited < limit {
+                let Some((asset_id, account, entry)) = iter.next() else {
+                    cursor = None;
+                    break;
+                };
+                visited += 1;
+                if entry.is_expired(&now) {
+                    expired.push((asset_id, account.clone()));
+                }
+                cursor = Some((asset_id, account));
+            }
+
+            match cursor {
+                Some(cursor) => WhitelistSweepCursor::<T>::put(cursor),
+                None => WhitelistSweepCursor::<T>::kill(),
+            }
+            for (asset_id, account) in expired {
+                Whitelist::<T>::remove(asset_id, &account);
+                Self::deposit_event(Event::WhitelistExpired { asset_id, account });
+            }
+            visited
+        }
+
+        /// Whether `who` passes the identity check for `asset_id`.
+        pub fn is_verified(asset_id: AssetId, who: &T::AccountId) -> bool {
+            Self::ensure_verified(asset_id, who).is_ok()
+        }
+
+        /// Whether `who` holds an unexpired claim for `topic` from a trusted issuer.
+        fn has_valid_claim(who: &T::AccountId, topic: ClaimTopic, now: BlockNumberFor<T>) -> bool {
+            Claims::<T>::get(who, topic).is_some_and(|claim| {
+                claim.expires_at > now && TrustedIssuers::<T>::get(&claim.issuer).contains(&topic)
+            })
+        }
+
+        /// Ensure holder-initiated movements of `asset_id` are not paused.
+        pub fn ensure_not_paused(asset_id: AssetId) -> DispatchResult {
+            ensure!(!Paused::<T>::get(asset_id), Error::<T>::TokenPaused);
+            Ok(())
+        }
+
+        /// Free (spendable) balance of `who`: balance minus the partially frozen
+        /// amount and the amount still locked by vesting schedules.
+        pub fn free_balance(asset_id: AssetId, who: &T::AccountId) -> u128 {
+            Balances::<T>::get(asset_id, who)
+                .saturating_sub(FrozenAmount::<T>::get(asset_id, who))
+                .saturating_sub(Self::vesting_locked(asset_id, who))
+        }
+
+        /// Amount of `who`'s `asset_id` balance still locked by vesting schedules.
+        pub fn vesting_locked(asset_id: AssetId, who: &T::AccountId) -> u128 {
+            let now = frame_system::Pallet::<T>::block_number();
+            Vesting::<T>::get(asset_id, who)
+                .iter()
+                .fold(0u128, |locked, lock| locked.saturating_add(lock.locked_at(now)))
+        }
+
+        /// Locked and unlocked parts of `who`'s balance, as `(locked, unlocked)`.
+        ///
+        /// Backs the `CladTokenApi::vesting_balance` runtime API. A forced transfer
+        /// can leave less balance than is locked; the locked part is capped at the
+        /// balance.
+        pub fn vesting_balance(asset_id: AssetId, who: &T::AccountId) -> (u128, u128) {
+            let balance = Balances::<T>::get(asset_id, who);
+            let locked = Self::vesting_locked(asset_id, who).min(balance);
+            (locked, balance - locked)
+        }
+
+        /// Ensure `who` can spend `amount` of `asset_id` without touching frozen or
+        /// unvested tokens.
         ///
-        /// # Example
+        /// Returns the full balance so callers can avoid a second read.
+        fn ensure_can_spend(
+            asset_id: AssetId,
+            who: &T::AccountId,
+            amount: u128,
+        ) -> Result<u128, DispatchError> {
+            let balance = Balances::<T>::get(asset_id, who);
+            ensure!(balance >= amount, Error::<T>::InsufficientBalance);
+            let free = balance.saturating_sub(FrozenAmount::<T>::get(asset_id, who));
+            ensure!(free >= amount, Error::<T>::InsufficientUnfrozenBalance);
+            let vested = free.saturating_sub(Self::vesting_locked(asset_id, who));
+            ensure!(vested >= amount, Error::<T>::InsufficientVestedBalance);
+            Ok(balance)
+        }
+
+        /// Move `amount` tokens of `asset_id` from `from` to `to`.
+        ///
+        /// Performs only the balance bookkeeping; compliance checks are the
+        /// caller's responsibility. Emits no event.
+        fn move_balance(
+            asset_id: AssetId,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let from_balance = Balances::<T>::get(asset_id, from);
+            ensure!(from_balance >= amount, Error::<T>::InsufficientBalance);
+
+            // Handle self-transfer: no overflow check needed, balance unchanged
+            if from == to {
+                return Ok(());
+            }
+
+            // Check for overflow in receiver balance (defensive - should not happen with capped supply)
+            let to_balance = Balances::<T>::get(asset_id, to);
+            let new_to_balance = to_balance.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+
+            // Apply changes only after all checks pass
+            Self::set_balance(asset_id, from, from_balance, from_balance - amount);
+            Self::set_balance(asset_id, to, to_balance, new_to_balance);
+            Ok(())
+        }
+
+        /// Write `who`'s balance, keeping holder statistics in line.
+        ///
+        /// `old` must be the balance currently stored. Every balance change goes
+        /// through here so that [`HolderCount`], [`HoldersPerCountry`] and
+        /// [`SnapshotBalances`] stay exact.
+        fn set_balance(asset_id: AssetId, who: &T::AccountId, old: u128, new: u128) {
+            Self::record_snapshot_balance(asset_id, who, old);
+            Balances::<T>::insert(asset_id, who, new);
+            if (old == 0) != (new == 0) {
+                HolderCount::<T>::mutate(asset_id, |holders| {
+                    *holders = if new != 0 {
+                        holders.saturating_add(1)
+                    } else {
+                        holders.saturating_sub(1)
+                    };
+                });
+                if let Some(country) = InvestorCountry::<T>::get(asset_id, who) {
+                    Self::adjust_country_holders(asset_id, country, new != 0);
+                }
+            }
+        }
+
+        /// Record `balance`, the balance `who` is about to leave, for the latest
+        /// snapshot of `asset_id` unless already recorded.
+        fn record_snapshot_balance(asset_id: AssetId, who: &T::AccountId, balance: u128) {
+            let snapshot_id = CurrentSnapshotId::<T>::get(asset_id);
+            if snapshot_id != 0
+                && !SnapshotBalances::<T>::contains_key((asset_id, snapshot_id, who))
+            {
+                SnapshotBalances::<T>::insert((asset_id, snapshot_id, who), balance);
+            }
+        }
+
+        /// Record the current supply of `asset_id` for its latest snapshot unless
+        /// already recorded. Called before every change of [`TotalSupply`].
+        fn record_snapshot_supply(asset_id: AssetId) {
+            let snapshot_id = CurrentSnapshotId::<T>::get(asset_id);
+            if snapshot_id != 0 && !SnapshotTotalSupply::<T>::contains_key(asset_id, snapshot_id) {
+                SnapshotTotalSupply::<T>::insert(
+                    asset_id,
+                    snapshot_id,
+                    TotalSupply::<T>::get(asset_id),
+                );
+            }
+        }
+
+        /// Balance of `who` at snapshot `snapshot_id` of `asset_id`, or `None` if
+        /// no such snapshot has been taken.
+        ///
+        /// Uses the first record at or after `snapshot_id`, so the cost grows with
+        /// the number of snapshots taken since. Backs the `CladTokenApi::balance_at`
+        /// runtime API.
+        pub fn balance_at(
+            asset_id: AssetId,
+            who: &T::AccountId,
+            snapshot_id: SnapshotId,
+        ) -> Option<u128> {
+            let current = CurrentSnapshotId::<T>::get(asset_id);
+            if snapshot_id == 0 || snapshot_id > current {
+                return None;
+            }
+            let recorded = (snapshot_id..=current)
+                .find_map(|id| SnapshotBalances::<T>::get((asset_id, id, who)));
+            Some(recorded.unwrap_or_else(|| Balances::<T>::get(asset_id, who)))
+        }
+
+        /// Total supply of `asset_id` at snapshot `snapshot_id`, or `None` if no
+        /// such snapshot has been taken.
+        ///
+        /// See [`Pallet::balance_at`].
+        pub fn total_supply_at(asset_id: AssetId, snapshot_id: SnapshotId) -> Option<u128> {
+            let current = CurrentSnapshotId::<T>::get(asset_id);
+            if snapshot_id == 0 || snapshot_id > current {
+                return None;
+            }
+            let recorded =
+                (snapshot_id..=current).find_map(|id| SnapshotTotalSupply::<T>::get(asset_id, id));
+            Some(recorded.unwrap_or_else(|| TotalSupply::<T>::get(asset_id)))
+        }
+
+        /// Every legal document of `asset_id`, in storage order.
         ///
-        /// ```ignore
-        /// // Remove investor with expired KYC
-        /// CladToken::remove_from_whitelist(RawOrigin::Root.into(), expired_investor)?;
-        /// ```
+        /// Backs the `CladTokenApi::documents` runtime API (ERC-1643
+        /// `getAllDocuments`).
+        pub fn documents(asset_id: AssetId) -> Vec<(DocumentNameOf, DocumentOf<T>)> {
+            Documents::<T>::iter_prefix(asset_id).collect()
+        }
+
+        /// Count one more (`joined`) or one fewer holder in `country`.
+        fn adjust_country_holders(asset_id: AssetId, country: CountryCode, joined: bool) {
+            HoldersPerCountry::<T>::mutate(asset_id, country, |holders| {
+                *holders =
+                    if joined { holders.saturating_add(1) } else { holders.saturating_sub(1) };
+            });
+        }
+
+        /// Move `amount` of `asset_id` from `sender` to `to` after every transfer
+        /// check, and emit [`Event::Transferred`] with `reference`.
         ///
-        /// # Idempotency
+        /// Shared by [`Pallet::transfer`] and [`Pallet::transfer_with_reference`].
+        fn do_transfer(
+            asset_id: AssetId,
+            sender: T::AccountId,
+            to: T::AccountId,
+            amount: u128,
+            reference: Option<TransferReferenceOf>,
+        ) -> DispatchResult {
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_not_paused(asset_id)?;
+            Self::ensure_not_matured(asset_id)?;
+            Self::ensure_no_coupon_distribution(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+            Self::ensure_verified(asset_id, &sender)?;
+            Self::ensure_verified(asset_id, &to)?;
+            Self::ensure_country_allowed(asset_id, &to)?;
+            ensure!(!Frozen::<T>::get(asset_id, &sender), Error::<T>::AccountFrozen);
+            Self::ensure_can_spend(asset_id, &sender, amount)?;
+            Self::ensure_holder_capacity(asset_id, Some(&sender), &to, amount)?;
+            if sender != to {
+                let new_balance = Balances::<T>::get(asset_id, &to).saturating_add(amount);
+                let supply = TotalSupply::<T>::get(asset_id);
+                Self::ensure_within_holding_limit(asset_id, &to, new_balance, supply)?;
+            }
+            T::Compliance::can_transfer(asset_id, &sender, &to, amount)?;
+
+            Self::move_balance(asset_id, &sender, &to, amount)?;
+            T::Compliance::transferred(asset_id, &sender, &to, amount);
+            Self::deposit_event(Event::Transferred {
+                asset_id,
+                from: sender,
+                to,
+                amount,
+                reference,
+            });
+            Ok(())
+        }
+
+        /// Ensure a whitelist expiry, if any, lies in the future.
+        fn ensure_whitelist_expiry(expires_at: Option<BlockNumberFor<T>>) -> DispatchResult {
+            if let Some(expires_at) = expires_at {
+                ensure!(
+                    expires_at > frame_system::Pallet::<T>::block_number(),
+                    Error::<T>::InvalidWhitelistExpiry
+                );
+            }
+            Ok(())
+        }
+
+        /// Whitelist `account` on `asset_id` and record its country.
         ///
-        /// Removing a non-whitelisted account is a no-op.
-        #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::remove_from_whitelist())]
-        pub fn remove_from_whitelist(
-            origin: OriginFor<T>,
+        /// Shared by [`Pallet::add_to_whitelist`] and [`Pallet::batch_whitelist`];
+        /// the caller checks the origin, the instrument and the expiry.
+        fn do_add_to_whitelist(
+            asset_id: AssetId,
             account: T::AccountId,
+            expires_at: Option<BlockNumberFor<T>>,
+            country: Option<CountryCode>,
         ) -> DispatchResult {
-            T::AdminOrigin::ensure_origin(origin)?;
-            Whitelist::<T>::remove(&account);
-            Self::deposit_event(Event::RemovedFromWhitelist { account });
+            Self::ensure_not_recovered(asset_id, &account)?;
+            Whitelist::<T>::insert(asset_id, &account, WhitelistEntry { expires_at });
+            Self::set_investor_country(asset_id, &account, country);
+            Self::deposit_event(Event::Whitelisted { asset_id, account, expires_at });
             Ok(())
         }
 
-        /// Transfer admin privileges to a new account.
-        ///
-        /// Sets a new admin account in storage, allowing admin rotation without
-        /// a runtime upgrade. This is essential for production deployments where
-        /// ministry committees have personnel changes.
-        ///
-        /// # Permissions
-        ///
-        /// **Admin only** - Requires [`Config::AdminOrigin`].
-        ///
-        /// In practice, this means:
-        /// - Root origin can always set a new admin (emergency recovery)
-        /// - The current multi-sig admin can rotate to a new multi-sig
-        ///
-        /// # Parameters
-        ///
-        /// | Parameter | Type | Description |
-        /// |-----------|------|-------------|
-        /// | `origin` | `OriginFor<T>` | Must satisfy `AdminOrigin` |
-        /// | `new_admin` | `T::AccountId` | Account to become the new admin |
-        ///
-        /// # Events
-        ///
-        /// - [`Event::AdminChanged`] on success
-        /// - [`Event::Whitelisted`] for the new admin (auto-whitelisted)
-        ///
-        /// # Errors
-        ///
-        /// - `BadOrigin` if caller is not current admin or root
-        ///
-        /// # Use Cases
-        ///
-        /// 1. **Personnel change**: Ministry official retires, committee rotates keys
-        /// 2. **Threshold change**: Upgrade from 2-of-3 to 3-of-5 multi-sig
-        /// 3. **Emergency recovery**: Root sets new admin after key compromise
-        /// 4. **Initial setup**: Set explicit admin after genesis (from None)
-        ///
-        /// # Workflow Example
+        /// Record `who`'s jurisdiction, moving them between holder buckets if they
+        /// currently hold `asset_id`.
+        fn set_investor_country(
+            asset_id: AssetId,
+            who: &T::AccountId,
+            country: Option<CountryCode>,
+        ) {
+            let previous = InvestorCountry::<T>::get(asset_id, who);
+            if previous == country {
+                return;
+            }
+
+            if Balances::<T>::get(asset_id, who) > 0 {
+                if let Some(previous) = previous {
+                    Self::adjust_country_holders(asset_id, previous, false);
+                }
+                if let Some(country) = country {
+                    Self::adjust_country_holders(asset_id, country, true);
+                }
+            }
+            InvestorCountry::<T>::set(asset_id, who, country);
+            Self::deposit_event(Event::InvestorCountrySet {
+                asset_id,
+                account: who.clone(),
+                country,
+            });
+        }
+
+        /// Ensure crediting `amount` to `to` keeps `asset_id` within [`MaxHolders`].
+        ///
+        /// `from` is the sender for transfers, or `None` for mints. A transfer that
+        /// empties the sender's balance frees a slot for the receiver.
+        fn ensure_holder_capacity(
+            asset_id: AssetId,
+            from: Option<&T::AccountId>,
+            to: &T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let Some(max_holders) = MaxHolders::<T>::get(asset_id) else {
+                return Ok(());
+            };
+            let joins = amount > 0 && from != Some(to) && Balances::<T>::get(asset_id, to) == 0;
+            let leaves = from.is_some_and(|from| Balances::<T>::get(asset_id, from) == amount);
+            if joins && !leaves {
+                ensure!(HolderCount::<T>::get(asset_id) < max_holders, Error::<T>::TooManyHolders);
+            }
+            Ok(())
+        }
+
+        /// Mint `amount` of `asset_id` to `to` and emit [`Event::Minted`].
+        ///
+        /// Shared by [`Pallet::mint`] and [`Pallet::mint_with_schedule`]; the
+        /// caller checks the origin.
+        fn do_mint(asset_id: AssetId, to: &T::AccountId, amount: u128) -> DispatchResult {
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_not_recovered(asset_id, to)?;
+            if T::PauseBlocksMinting::get() {
+                Self::ensure_not_paused(asset_id)?;
+            }
+            Self::ensure_not_matured(asset_id)?;
+            Self::ensure_no_coupon_distribution(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+
+            // Check for overflow in total supply
+            let new_supply =
+                TotalSupply::<T>::get(asset_id).checked_add(amount).ok_or(Error::<T>::Overflow)?;
+            if let Some(max_supply) = MaxSupply::<T>::get(asset_id) {
+                ensure!(new_supply <= max_supply, Error::<T>::SupplyCapExceeded);
+            }
+
+            // Check for overflow in recipient balance
+            let balance = Balances::<T>::get(asset_id, to);
+            let new_balance = balance.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+            Self::ensure_holder_capacity(asset_id, None, to, amount)?;
+            Self::ensure_within_holding_limit(asset_id, to, new_balance, new_supply)?;
+
+            // Apply changes only after all checks pass
+            Self::record_snapshot_supply(asset_id);
+            TotalSupply::<T>::insert(asset_id, new_supply);
+            Self::set_balance(asset_id, to, balance, new_balance);
+            T::Compliance::created(asset_id, to, amount);
+            Self::deposit_event(Event::Minted { asset_id, to: to.clone(), amount });
+            Ok(())
+        }
+
+        /// Ensure a `new_balance` for `who` respects its holding limit, given the
+        /// instrument's `total_supply` after the operation.
+        ///
+        /// See [`AccountHoldingLimit`] and [`MaxBalancePerHolder`].
+        fn ensure_within_holding_limit(
+            asset_id: AssetId,
+            who: &T::AccountId,
+            new_balance: u128,
+            total_supply: u128,
+        ) -> DispatchResult {
+            let limit = AccountHoldingLimit::<T>::get(asset_id, who)
+                .or_else(|| MaxBalancePerHolder::<T>::get(asset_id));
+            if let Some(limit) = limit {
+                ensure!(
+                    new_balance <= limit.max_balance(total_supply),
+                    Error::<T>::HoldingLimitExceeded
+                );
+            }
+            Ok(())
+        }
+
+        /// Ensure `to`'s jurisdiction may receive `asset_id`.
+        ///
+        /// See [`BlockedCountries`] and [`AllowedCountries`].
+        pub fn ensure_country_allowed(asset_id: AssetId, to: &T::AccountId) -> DispatchResult {
+            let country = InvestorCountry::<T>::get(asset_id, to);
+            if let Some(country) = country {
+                ensure!(
+                    !BlockedCountries::<T>::get(asset_id).contains(&country),
+                    Error::<T>::CountryBlocked
+                );
+            }
+            let allowed = AllowedCountries::<T>::get(asset_id);
+            ensure!(
+                allowed.is_empty() || country.is_some_and(|country| allowed.contains(&country)),
+                Error::<T>::CountryNotAllowed
+            );
+            Ok(())
+        }
+
+        /// Account holding the native currency from which `asset_id`'s coupons are
+        /// paid.
         ///
-        /// ```text
-        /// // Current admin: 2-of-3 multi-sig (Alice, Bob, Charlie)
-        /// // New admin: 3-of-5 multi-sig (Alice, Bob, Charlie, Dave, Eve)
+        /// Derived from [`Config::PalletId`]; no key controls it. The issuer funds
+        /// it with a plain transfer before each coupon date.
+        pub fn coupon_pot(asset_id: AssetId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(asset_id)
+        }
+
+        /// Account holding the native currency from which `asset_id`'s principal is
+        /// repaid at maturity.
         ///
-        /// 1. Create new 3-of-5 multi-sig address off-chain
-        /// 2. Current 2-of-3 multi-sig approves set_admin(new_multisig)
-        /// 3. AdminChanged event emitted
-        /// 4. New multi-sig is auto-whitelisted
-        /// 5. Old multi-sig remains whitelisted (can still receive tokens)
-        /// ```
+        /// Derived from [`Config::PalletId`], separately from the coupon pot.
+        pub fn redemption_pot(asset_id: AssetId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating((b"redeem", asset_id))
+        }
+
+        /// Account holding the native currency of one dividend until it is paid
+        /// or reclaimed.
         ///
-        /// # Example
+        /// Derived from [`Config::PalletId`]; each dividend has its own pot.
+        pub fn dividend_pot(asset_id: AssetId, dividend_id: DividendId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating((b"dividend", asset_id, dividend_id))
+        }
+
+        /// Native currency available in `asset_id`'s redemption pot, less the
+        /// principal withheld in [`MaturityEscrow`].
+        fn redemption_funds(asset_id: AssetId) -> u128 {
+            let balance: u128 = T::Currency::reducible_balance(
+                &Self::redemption_pot(asset_id),
+                Preservation::Expendable,
+                Fortitude::Polite,
+            )
+            .unique_saturated_into();
+            balance.saturating_sub(MaturityEscrowTotal::<T>::get(asset_id))
+        }
+
+        /// Ensure `asset_id` has not matured.
+        fn ensure_not_matured(asset_id: AssetId) -> DispatchResult {
+            ensure!(!Matured::<T>::get(asset_id), Error::<T>::InstrumentMatured);
+            Ok(())
+        }
+
+        /// Redeem `holder`'s whole balance of `asset_id` at maturity.
+        ///
+        /// Unlike [`Pallet::redeem`], skips the identity check: repaying principal
+        /// must not depend on the holder renewing its KYC.
+        fn redeem_holder_at_maturity(
+            asset_id: AssetId,
+            terms: &BondTermsOf<T>,
+            holder: &T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_not_recovered(asset_id, holder)?;
+            let amount = Balances::<T>::get(asset_id, holder);
+            ensure!(amount > 0, Error::<T>::InsufficientBalance);
+            ensure!(!Frozen::<T>::get(asset_id, holder), Error::<T>::AccountFrozen);
+            Self::ensure_can_spend(asset_id, holder, amount)?;
+            let payout = terms.face_value.saturating_mul(amount);
+            ensure!(
+                Self::redemption_funds(asset_id) >= payout,
+                Error::<T>::InsufficientRedemptionFunds
+            );
+
+            Self::destroy(asset_id, holder, amount, amount);
+            T::Currency::transfer(
+                &Self::redemption_pot(asset_id),
+                holder,
+                payout.unique_saturated_into(),
+                Preservation::Expendable,
+            )?;
+            Self::deposit_event(Event::MaturityRedeemed {
+                asset_id,
+                account: holder.clone(),
+                amount,
+                payout,
+            });
+            Ok(())
+        }
+
+        /// Redeem up to `limit` holders of `asset_id` at maturity, resuming from
+        /// [`RedemptionCursor`]. Returns the number of holders visited.
+        ///
+        /// Every holder's tokens are burned. Frozen holders, holders with
+        /// partially frozen or vesting-locked tokens and holders that cannot be
+        /// paid have their principal withheld in [`MaturityEscrow`] instead, so
+        /// the pass always brings the supply to zero.
+        pub(crate) fn redeem_matured_holders(
+            asset_id: AssetId,
+            terms: &BondTermsOf<T>,
+            limit: u32,
+        ) -> u32 {
+            let pot = Self::redemption_pot(asset_id);
+            let mut iter = match RedemptionCursor::<T>::get(asset_id) {
+                Some(last) => Balances::<T>::iter_prefix_from(
+                    asset_id,
+                    Balances::<T>::hashed_key_for(asset_id, last),
+                ),
+                None => Balances::<T>::iter_prefix(asset_id),
+            };
+
+            let mut visited = 0;
+            let mut cursor = None;
+            while visited < limit {
+                let Some((account, amount)) = iter.next() else { break };
+                visited += 1;
+                cursor = Some(account.clone());
+                if amount == 0 {
+                    continue;
+                }
+
+                let payout = terms.face_value.saturating_mul(amount);
+                let restricted = Frozen::<T>::get(asset_id, &account)
+                    || Self::free_balance(asset_id, &account) < amount;
+                Self::destroy(asset_id, &account, amount, amount);
+                if !restricted {
+                    let paid = T::Currency::transfer(
+                        &pot,
+                        &account,
+                        payout.unique_saturated_into(),
+                        Preservation::Expendable,
+                    );
+                    if paid.is_ok() {
+                        Self::deposit_event(Event::MaturityRedeemed {
+                            asset_id,
+                            account,
+                            amount,
+                            payout,
+                        });
+                        continue;
+                    }
+                    Self::deposit_event(Event::MaturityRedemptionFailed {
+                        asset_id,
+                        account: account.clone(),
+                        amount,
+                    });
+                }
+
+                MaturityEscrow::<T>::mutate(asset_id, &account, |escrowed| {
+                    *escrowed = Some(escrowed.unwrap_or(0).saturating_add(payout))
+                });
+                MaturityEscrowTotal::<T>::mutate(asset_id, |total| {
+                    *total = total.saturating_add(payout)
+                });
+                Self::deposit_event(Event::MaturityEscrowed { asset_id, account, amount, payout });
+            }
+
+            match cursor {
+                Some(cursor) if iter.next().is_some() => {
+                    RedemptionCursor::<T>::insert(asset_id, cursor)
+                }
+                _ => RedemptionCursor::<T>::remove(asset_id),
+            }
+            visited
+        }
+
+        /// Ensure no coupon of `asset_id` is being paid.
+        ///
+        /// See [`CouponDistribution`].
+        fn ensure_no_coupon_distribution(asset_id: AssetId) -> DispatchResult {
+            ensure!(
+                !CouponDistribution::<T>::contains_key(asset_id),
+                Error::<T>::CouponDistributionInProgress
+            );
+            Ok(())
+        }
+
+        /// Mint `amount` of `asset_id` to `to` under a vesting lock.
         ///
-        /// ```ignore
-        /// // Rotate to new multi-sig address
-        /// CladToken::set_admin(
-        ///     RuntimeOrigin::signed(current_multisig),  // Via multi-sig approval
-        ///     new_multisig_account
-        /// )?;
-        /// ```
+        /// See [`mint_with_schedule`](Pallet::mint_with_schedule).
+        fn do_mint_with_schedule(
+            asset_id: AssetId,
+            to: T::AccountId,
+            amount: u128,
+            schedule: VestingScheduleOf<T>,
+        ) -> DispatchResult {
+            let now = frame_system::Pallet::<T>::block_number();
+            Vesting::<T>::try_mutate(asset_id, &to, |locks| {
+                // Fully vested locks no longer restrict anything
+                locks.retain(|lock| lock.locked_at(now) > 0);
+                locks
+                    .try_push(VestingLock { amount, schedule })
+                    .map_err(|_| Error::<T>::TooManyVestingSchedules)
+            })?;
+
+            Self::do_mint(asset_id, &to, amount)?;
+            Self::deposit_event(Event::VestingScheduleAdded {
+                asset_id,
+                account: to,
+                amount,
+                schedule,
+            });
+            Ok(())
+        }
+
+        /// Mint `amount` at once, or queue it if it takes the instrument's immediate
+        /// mints in the current window to [`Config::LargeMintThreshold`].
+        fn mint_or_schedule(
+            asset_id: AssetId,
+            to: T::AccountId,
+            amount: u128,
+            schedule: Option<VestingScheduleOf<T>>,
+        ) -> DispatchResult {
+            let now = frame_system::Pallet::<T>::block_number();
+            let window_length = T::MintDelay::get().max(One::one());
+            let mut window = RecentMints::<T>::get(asset_id)
+                .filter(|window| now < window.start.saturating_add(window_length))
+                .unwrap_or(MintWindow { start: now, minted: 0 });
+            window.minted = window.minted.saturating_add(amount);
+            if window.minted >= T::LargeMintThreshold::get() {
+                return Self::schedule_mint(asset_id, to, amount, schedule);
+            }
+            RecentMints::<T>::insert(asset_id, window);
+            match schedule {
+                Some(schedule) => Self::do_mint_with_schedule(asset_id, to, amount, schedule),
+                None => Self::do_mint(asset_id, &to, amount),
+            }
+        }
+
+        /// Queue a large mint for execution [`Config::MintDelay`] blocks from now.
+        fn schedule_mint(
+            asset_id: AssetId,
+            to: T::AccountId,
+            amount: u128,
+            schedule: Option<VestingScheduleOf<T>>,
+        ) -> DispatchResult {
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_not_recovered(asset_id, &to)?;
+            Self::ensure_no_split(asset_id)?;
+            // A zero delay still runs the mint at the start of the next block
+            let delay = T::MintDelay::get().max(One::one());
+            let execute_at = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+            let id = NextPendingMintId::<T>::get();
+            let next_id = id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+            MintQueue::<T>::try_append(execute_at, id).map_err(|_| Error::<T>::MintQueueFull)?;
+            NextPendingMintId::<T>::put(next_id);
+            PendingMintCount::<T>::mutate(asset_id, |count| *count = count.saturating_add(1));
+            PendingMints::<T>::insert(
+                id,
+                PendingMint { asset_id, to: to.clone(), amount, schedule, execute_at },
+            );
+            Self::deposit_event(Event::MintScheduled { id, asset_id, to, amount, execute_at });
+            Ok(())
+        }
+
+        /// Ensure `origin` is a signed holder of `role`.
         ///
-        /// # Security Considerations
+        /// [`Config::AdminOrigin`] does not pass on its own; see [`roles`].
+        fn ensure_role(origin: OriginFor<T>, role: Role) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Roles::<T>::get(role, &who), DispatchError::BadOrigin);
+            Ok(())
+        }
+
+        /// Ensure `origin` may grant or revoke `role`: [`Config::AdminOrigin`] for
+        /// [`Role::RoleAdmin`], a role admin for every other role.
+        fn ensure_role_manager(origin: OriginFor<T>, role: Role) -> DispatchResult {
+            match role {
+                Role::RoleAdmin => {
+                    T::AdminOrigin::ensure_origin(origin)?;
+                    Ok(())
+                }
+                _ => Self::ensure_role(origin, Role::RoleAdmin),
+            }
+        }
+
+        /// Ensure no split of `asset_id` is being applied.
         ///
-        /// - The new admin is automatically whitelisted to ensure it can receive
-        ///   tokens if needed (e.g., treasury operations)
-        /// - The old admin is NOT automatically removed from whitelist—this
-        ///   preserves their ability to hold tokens they may already have
-        /// - Consider implementing a timelock for admin changes in high-security
-        ///   deployments (future enhancement)
-        /// - Root origin should be protected by sudo or similar mechanism
-        #[pallet::call_index(6)]
-        #[pallet::weight(T::WeightInfo::set_admin())]
-        pub fn set_admin(origin: OriginFor<T>, new_admin: T::AccountId) -> DispatchResult {
-            T::AdminOrigin::ensure_origin(origin)?;
+        /// See [`SplitInProgress`].
+        fn ensure_no_split(asset_id: AssetId) -> DispatchResult {
+            ensure!(!SplitInProgress::<T>::contains_key(asset_id), Error::<T>::SplitInProgress);
+            Ok(())
+        }
 
-            // Get current admin for event
-            let old_admin = Admin::<T>::get();
+        /// Rescale up to `limit` holders, then [`AccountHoldingLimit`] overrides,
+        /// of `asset_id`, resuming after [`SplitProgress::last_holder`]. Returns the
+        /// number of accounts visited.
+        ///
+        /// Overrides get their own pass, so that accounts without tokens are not
+        /// listed in [`Balances`]. The call that reaches the last override writes
+        /// the new [`TotalSupply`] and clears [`SplitInProgress`].
+        pub(crate) fn rescale_holders(
+            asset_id: AssetId,
+            mut progress: SplitProgressOf<T>,
+            limit: u32,
+        ) -> u32 {
+            let mut visited = 0;
+            if !progress.rescaling_limits {
+                visited = Self::rescale_balances(asset_id, &mut progress, limit);
+                if !progress.rescaling_limits {
+                    SplitInProgress::<T>::insert(asset_id, progress);
+                    return visited;
+                }
+            }
 
-            // Set new admin in storage
-            Admin::<T>::put(&new_admin);
+            let mut iter = match &progress.last_holder {
+                Some(last) => AccountHoldingLimit::<T>::iter_prefix_from(
+                    asset_id,
+                    AccountHoldingLimit::<T>::hashed_key_for(asset_id, last),
+                ),
+                None => AccountHoldingLimit::<T>::iter_prefix(asset_id),
+            };
+            while visited < limit {
+                let Some((account, holding_limit)) = iter.next() else { break };
+                visited += 1;
+                let rescaled = progress.rescale_limit(holding_limit);
+                if rescaled != holding_limit {
+                    AccountHoldingLimit::<T>::insert(asset_id, &account, rescaled);
+                }
+                progress.last_holder = Some(account);
+            }
 
-            // Auto-whitelist new admin so they can receive tokens if needed
-            Whitelist::<T>::insert(&new_admin, true);
+            if iter.next().is_some() {
+                SplitInProgress::<T>::insert(asset_id, progress);
+            } else {
+                SplitInProgress::<T>::remove(asset_id);
+                TotalSupply::<T>::insert(asset_id, progress.new_supply);
+                Self::deposit_event(Event::SplitCompleted {
+                    asset_id,
+                    numerator: progress.numerator,
+                    denominator: progress.denominator,
+                    old_supply: progress.old_supply,
+                    new_supply: progress.new_supply,
+                });
+            }
+            visited
+        }
 
-            // Emit events
-            Self::deposit_event(Event::AdminChanged { old_admin, new_admin: new_admin.clone() });
-            Self::deposit_event(Event::Whitelisted { account: new_admin });
+        /// Rescale up to `limit` holders of `asset_id` for the split in
+        /// `progress`, resuming after [`SplitProgress::last_holder`]. Returns the
+        /// number of holders visited, and moves `progress` on to the overrides
+        /// once every holder is done.
+        fn rescale_balances(
+            asset_id: AssetId,
+            progress: &mut SplitProgressOf<T>,
+            limit: u32,
+        ) -> u32 {
+            let mut iter = match &progress.last_holder {
+                Some(last) => Balances::<T>::iter_prefix_from(
+                    asset_id,
+                    Balances::<T>::hashed_key_for(asset_id, last),
+                ),
+                None => Balances::<T>::iter_prefix(asset_id),
+            };
+
+            let mut visited = 0;
+            while visited < limit {
+                let Some((account, old_balance)) = iter.next() else { break };
+                visited += 1;
+                progress.last_holder = Some(account.clone());
+                if old_balance == 0 {
+                    continue;
+                }
+
+                let new_balance = progress.rescale(old_balance);
+                Self::set_balance(asset_id, &account, old_balance, new_balance);
+                let frozen = FrozenAmount::<T>::get(asset_id, &account);
+                if frozen > 0 {
+                    Self::set_frozen_amount(asset_id, &account, progress.rescale(frozen));
+                }
+                let mut locks = Vesting::<T>::get(asset_id, &account);
+                if !locks.is_empty() {
+                    for lock in locks.iter_mut() {
+                        lock.amount = progress.rescale(lock.amount);
+                    }
+                    Vesting::<T>::insert(asset_id, &account, locks);
+                }
+                progress.new_supply = progress.new_supply.saturating_add(new_balance);
+                Self::deposit_event(Event::BalanceSplit {
+                    asset_id,
+                    account,
+                    old_balance,
+                    new_balance,
+                });
+            }
+
+            if iter.next().is_none() {
+                progress.rescaling_limits = true;
+                progress.last_holder = None;
+            }
+            visited
+        }
+
+        /// Open the payment of `asset_id`'s next coupon, checking that it is due
+        /// and that the pot covers it.
+        fn open_coupon(
+            asset_id: AssetId,
+            terms: &BondTermsOf<T>,
+        ) -> Result<CouponProgressOf<T>, DispatchError> {
+            Self::ensure_no_split(asset_id)?;
+            let coupon_index = NextCoupon::<T>::get(asset_id);
+            let due =
+                terms.coupon_dates.get(coupon_index as usize).ok_or(Error::<T>::NoCouponDue)?;
+            ensure!(frame_system::Pallet::<T>::block_number() >= *due, Error::<T>::CouponNotDue);
+
+            // Per-holder amounts round down, so their sum never exceeds this
+            let total = terms.coupon_amount(TotalSupply::<T>::get(asset_id));
+            let balance: u128 = T::Currency::reducible_balance(
+                &Self::coupon_pot(asset_id),
+                Preservation::Expendable,
+                Fortitude::Polite,
+            )
+            .unique_saturated_into();
+            // Coupons owed from earlier dates are not available
+            let funds = balance.saturating_sub(UnpaidCouponTotal::<T>::get(asset_id));
+            ensure!(funds >= total, Error::<T>::InsufficientCouponFunds);
+
+            Self::deposit_event(Event::CouponDistributionStarted { asset_id, coupon_index, total });
+            Ok(CouponProgress { coupon_index, last_holder: None, paid: 0 })
+        }
+
+        /// Pay the open coupon of `asset_id` to up to `limit` holders, resuming
+        /// after `progress.last_holder`, and close the payment once every holder
+        /// has been visited. Returns the number of holders visited.
+        pub(crate) fn pay_coupons(
+            asset_id: AssetId,
+            terms: &BondTermsOf<T>,
+            mut progress: CouponProgressOf<T>,
+            limit: u32,
+        ) -> u32 {
+            let pot = Self::coupon_pot(asset_id);
+            let coupon_index = progress.coupon_index;
+            let mut iter = match &progress.last_holder {
+                Some(last) => Balances::<T>::iter_prefix_from(
+                    asset_id,
+                    Balances::<T>::hashed_key_for(asset_id, last),
+                ),
+                None => Balances::<T>::iter_prefix(asset_id),
+            };
+
+            let mut visited = 0;
+            while visited < limit {
+                let Some((account, balance)) = iter.next() else { break };
+                visited += 1;
+                progress.last_holder = Some(account.clone());
+                let amount = terms.coupon_amount(balance);
+                if amount == 0 {
+                    continue;
+                }
+                let paid = T::Currency::transfer(
+                    &pot,
+                    &account,
+                    amount.unique_saturated_into(),
+                    Preservation::Expendable,
+                );
+                if paid.is_ok() {
+                    progress.paid = progress.paid.saturating_add(amount);
+                    Self::deposit_event(Event::CouponPaid {
+                        asset_id,
+                        coupon_index,
+                        account,
+                        amount,
+                    });
+                } else {
+                    UnpaidCoupons::<T>::insert((asset_id, coupon_index, &account), amount);
+                    UnpaidCouponTotal::<T>::mutate(asset_id, |total| {
+                        *total = total.saturating_add(amount)
+                    });
+                    Self::deposit_event(Event::CouponPaymentFailed {
+                        asset_id,
+                        coupon_index,
+                        account,
+                        amount,
+                    });
+                }
+            }
+
+            if iter.next().is_some() {
+                CouponDistribution::<T>::insert(asset_id, progress);
+            } else {
+                CouponDistribution::<T>::remove(asset_id);
+                NextCoupon::<T>::insert(asset_id, coupon_index.saturating_add(1));
+                Self::deposit_event(Event::CouponDistributed {
+                    asset_id,
+                    coupon_index,
+                    total_paid: progress.paid,
+                });
+            }
+            visited
+        }
+
+        /// The unsettled dividend share of `holder`, released from escrow if it was
+        /// escrowed. The caller records the share as paid.
+        fn unsettled_dividend(
+            asset_id: AssetId,
+            dividend_id: DividendId,
+            dividend: &mut DividendOf<T>,
+            holder: &T::AccountId,
+        ) -> Result<u128, DispatchError> {
+            match DividendClaims::<T>::get((asset_id, dividend_id, holder)) {
+                Some(DividendClaim::Paid(_)) => Err(Error::<T>::DividendAlreadyClaimed.into()),
+                Some(DividendClaim::Escrowed(amount)) => {
+                    dividend.escrowed = dividend.escrowed.saturating_sub(amount);
+                    Ok(amount)
+                }
+                None => {
+                    ensure!(
+                        frame_system::Pallet::<T>::block_number() <= dividend.deadline,
+                        Error::<T>::DividendExpired
+                    );
+                    let balance =
+                        Self::balance_at(asset_id, holder, dividend.snapshot_id).unwrap_or(0);
+                    let amount = dividend.share(balance);
+                    ensure!(amount > 0, Error::<T>::NoDividendShare);
+                    Ok(amount)
+                }
+            }
+        }
+
+        /// Settle the dividend shares of up to `limit` holders, resuming after
+        /// [`Dividend::last_holder`]. Returns the number of holders visited.
+        ///
+        /// Holders with a settled share or nothing at the snapshot are skipped.
+        /// The share of a recovered holder goes to [`Pallet::recovered_address`],
+        /// while the claim is recorded against the holder itself. Once the last
+        /// holder has been visited the dividend is marked distributed.
+        pub(crate) fn pay_dividends(
+            asset_id: AssetId,
+            dividend_id: DividendId,
+            mut dividend: DividendOf<T>,
+            limit: u32,
+        ) -> u32 {
+            let pot = Self::dividend_pot(asset_id, dividend_id);
+            let expired = frame_system::Pallet::<T>::block_number() > dividend.deadline;
+            let mut iter = match &dividend.last_holder {
+                Some(last) => Balances::<T>::iter_prefix_from(
+                    asset_id,
+                    Balances::<T>::hashed_key_for(asset_id, last),
+                ),
+                None => Balances::<T>::iter_prefix(asset_id),
+            };
 
+            let mut visited = 0;
+            while visited < limit {
+                let Some((account, _)) = iter.next() else { break };
+                visited += 1;
+                dividend.last_holder = Some(account.clone());
+                if DividendClaims::<T>::contains_key((asset_id, dividend_id, &account)) {
+                    continue;
+                }
+                let balance =
+                    Self::balance_at(asset_id, &account, dividend.snapshot_id).unwrap_or(0);
+                let amount = dividend.share(balance);
+                if amount == 0 {
+                    continue;
+                }
+                // The claim stays with the holder, the money goes to its current address
+                let payee = Self::recovered_address(asset_id, &account);
+                if Frozen::<T>::get(asset_id, &payee) {
+                    dividend.escrowed = dividend.escrowed.saturating_add(amount);
+                    DividendClaims::<T>::insert(
+                        (asset_id, dividend_id, &account),
+                        DividendClaim::Escrowed(amount),
+                    );
+                    Self::deposit_event(Event::DividendEscrowed {
+                        asset_id,
+                        dividend_id,
+                        account: payee,
+                        amount,
+                    });
+                    continue;
+                }
+                if expired {
+                    // Left in the pot for the issuer to reclaim
+                    continue;
+                }
+                let paid = T::Currency::transfer(
+                    &pot,
+                    &payee,
+                    amount.unique_saturated_into(),
+                    Preservation::Expendable,
+                );
+                if paid.is_ok() {
+                    dividend.paid = dividend.paid.saturating_add(amount);
+                    DividendClaims::<T>::insert(
+                        (asset_id, dividend_id, &account),
+                        DividendClaim::Paid(amount),
+                    );
+                    Self::deposit_event(Event::DividendPaid {
+                        asset_id,
+                        dividend_id,
+                        account: payee,
+                        amount,
+                    });
+                } else {
+                    Self::deposit_event(Event::DividendPaymentFailed {
+                        asset_id,
+                        dividend_id,
+                        account: payee,
+                        amount,
+                    });
+                }
+            }
+
+            if iter.next().is_none() {
+                dividend.distributed = true;
+                Self::deposit_event(Event::DividendDistributed {
+                    asset_id,
+                    dividend_id,
+                    paid: dividend.paid,
+                    escrowed: dividend.escrowed,
+                });
+            }
+            Dividends::<T>::insert(asset_id, dividend_id, dividend);
+            visited
+        }
+
+        /// Write a partially frozen amount, removing the entry when it is zero.
+        fn set_frozen_amount(asset_id: AssetId, who: &T::AccountId, amount: u128) {
+            if amount == 0 {
+                FrozenAmount::<T>::remove(asset_id, who);
+            } else {
+                FrozenAmount::<T>::insert(asset_id, who, amount);
+            }
+        }
+
+        /// Destroy `amount` tokens of `asset_id` held by `who`.
+        ///
+        /// Shared by [`Pallet::burn`] and [`Pallet::redeem`]. Applies the outgoing
+        /// transfer checks (whitelisted, not frozen) and keeps `TotalSupply` in
+        /// line with `Balances`. Emits no event; callers emit their own.
+        fn do_burn(asset_id: AssetId, who: &T::AccountId, amount: u128) -> DispatchResult {
+            Self::ensure_instrument(asset_id)?;
+            Self::ensure_no_coupon_distribution(asset_id)?;
+            Self::ensure_no_split(asset_id)?;
+            Self::ensure_verified(asset_id, who)?;
+            ensure!(!Frozen::<T>::get(asset_id, who), Error::<T>::AccountFrozen);
+            let balance = Self::ensure_can_spend(asset_id, who, amount)?;
+            Self::destroy(asset_id, who, balance, amount);
             Ok(())
         }
+
+        /// Remove `amount` of `who`'s `balance` from circulation, without checks.
+        fn destroy(asset_id: AssetId, who: &T::AccountId, balance: u128, amount: u128) {
+            // Supply is always >= any single balance, so this cannot saturate
+            Self::set_balance(asset_id, who, balance, balance - amount);
+            Self::record_snapshot_supply(asset_id);
+            TotalSupply::<T>::mutate(asset_id, |supply| *supply = supply.saturating_sub(amount));
+            T::Compliance::destroyed(asset_id, who, amount);
+        }
     }
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -1366,6 +8323,9 @@ pub mod pallet {
     /// It is typically configured in the chain spec file (`chain_spec.rs`) and
     /// applied during chain genesis.
     ///
+    /// The configured token is registered as instrument [`GENESIS_ASSET_ID`]. Further
+    /// instruments are created after launch with [`Pallet::create_instrument`].
+    ///
     /// # Overview
     ///
     /// The genesis configuration allows you to:
@@ -1373,6 +8333,7 @@ pub mod pallet {
     /// - Designate an admin account
     /// - Pre-whitelist accounts for transfers
     /// - Distribute initial token balances
+    /// - Cap the supply at the prospectus' authorised amount
     ///
     /// # Example Configuration (Rust)
     ///
@@ -1395,6 +8356,8 @@ pub mod pallet {
     ///                 // Mint $100M to treasury (Alice)
     ///                 (get_account_id_from_seed::<sr25519::Public>("Alice"), 100_000_000_000_000),
     ///             ],
+    ///             // $500M authorised, issued in several taps
+    ///             max_supply: Some(500_000_000_000_000),
     ///         },
     ///         // ... other pallets
     ///     }
@@ -1416,7 +8379,8 @@ pub mod pallet {
     ///     ],
     ///     "initialBalances": [
     ///       ["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", 100000000000000]
-    ///     ]
+    ///     ],
+    ///     "maxSupply": 500000000000000
     ///   }
     /// }
     /// ```
@@ -1426,6 +8390,7 @@ pub mod pallet {
     /// The genesis build will **panic** if:
     /// - `token_name` exceeds 64 bytes
     /// - `token_symbol` exceeds 16 bytes
+    /// - `initial_balances` add up to more than `max_supply`
     ///
     /// Always verify your configuration in a test environment before mainnet deployment.
     #[pallet::genesis_config]
@@ -1440,7 +8405,8 @@ pub mod pallet {
         /// # Note
         ///
         /// This does NOT grant admin privileges for extrinsics—that is controlled
-        /// by [`Config::AdminOrigin`]. This only auto-whitelists the account.
+        /// by [`Config::AdminOrigin`]. It only auto-whitelists the account and
+        /// grants it every [`Role`].
         pub admin: Option<T::AccountId>,
 
         /// Human-readable token name.
@@ -1500,6 +8466,11 @@ pub mod pallet {
         /// Make sure to also add them to `whitelisted_accounts` or
         /// specify an `admin` if the recipient should be able to transfer tokens.
         pub initial_balances: Vec<(T::AccountId, u128)>,
+
+        /// Authorised amount of the genesis instrument (raw value).
+        ///
+        /// `None` leaves the instrument uncapped. See [`MaxSupply`].
+        pub max_supply: Option<u128>,
     }
 
     /// Genesis build implementation.
@@ -1509,7 +8480,8 @@ pub mod pallet {
     ///
     /// # Initialization Order
     ///
-    /// 1. Set token metadata (name, symbol, decimals)
+    /// 1. Register instrument [`GENESIS_ASSET_ID`] with token metadata (name, symbol, decimals)
+    ///    and supply cap
     /// 2. Whitelist admin account (if provided)
     /// 3. Whitelist additional accounts
     /// 4. Mint initial balances
@@ -1519,38 +8491,50 @@ pub mod pallet {
     ///
     /// - If `token_name` exceeds 64 bytes
     /// - If `token_symbol` exceeds 16 bytes
+    /// - If the initial balances exceed `max_supply`
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            // Set token metadata
-            let name: BoundedVec<u8, ConstU32<64>> =
+            let asset_id = GENESIS_ASSET_ID;
+
+            // Register the genesis instrument and set its metadata
+            let name: TokenNameOf =
                 self.token_name.clone().try_into().expect("Token name too long (max 64 bytes)");
-            TokenName::<T>::put(name);
+            TokenName::<T>::insert(asset_id, name);
 
-            let symbol: BoundedVec<u8, ConstU32<16>> =
+            let symbol: TokenSymbolOf =
                 self.token_symbol.clone().try_into().expect("Token symbol too long (max 16 bytes)");
-            TokenSymbol::<T>::put(symbol);
+            TokenSymbol::<T>::insert(asset_id, symbol);
 
-            Decimals::<T>::put(self.decimals);
+            Decimals::<T>::insert(asset_id, self.decimals);
+            MaxSupply::<T>::set(asset_id, self.max_supply);
+            NextAssetId::<T>::put(asset_id + 1);
 
             // Set and whitelist admin if provided
             if let Some(ref admin) = self.admin {
                 Admin::<T>::put(admin);
-                Whitelist::<T>::insert(admin, true);
+                Whitelist::<T>::insert(asset_id, admin, WhitelistEntry::permanent());
+                for role in Role::ALL {
+                    Roles::<T>::insert(role, admin, true);
+                }
             }
 
             // Whitelist specified accounts
             for account in &self.whitelisted_accounts {
-                Whitelist::<T>::insert(account, true);
+                Whitelist::<T>::insert(asset_id, account, WhitelistEntry::permanent());
             }
 
             // Mint initial balances
             let mut total: u128 = 0;
             for (account, amount) in &self.initial_balances {
-                Balances::<T>::insert(account, amount);
+                let previous = Balances::<T>::get(asset_id, account);
+                Pallet::<T>::set_balance(asset_id, account, previous, *amount);
                 total = total.saturating_add(*amount);
             }
-            TotalSupply::<T>::put(total);
+            if let Some(max_supply) = self.max_supply {
+                assert!(total <= max_supply, "Initial balances exceed max supply");
+            }
+            TotalSupply::<T>::insert(asset_id, total);
         }
     }
 }
@@ -276,10 +276,484 @@ pub mod v1 {
     }
 }
 
+/// Migration to version 2 (multi-instrument storage).
+///
+/// Version 1 stored a single token: metadata and supply in `StorageValue`s and
+/// balances, freezes and whitelist entries in `StorageMap`s keyed by account.
+/// Version 2 keys every item by [`AssetId`](crate::AssetId). This migration
+/// moves the existing token into [`GENESIS_ASSET_ID`](crate::GENESIS_ASSET_ID)
+/// and registers it by setting [`NextAssetId`](crate::NextAssetId) to `1`.
+///
+/// # Weight
+///
+/// Linear in the number of balance, freeze and whitelist entries. Each entry is
+/// read once, removed once and written once under its new key.
+pub mod v2 {
+    use super::*;
+    use crate::{
+        Balances, Decimals, Frozen, NextAssetId, TokenName, TokenNameOf, TokenSymbol,
+        TokenSymbolOf, TotalSupply, GENESIS_ASSET_ID,
+    };
+    use sp_std::vec::Vec;
+
+    /// Storage layout as of version 1, used to read the old entries.
+    pub(crate) mod v1 {
+        use super::*;
+
+        #[frame_support::storage_alias]
+        pub type TokenName<T: Config> = StorageValue<Pallet<T>, TokenNameOf, OptionQuery>;
+
+        #[frame_support::storage_alias]
+        pub type TokenSymbol<T: Config> = StorageValue<Pallet<T>, TokenSymbolOf, OptionQuery>;
+
+        #[frame_support::storage_alias]
+        pub type Decimals<T: Config> = StorageValue<Pallet<T>, u8, OptionQuery>;
+
+        #[frame_support::storage_alias]
+        pub type TotalSupply<T: Config> = StorageValue<Pallet<T>, u128, OptionQuery>;
+
+        #[frame_support::storage_alias]
+        pub type Balances<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            u128,
+            OptionQuery,
+        >;
+
+        #[frame_support::storage_alias]
+        pub type Frozen<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            bool,
+            OptionQuery,
+        >;
+
+        #[frame_support::storage_alias]
+        pub type Whitelist<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            bool,
+            OptionQuery,
+        >;
+    }
+
+    /// Migration struct for upgrading storage to version 2.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The runtime configuration type implementing `Config`
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        /// Execute the migration.
+        ///
+        /// Only runs when the on-chain storage version is 1. The old map entries
+        /// are drained into memory before any new entry is written, because the
+        /// old and new layouts share the same storage prefixes.
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+            if on_chain_version != 1 {
+                log::info!(
+                    target: "pallet-clad-token",
+                    "Storage at v{on_chain_version:?}, skipping v2 migration"
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let asset_id = GENESIS_ASSET_ID;
+
+            // Metadata and supply: single values become entries of asset 0
+            if let Some(name) = v1::TokenName::<T>::take() {
+                TokenName::<T>::insert(asset_id, name);
+            }
+            if let Some(symbol) = v1::TokenSymbol::<T>::take() {
+                TokenSymbol::<T>::insert(asset_id, symbol);
+            }
+            if let Some(decimals) = v1::Decimals::<T>::take() {
+                Decimals::<T>::insert(asset_id, decimals);
+            }
+            if let Some(supply) = v1::TotalSupply::<T>::take() {
+                TotalSupply::<T>::insert(asset_id, supply);
+            }
+
+            // Per-account maps: drain fully before re-inserting under the new keys
+            let balances: Vec<_> = v1::Balances::<T>::drain().collect();
+            let frozen: Vec<_> = v1::Frozen::<T>::drain().collect();
+            let whitelist: Vec<_> = v1::Whitelist::<T>::drain().collect();
+            let count = (balances.len() + frozen.len() + whitelist.len()) as u64;
+
+            for (account, balance) in balances {
+                Balances::<T>::insert(asset_id, account, balance);
+            }
+            for (account, flag) in frozen {
+                Frozen::<T>::insert(asset_id, account, flag);
+            }
+            for (account, flag) in whitelist {
+                super::v3::v2::Whitelist::<T>::insert(asset_id, account, flag);
+            }
+
+            NextAssetId::<T>::put(asset_id + 1);
+            StorageVersion::new(2).put::<Pallet<T>>();
+
+            log::info!(
+                target: "pallet-clad-token",
+                "Migrated {count} account entries into asset {asset_id} (v1 → v2)"
+            );
+
+            // Reads: version + 4 values + entries
+            // Writes: 4 values removed and re-inserted + entries removed and re-inserted
+            //         + NextAssetId + version
+            T::DbWeight::get().reads_writes(count + 5, count * 2 + 10)
+        }
+
+        /// Pre-upgrade check (requires `try-runtime` feature).
+        ///
+        /// Records the v1 total supply and number of balance holders.
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let supply = v1::TotalSupply::<T>::get().unwrap_or_default();
+            let holders = v1::Balances::<T>::iter_keys().count() as u32;
+            Ok((supply, holders).encode())
+        }
+
+        /// Post-upgrade check (requires `try-runtime` feature).
+        ///
+        /// Verifies that supply and holders now live under the genesis asset.
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let (supply, holders): (u128, u32) = Decode::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::TryRuntimeError::Other("Failed to decode pre-state"))?;
+
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 2,
+                sp_runtime::TryRuntimeError::Other("Migration to v2 did not complete")
+            );
+            frame_support::ensure!(
+                TotalSupply::<T>::get(GENESIS_ASSET_ID) == supply,
+                sp_runtime::TryRuntimeError::Other("Total supply changed during v2 migration")
+            );
+            frame_support::ensure!(
+                Balances::<T>::iter_prefix(GENESIS_ASSET_ID).count() as u32 == holders,
+                sp_runtime::TryRuntimeError::Other("Holder count changed during v2 migration")
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Migration from v2 to v3: whitelist entries carry an optional expiry.
+///
+/// Version 2 stored a `bool` per `(AssetId, AccountId)`. Version 3 stores a
+/// [`WhitelistEntry`](crate::WhitelistEntry). Every `true` flag becomes a
+/// permanent entry (no expiry); `false` flags, which were never written by the
+/// pallet but would have meant "not whitelisted", are dropped.
+///
+/// # Weight
+///
+/// One read and one write per whitelist entry.
+pub mod v3 {
+    use super::*;
+    use crate::{AssetId, Whitelist, WhitelistEntry};
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Storage layout as of version 2, used to read the old entries.
+    pub(crate) mod v2 {
+        use super::*;
+
+        #[frame_support::storage_alias]
+        pub type Whitelist<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            AssetId,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            bool,
+            OptionQuery,
+        >;
+    }
+
+    /// Migration struct for upgrading storage to version 3.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The runtime configuration type implementing `Config`
+    pub struct MigrateToV3<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        /// Execute the migration.
+        ///
+        /// Only runs when the on-chain storage version is 2. Entries are translated
+        /// in place; the key layout is unchanged.
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+            if on_chain_version != 2 {
+                log::info!(
+                    target: "pallet-clad-token",
+                    "Storage at v{on_chain_version:?}, skipping v3 migration"
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut count: u64 = 0;
+            Whitelist::<T>::translate::<bool, _>(|_, _, flag| {
+                count += 1;
+                flag.then(WhitelistEntry::permanent)
+            });
+            StorageVersion::new(3).put::<Pallet<T>>();
+
+            log::info!(
+                target: "pallet-clad-token",
+                "Migrated {count} whitelist entries to permanent approvals (v2 → v3)"
+            );
+
+            // Reads: version + entries
+            // Writes: entries + version
+            T::DbWeight::get().reads_writes(count + 1, count + 1)
+        }
+
+        /// Pre-upgrade check (requires `try-runtime` feature).
+        ///
+        /// Records the number of whitelisted accounts.
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let whitelisted = v2::Whitelist::<T>::iter_values().filter(|flag| *flag).count() as u32;
+            Ok(whitelisted.encode())
+        }
+
+        /// Post-upgrade check (requires `try-runtime` feature).
+        ///
+        /// Verifies that every whitelisted account still has a permanent entry.
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let whitelisted: u32 = Decode::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::TryRuntimeError::Other("Failed to decode pre-state"))?;
+
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 3,
+                sp_runtime::TryRuntimeError::Other("Migration to v3 did not complete")
+            );
+            frame_support::ensure!(
+                Whitelist::<T>::iter_values().filter(|entry| entry.expires_at.is_none()).count()
+                    as u32
+                    == whitelisted,
+                sp_runtime::TryRuntimeError::Other("Whitelist changed during v3 migration")
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Migration from v3 to v4: backfill holder counts.
+///
+/// Version 4 introduces [`HolderCount`](crate::HolderCount), maintained on every
+/// balance change. This migration seeds it by counting the non-zero
+/// [`Balances`](crate::Balances) entries of each instrument.
+///
+/// # Weight
+///
+/// One read per balance entry, plus one write per instrument with holders. The
+/// migration is not stepped: it walks every balance in the upgrade block, which
+/// is fine for the few thousand balances a v3 chain holds. The full weight is
+/// reported, and a warning is logged if it exceeds the block's maximum weight;
+/// a chain that large needs a multi-block migration instead.
+pub mod v4 {
+    use super::*;
+    use crate::{AssetId, Balances, HolderCount};
+    use sp_std::collections::btree_map::BTreeMap;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Count the non-zero balances of each instrument.
+    fn count_holders<T: Config>() -> (BTreeMap<AssetId, u32>, u64) {
+        let mut reads: u64 = 0;
+        let mut holders: BTreeMap<AssetId, u32> = BTreeMap::new();
+        for (asset_id, _, balance) in Balances::<T>::iter() {
+            reads += 1;
+            if balance > 0 {
+                *holders.entry(asset_id).or_default() += 1;
+            }
+        }
+        (holders, reads)
+    }
+
+    /// Migration struct for upgrading storage to version 4.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The runtime configuration type implementing `Config`
+    pub struct MigrateToV4<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        /// Execute the migration.
+        ///
+        /// Only runs when the on-chain storage version is 3.
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+            if on_chain_version != 3 {
+                log::info!(
+                    target: "pallet-clad-token",
+                    "Storage at v{on_chain_version:?}, skipping v4 migration"
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let (holders, reads) = count_holders::<T>();
+            let writes = holders.len() as u64;
+            for (asset_id, count) in holders {
+                HolderCount::<T>::insert(asset_id, count);
+            }
+            StorageVersion::new(4).put::<Pallet<T>>();
+
+            log::info!(
+                target: "pallet-clad-token",
+                "Counted holders of {writes} instruments from {reads} balances (v3 → v4)"
+            );
+
+            // Reads: version + balances
+            // Writes: one count per instrument + version
+            let weight = T::DbWeight::get().reads_writes(reads + 1, writes + 1);
+            let max_block = <T as frame_system::Config>::BlockWeights::get().max_block;
+            if weight.any_gt(max_block) {
+                log::warn!(
+                    target: "pallet-clad-token",
+                    "v4 migration used {weight:?}, more than a block's {max_block:?}"
+                );
+            }
+            weight
+        }
+
+        /// Pre-upgrade check (requires `try-runtime` feature).
+        ///
+        /// Records the number of non-zero balances of each instrument.
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let (holders, _) = count_holders::<T>();
+            Ok(holders.into_iter().collect::<Vec<(AssetId, u32)>>().encode())
+        }
+
+        /// Post-upgrade check (requires `try-runtime` feature).
+        ///
+        /// Verifies that every instrument with holders has a matching count, and
+        /// that no other instrument has one.
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let holders: Vec<(AssetId, u32)> = Decode::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::TryRuntimeError::Other("Failed to decode pre-state"))?;
+
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 4,
+                sp_runtime::TryRuntimeError::Other("Migration to v4 did not complete")
+            );
+            for (asset_id, count) in &holders {
+                frame_support::ensure!(
+                    HolderCount::<T>::get(asset_id) == *count,
+                    sp_runtime::TryRuntimeError::Other("Holder count mismatch after v4 migration")
+                );
+            }
+            frame_support::ensure!(
+                HolderCount::<T>::iter().filter(|(_, count)| *count > 0).count() == holders.len(),
+                sp_runtime::TryRuntimeError::Other("Stray holder count after v4 migration")
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Migration from v4 to v5: seed the role registry.
+///
+/// Version 5 introduces [`Roles`](crate::Roles), and role-gated calls only accept
+/// role holders. So that the existing operator keeps every permission across the
+/// upgrade, the storage [`Admin`](crate::Admin), if any, is granted every
+/// [`Role`](crate::Role). Separating duties is then a matter of granting roles to
+/// other keys and revoking them from the admin.
+///
+/// # Weight
+///
+/// One read of the admin, plus one write per role.
+pub mod v5 {
+    use super::*;
+    use crate::{Admin, Role, Roles};
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Migration struct for upgrading storage to version 5.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The runtime configuration type implementing `Config`
+    pub struct MigrateToV5<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+        /// Execute the migration.
+        ///
+        /// Only runs when the on-chain storage version is 4.
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+            if on_chain_version != 4 {
+                log::info!(
+                    target: "pallet-clad-token",
+                    "Storage at v{on_chain_version:?}, skipping v5 migration"
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut writes: u64 = 0;
+            if let Some(admin) = Admin::<T>::get() {
+                for role in Role::ALL {
+                    Roles::<T>::insert(role, &admin, true);
+                    writes += 1;
+                }
+            }
+            StorageVersion::new(5).put::<Pallet<T>>();
+
+            log::info!(
+                target: "pallet-clad-token",
+                "Granted {writes} roles to the admin (v4 → v5)"
+            );
+
+            // Reads: version + admin
+            // Writes: one per role + version
+            T::DbWeight::get().reads_writes(2, writes + 1)
+        }
+
+        /// Post-upgrade check (requires `try-runtime` feature).
+        ///
+        /// Verifies that the admin holds every role.
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 5,
+                sp_runtime::TryRuntimeError::Other("Migration to v5 did not complete")
+            );
+            if let Some(admin) = Admin::<T>::get() {
+                frame_support::ensure!(
+                    Role::ALL.iter().all(|role| Roles::<T>::get(role, &admin)),
+                    sp_runtime::TryRuntimeError::Other("Admin lacks a role after v5 migration")
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::mock::{new_test_ext, Test};
+    use crate::{
+        Admin, Balances, HolderCount, NextAssetId, Role, Roles, TokenNameOf, TokenSymbolOf,
+        WhitelistEntry, GENESIS_ASSET_ID,
+    };
     use frame_support::traits::StorageVersion;
 
     // Note: Weight assertions are omitted in these tests because the mock runtime
@@ -355,4 +829,153 @@ mod tests {
             assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
         });
     }
+
+    /// Test that v1 single-token state is moved into the genesis asset.
+    #[test]
+    fn migration_v2_moves_token_into_genesis_asset() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            // Write the v1 layout directly
+            StorageVersion::new(1).put::<Pallet<Test>>();
+            v2::v1::TokenName::<Test>::put(TokenNameOf::truncate_from(b"Old Bond".to_vec()));
+            v2::v1::TokenSymbol::<Test>::put(TokenSymbolOf::truncate_from(b"OLD".to_vec()));
+            v2::v1::Decimals::<Test>::put(6);
+            v2::v1::TotalSupply::<Test>::put(1_500);
+            v2::v1::Balances::<Test>::insert(2, 1_000);
+            v2::v1::Balances::<Test>::insert(3, 500);
+            v2::v1::Whitelist::<Test>::insert(2, true);
+            v2::v1::Whitelist::<Test>::insert(3, true);
+            v2::v1::Frozen::<Test>::insert(3, true);
+
+            v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+            assert_eq!(Pallet::<Test>::on_chain_storage_version(), 2);
+            assert_eq!(NextAssetId::<Test>::get(), 1);
+            assert_eq!(Pallet::<Test>::token_name(GENESIS_ASSET_ID).to_vec(), b"Old Bond".to_vec());
+            assert_eq!(Pallet::<Test>::token_symbol(GENESIS_ASSET_ID).to_vec(), b"OLD".to_vec());
+            assert_eq!(Pallet::<Test>::decimals(GENESIS_ASSET_ID), 6);
+            assert_eq!(Pallet::<Test>::total_supply(GENESIS_ASSET_ID), 1_500);
+            assert_eq!(Pallet::<Test>::balance_of(GENESIS_ASSET_ID, 2), 1_000);
+            assert_eq!(Pallet::<Test>::balance_of(GENESIS_ASSET_ID, 3), 500);
+            assert_eq!(v3::v2::Whitelist::<Test>::get(GENESIS_ASSET_ID, 2), Some(true));
+            assert!(Pallet::<Test>::is_frozen(GENESIS_ASSET_ID, 3));
+            assert!(!Pallet::<Test>::is_frozen(GENESIS_ASSET_ID, 2));
+
+            // Old values are gone
+            assert!(v2::v1::TotalSupply::<Test>::get().is_none());
+        });
+    }
+
+    /// Test that v2 migration does not run on other versions.
+    #[test]
+    fn migration_v2_skipped_when_not_v1() {
+        new_test_ext().execute_with(|| {
+            // Genesis already writes the latest layout
+            assert_eq!(Pallet::<Test>::on_chain_storage_version(), 5);
+            let supply = Pallet::<Test>::total_supply(GENESIS_ASSET_ID);
+
+            v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+            assert_eq!(Pallet::<Test>::on_chain_storage_version(), 5);
+            assert_eq!(Pallet::<Test>::total_supply(GENESIS_ASSET_ID), supply);
+            assert_eq!(NextAssetId::<Test>::get(), 1);
+        });
+    }
+
+    /// Test that v2 boolean whitelist flags become permanent entries.
+    #[test]
+    fn migration_v3_converts_whitelist_flags() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            StorageVersion::new(2).put::<Pallet<Test>>();
+            v3::v2::Whitelist::<Test>::insert(GENESIS_ASSET_ID, 2, true);
+            v3::v2::Whitelist::<Test>::insert(GENESIS_ASSET_ID, 3, false);
+            v3::v2::Whitelist::<Test>::insert(1, 2, true);
+
+            v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+            assert_eq!(Pallet::<Test>::on_chain_storage_version(), 3);
+            assert_eq!(
+                Pallet::<Test>::whitelist_entry(GENESIS_ASSET_ID, 2),
+                Some(WhitelistEntry::permanent())
+            );
+            assert_eq!(Pallet::<Test>::whitelist_entry(1, 2), Some(WhitelistEntry::permanent()));
+            assert_eq!(Pallet::<Test>::whitelist_entry(GENESIS_ASSET_ID, 3), None);
+        });
+    }
+
+    /// Test that a v1 chain reaches v3 when both migrations run in order.
+    #[test]
+    fn migrations_v2_and_v3_chain() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            StorageVersion::new(1).put::<Pallet<Test>>();
+            v2::v1::Whitelist::<Test>::insert(2, true);
+
+            v2::MigrateToV2::<Test>::on_runtime_upgrade();
+            v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+            assert_eq!(Pallet::<Test>::on_chain_storage_version(), 3);
+            assert!(Pallet::<Test>::is_whitelisted(GENESIS_ASSET_ID, &2));
+        });
+    }
+
+    /// Test that v4 counts the non-zero balances of each instrument.
+    #[test]
+    fn migration_v4_counts_holders() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            StorageVersion::new(3).put::<Pallet<Test>>();
+            Balances::<Test>::insert(GENESIS_ASSET_ID, 2, 1_000);
+            Balances::<Test>::insert(GENESIS_ASSET_ID, 3, 500);
+            Balances::<Test>::insert(GENESIS_ASSET_ID, 4, 0);
+            Balances::<Test>::insert(1, 2, 10);
+
+            v4::MigrateToV4::<Test>::on_runtime_upgrade();
+
+            assert_eq!(Pallet::<Test>::on_chain_storage_version(), 4);
+            assert_eq!(Pallet::<Test>::holder_count(GENESIS_ASSET_ID), 2);
+            assert_eq!(Pallet::<Test>::holder_count(1), 1);
+            assert_eq!(Pallet::<Test>::holder_count(2), 0);
+        });
+    }
+
+    /// Test that v4 does not recount a chain already at v4.
+    #[test]
+    fn migration_v4_skipped_when_not_v3() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(Pallet::<Test>::holder_count(GENESIS_ASSET_ID), 2);
+            HolderCount::<Test>::insert(GENESIS_ASSET_ID, 7);
+
+            v4::MigrateToV4::<Test>::on_runtime_upgrade();
+
+            assert_eq!(Pallet::<Test>::holder_count(GENESIS_ASSET_ID), 7);
+        });
+    }
+
+    /// Test that v5 grants the existing admin every role.
+    #[test]
+    fn migration_v5_grants_admin_all_roles() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            StorageVersion::new(4).put::<Pallet<Test>>();
+            Admin::<Test>::put(7);
+
+            v5::MigrateToV5::<Test>::on_runtime_upgrade();
+
+            assert_eq!(Pallet::<Test>::on_chain_storage_version(), 5);
+            for role in Role::ALL {
+                assert!(Pallet::<Test>::has_role(role, 7));
+            }
+            assert!(!Pallet::<Test>::has_role(Role::Minter, 1));
+        });
+    }
+
+    /// Test that v5 only bumps the version when no admin is set.
+    #[test]
+    fn migration_v5_without_admin() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            StorageVersion::new(4).put::<Pallet<Test>>();
+
+            v5::MigrateToV5::<Test>::on_runtime_upgrade();
+
+            assert_eq!(Pallet::<Test>::on_chain_storage_version(), 5);
+            assert_eq!(Roles::<Test>::iter().count(), 0);
+        });
+    }
 }
@@ -6,12 +6,14 @@
 //! # Test Fixtures
 //!
 //! ## Accounts
-//! - **Account 1**: Admin account with `AdminOrigin` privileges (can mint, freeze, whitelist)
+//! - **Account 1**: Admin account with `AdminOrigin` privileges, holding every
+//!   [`Role`](crate::Role) from genesis (can mint, freeze, whitelist)
 //! - **Account 2**: Whitelisted user with 1,000,000 tokens initial balance
 //! - **Account 3**: Whitelisted user with 500,000 tokens initial balance
 //! - **Accounts 4+**: Not whitelisted, zero balance (use for testing non-whitelisted scenarios)
 //!
 //! ## Initial State (via `new_test_ext()`)
+//! - One instrument registered: [`ASSET`] (the genesis asset, id 0)
 //! - Token name: "Test Token"
 //! - Token symbol: "TST"
 //! - Decimals: 6
@@ -19,28 +21,46 @@
 //! - Whitelisted accounts: 1 (admin), 2, 3
 //! - Frozen accounts: none
 //!
+//! ## Native Currency
+//! - `pallet_balances` with an existential deposit of 1 ([`ExistentialDeposit`]);
+//!   nobody is endowed
+//! - Every coupon and redemption pot resolves to the same account, because
+//!   `u64` account ids truncate the sub-account seed
+//!
+//! ## Time-Locked Mints
+//! - [`LargeMintThreshold`] is `u128::MAX` by default, so every mint executes at once
+//! - Large mints wait 10 blocks; [`AuditOffice`] (account 9) can cancel them
+//!
+//! ## Compliance
+//! - [`TransferLimitCompliance`] rejects transfers above [`TransferLimit`] (unlimited by default)
+//! - [`RecordingCompliance`] records every notification hook in [`ComplianceCalls`]
+//!
 //! # Example Usage
 //! ```ignore
 //! #[test]
 //! fn my_test() {
 //!     new_test_ext().execute_with(|| {
 //!         // Account 2 has 1_000_000 tokens and is whitelisted
-//!         assert_eq!(CladToken::balance_of(&2), 1_000_000);
+//!         assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_000);
 //!         // Account 1 is admin and can mint
-//!         assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 5, 1000));
+//!         assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 1000));
 //!     });
 //! }
 //! ```
 
 use crate as pallet_clad_token;
+use crate::{AssetId, TransferCompliance};
 use frame_support::{
-    derive_impl, parameter_types,
+    derive_impl,
+    dispatch::DispatchResult,
+    ensure, parameter_types,
     traits::{ConstU32, ConstU64},
+    PalletId,
 };
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, DispatchError,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -49,6 +69,7 @@ type Block = frame_system::mocking::MockBlock<Test>;
 frame_support::construct_runtime!(
     pub enum Test {
         System: frame_system,
+        Balances: pallet_balances,
         CladToken: pallet_clad_token,
     }
 );
@@ -71,7 +92,7 @@ impl frame_system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -80,10 +101,74 @@ impl frame_system::Config for Test {
     type MaxConsumers = ConstU32<16>;
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+    type ExistentialDeposit = ExistentialDeposit;
+}
+
 parameter_types! {
     pub const AdminAccount: u64 = 1;
+    pub const CladTokenPalletId: PalletId = PalletId(*b"cladtokn");
+    pub static PauseBlocksMinting: bool = true;
+    pub static TransferLimit: u128 = u128::MAX;
+    pub static ComplianceCalls: Vec<ComplianceCall> = Vec::new();
+    pub static LargeMintThreshold: u128 = u128::MAX;
+    pub static ExistentialDeposit: u64 = 1;
 }
 
+frame_support::ord_parameter_types! {
+    pub const AuditOffice: u64 = 9;
+}
+
+/// A compliance hook invocation recorded by [`RecordingCompliance`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ComplianceCall {
+    Transferred { asset_id: AssetId, from: u64, to: u64, amount: u128 },
+    Created { asset_id: AssetId, to: u64, amount: u128 },
+    Destroyed { asset_id: AssetId, from: u64, amount: u128 },
+}
+
+/// Compliance module rejecting transfers above [`TransferLimit`].
+pub struct TransferLimitCompliance;
+impl TransferCompliance<u64> for TransferLimitCompliance {
+    fn can_transfer(_: AssetId, _: &u64, _: &u64, amount: u128) -> DispatchResult {
+        ensure!(amount <= TransferLimit::get(), DispatchError::Other("TransferLimitExceeded"));
+        Ok(())
+    }
+    fn transferred(_: AssetId, _: &u64, _: &u64, _: u128) {}
+    fn created(_: AssetId, _: &u64, _: u128) {}
+    fn destroyed(_: AssetId, _: &u64, _: u128) {}
+}
+
+/// Compliance module recording every notification in [`ComplianceCalls`].
+pub struct RecordingCompliance;
+impl TransferCompliance<u64> for RecordingCompliance {
+    fn can_transfer(_: AssetId, _: &u64, _: &u64, _: u128) -> DispatchResult {
+        Ok(())
+    }
+    fn transferred(asset_id: AssetId, from: &u64, to: &u64, amount: u128) {
+        ComplianceCalls::mutate(|calls| {
+            calls.push(ComplianceCall::Transferred { asset_id, from: *from, to: *to, amount })
+        });
+    }
+    fn created(asset_id: AssetId, to: &u64, amount: u128) {
+        ComplianceCalls::mutate(|calls| {
+            calls.push(ComplianceCall::Created { asset_id, to: *to, amount })
+        });
+    }
+    fn destroyed(asset_id: AssetId, from: &u64, amount: u128) {
+        ComplianceCalls::mutate(|calls| {
+            calls.push(ComplianceCall::Destroyed { asset_id, from: *from, amount })
+        });
+    }
+}
+
+/// The instrument created by the genesis config.
+pub const ASSET: AssetId = pallet_clad_token::GENESIS_ASSET_ID;
+
+/// Admin origin: signed by [`AdminAccount`], or Root standing in for an admin
+/// origin without a signer (a collective, a multi-sig proxy).
 pub struct EnsureAdmin;
 impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureAdmin {
     type Success = u64;
@@ -93,6 +178,7 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureAdmin {
             Ok(frame_system::RawOrigin::Signed(account)) if account == AdminAccount::get() => {
                 Ok(account)
             }
+            Ok(frame_system::RawOrigin::Root) => Ok(AdminAccount::get()),
             _ => Err(o),
         }
     }
@@ -105,6 +191,23 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureAdmin {
 
 impl pallet_clad_token::Config for Test {
     type AdminOrigin = EnsureAdmin;
+    type MaxInstruments = ConstU32<4>;
+    type PauseBlocksMinting = PauseBlocksMinting;
+    type Compliance = (TransferLimitCompliance, RecordingCompliance);
+    type MaxClaimTopics = ConstU32<4>;
+    type MaxWhitelistSweep = ConstU32<2>;
+    type MaxCountries = ConstU32<4>;
+    type MaxVestingSchedules = ConstU32<2>;
+    type Currency = Balances;
+    type PalletId = CladTokenPalletId;
+    type MaxCouponDates = ConstU32<4>;
+    type MaxHoldersPerPage = ConstU32<2>;
+    type LargeMintThreshold = LargeMintThreshold;
+    type MintDelay = ConstU64<10>;
+    type VetoOrigin = frame_system::EnsureSignedBy<AuditOffice, u64>;
+    type MaxPendingMints = ConstU32<2>;
+    type SupplyCapOrigin = frame_system::EnsureRoot<u64>;
+    type MaxBatchSize = ConstU32<4>;
     type WeightInfo = ();
 }
 
@@ -126,6 +229,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
         decimals: 6,
         whitelisted_accounts: vec![2, 3],
         initial_balances: vec![(2, 1_000_000), (3, 500_000)],
+        max_supply: None,
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -0,0 +1,137 @@
+//! Operator roles for pallet-clad-token.
+//!
+//! Day-to-day operations are performed by signed accounts holding a [`Role`], so a
+//! transfer agent can whitelist investors without also being able to mint:
+//!
+//! | Role | Calls |
+//! |------|-------|
+//! | [`Role::Minter`] | [`mint`](crate::Pallet::mint), [`mint_with_schedule`](crate::Pallet::mint_with_schedule), [`burn`](crate::Pallet::burn), [`batch_mint`](crate::Pallet::batch_mint) |
+//! | [`Role::Freezer`] | [`freeze`](crate::Pallet::freeze), [`unfreeze`](crate::Pallet::unfreeze), [`freeze_partial`](crate::Pallet::freeze_partial), [`unfreeze_partial`](crate::Pallet::unfreeze_partial), [`batch_freeze`](crate::Pallet::batch_freeze) |
+//! | [`Role::ComplianceAgent`] | [`add_to_whitelist`](crate::Pallet::add_to_whitelist), [`remove_from_whitelist`](crate::Pallet::remove_from_whitelist), [`batch_whitelist`](crate::Pallet::batch_whitelist), [`forced_transfer`](crate::Pallet::forced_transfer), [`recover_account`](crate::Pallet::recover_account) |
+//! | [`Role::RoleAdmin`] | [`grant_role`](crate::Pallet::grant_role), [`revoke_role`](crate::Pallet::revoke_role) |
+//!
+//! Roles are chain-wide, like claims: a minter may mint every instrument. Every
+//! other privileged call requires the admin origin.
+//!
+//! [`Config::AdminOrigin`](crate::Config::AdminOrigin) does not pass role checks;
+//! it only grants and revokes [`Role::RoleAdmin`]. The admin account starts out
+//! holding every role (from genesis or the v5 migration), so duties are separated
+//! by granting the roles to other keys and revoking them from the admin.
+//!
+//! # Gating Other Pallets
+//!
+//! [`EnsureRole`] is an `EnsureOrigin` accepting signed holders of one role, for
+//! runtimes that want to gate their own calls on the registry:
+//!
+//! ```ignore
+//! impl pallet_example::Config for Runtime {
+//!     type ReportOrigin = pallet_clad_token::EnsureComplianceAgent<Runtime>;
+//! }
+//! ```
+
+use crate::{Config, Roles};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::marker::PhantomData;
+
+/// A permission that can be granted to an account.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum Role {
+    /// Issue and destroy tokens.
+    Minter,
+    /// Freeze and unfreeze accounts and balances.
+    Freezer,
+    /// Manage the whitelist, force transfers and recover lost wallets.
+    ComplianceAgent,
+    /// Grant and revoke the other roles. Appointed by the admin origin.
+    RoleAdmin,
+}
+
+impl Role {
+    /// Every role, in declaration order.
+    pub const ALL: [Role; 4] =
+        [Role::Minter, Role::Freezer, Role::ComplianceAgent, Role::RoleAdmin];
+}
+
+/// [`Role::Minter`] as a type, for [`EnsureRole`].
+pub struct MinterRole;
+impl Get<Role> for MinterRole {
+    fn get() -> Role {
+        Role::Minter
+    }
+}
+
+/// [`Role::Freezer`] as a type, for [`EnsureRole`].
+pub struct FreezerRole;
+impl Get<Role> for FreezerRole {
+    fn get() -> Role {
+        Role::Freezer
+    }
+}
+
+/// [`Role::ComplianceAgent`] as a type, for [`EnsureRole`].
+pub struct ComplianceAgentRole;
+impl Get<Role> for ComplianceAgentRole {
+    fn get() -> Role {
+        Role::ComplianceAgent
+    }
+}
+
+/// [`Role::RoleAdmin`] as a type, for [`EnsureRole`].
+pub struct RoleAdminRole;
+impl Get<Role> for RoleAdminRole {
+    fn get() -> Role {
+        Role::RoleAdmin
+    }
+}
+
+/// Origin check passing for signed accounts that hold the role `R`.
+///
+/// Succeeds with the signer. Like the pallet's own calls, it does not accept
+/// [`Config::AdminOrigin`](crate::Config::AdminOrigin); combine the two with
+/// `EitherOfDiverse` if the admin should pass too.
+pub struct EnsureRole<T, R>(PhantomData<(T, R)>);
+
+impl<T: Config, R: Get<Role>> EnsureOrigin<T::RuntimeOrigin> for EnsureRole<T, R> {
+    type Success = T::AccountId;
+
+    fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+        match o.clone().into() {
+            Ok(RawOrigin::Signed(who)) if Roles::<T>::get(R::get(), &who) => Ok(who),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+        let who: T::AccountId = frame_benchmarking::whitelisted_caller();
+        Roles::<T>::insert(R::get(), &who, true);
+        Ok(RawOrigin::Signed(who).into())
+    }
+}
+
+/// Signed holders of [`Role::Minter`].
+pub type EnsureMinter<T> = EnsureRole<T, MinterRole>;
+
+/// Signed holders of [`Role::Freezer`].
+pub type EnsureFreezer<T> = EnsureRole<T, FreezerRole>;
+
+/// Signed holders of [`Role::ComplianceAgent`].
+pub type EnsureComplianceAgent<T> = EnsureRole<T, ComplianceAgentRole>;
+
+/// Signed holders of [`Role::RoleAdmin`].
+pub type EnsureRoleAdmin<T> = EnsureRole<T, RoleAdminRole>;
@@ -1,28 +1,33 @@
 // Allow clippy warnings for test code (bool assertions and borrows are fine here)
 #![allow(clippy::bool_assert_comparison, clippy::needless_borrows_for_generic_args)]
 
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+    mock::*, AssetId, BondTerms, BondTermsOf, Claim, ClaimTopic, ClaimTopicsOf, CountriesOf,
+    CountryCode, Document, DocumentNameOf, Error, Event, ForcedTransferCode, ForcedTransferReason,
+    HoldingLimit, InstrumentMetadata, Role, VestingSchedule, WhitelistEntry,
+};
+use frame_support::{assert_noop, assert_ok, traits::fungible::Mutate};
+use sp_runtime::Perbill;
 
 #[test]
 fn genesis_config_works() {
     new_test_ext().execute_with(|| {
         // Check token metadata
-        assert_eq!(CladToken::token_name(), b"Test Token".to_vec());
-        assert_eq!(CladToken::token_symbol(), b"TST".to_vec());
-        assert_eq!(CladToken::decimals(), 6);
+        assert_eq!(CladToken::token_name(ASSET), b"Test Token".to_vec());
+        assert_eq!(CladToken::token_symbol(ASSET), b"TST".to_vec());
+        assert_eq!(CladToken::decimals(ASSET), 6);
 
         // Check admin is whitelisted
-        assert_eq!(CladToken::whitelist(&1), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &1), true);
 
         // Check initial balances
-        assert_eq!(CladToken::balance_of(&2), 1_000_000);
-        assert_eq!(CladToken::balance_of(&3), 500_000);
-        assert_eq!(CladToken::total_supply(), 1_500_000);
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_000);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 500_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_000);
 
         // Check whitelisted accounts
-        assert_eq!(CladToken::whitelist(&2), true);
-        assert_eq!(CladToken::whitelist(&3), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &3), true);
     });
 }
 
@@ -32,14 +37,14 @@ fn mint_works() {
         System::set_block_number(1);
 
         // Admin (account 1) can mint
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 5, 10_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 10_000));
 
         // Check balance and total supply updated
-        assert_eq!(CladToken::balance_of(&5), 10_000);
-        assert_eq!(CladToken::total_supply(), 1_510_000);
+        assert_eq!(CladToken::balance_of(ASSET, &5), 10_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_510_000);
 
         // Check event emitted
-        System::assert_last_event(Event::Minted { to: 5, amount: 10_000 }.into());
+        System::assert_last_event(Event::Minted { asset_id: ASSET, to: 5, amount: 10_000 }.into());
     });
 }
 
@@ -48,7 +53,7 @@ fn mint_fails_for_non_admin() {
     new_test_ext().execute_with(|| {
         // Non-admin (account 2) cannot mint
         assert_noop!(
-            CladToken::mint(RuntimeOrigin::signed(2), 5, 10_000),
+            CladToken::mint(RuntimeOrigin::signed(2), ASSET, 5, 10_000),
             sp_runtime::DispatchError::BadOrigin
         );
     });
@@ -60,14 +65,23 @@ fn transfer_works_for_whitelisted_accounts() {
         System::set_block_number(1);
 
         // Account 2 -> Account 3 transfer (both whitelisted)
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 3, 100_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100_000));
 
         // Check balances updated
-        assert_eq!(CladToken::balance_of(&2), 900_000);
-        assert_eq!(CladToken::balance_of(&3), 600_000);
+        assert_eq!(CladToken::balance_of(ASSET, &2), 900_000);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 600_000);
 
         // Check event emitted
-        System::assert_last_event(Event::Transferred { from: 2, to: 3, amount: 100_000 }.into());
+        System::assert_last_event(
+            Event::Transferred {
+                asset_id: ASSET,
+                from: 2,
+                to: 3,
+                amount: 100_000,
+                reference: None,
+            }
+            .into(),
+        );
     });
 }
 
@@ -75,11 +89,11 @@ fn transfer_works_for_whitelisted_accounts() {
 fn transfer_fails_when_sender_not_whitelisted() {
     new_test_ext().execute_with(|| {
         // Mint tokens to non-whitelisted account 5
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 5, 10_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 10_000));
 
         // Account 5 (not whitelisted) cannot transfer
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(5), 2, 5_000),
+            CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 2, 5_000),
             Error::<Test>::NotWhitelisted
         );
     });
@@ -90,7 +104,7 @@ fn transfer_fails_when_receiver_not_whitelisted() {
     new_test_ext().execute_with(|| {
         // Account 2 (whitelisted) cannot transfer to account 5 (not whitelisted)
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(2), 5, 5_000),
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 5, 5_000),
             Error::<Test>::NotWhitelisted
         );
     });
@@ -100,11 +114,11 @@ fn transfer_fails_when_receiver_not_whitelisted() {
 fn transfer_fails_when_sender_frozen() {
     new_test_ext().execute_with(|| {
         // Freeze account 2
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), 2));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
 
         // Frozen account 2 cannot transfer
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(2), 3, 5_000),
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 5_000),
             Error::<Test>::AccountFrozen
         );
     });
@@ -115,7 +129,7 @@ fn transfer_fails_with_insufficient_balance() {
     new_test_ext().execute_with(|| {
         // Account 2 tries to transfer more than balance
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(2), 3, 2_000_000),
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 2_000_000),
             Error::<Test>::InsufficientBalance
         );
     });
@@ -127,13 +141,13 @@ fn freeze_works() {
         System::set_block_number(1);
 
         // Admin freezes account 2
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), 2));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
 
         // Check account is frozen
-        assert_eq!(CladToken::is_frozen(&2), true);
+        assert_eq!(CladToken::is_frozen(ASSET, &2), true);
 
         // Check event emitted
-        System::assert_last_event(Event::Frozen { account: 2 }.into());
+        System::assert_last_event(Event::Frozen { asset_id: ASSET, account: 2 }.into());
     });
 }
 
@@ -142,7 +156,7 @@ fn freeze_fails_for_non_admin() {
     new_test_ext().execute_with(|| {
         // Non-admin cannot freeze
         assert_noop!(
-            CladToken::freeze(RuntimeOrigin::signed(2), 3),
+            CladToken::freeze(RuntimeOrigin::signed(2), ASSET, 3),
             sp_runtime::DispatchError::BadOrigin
         );
     });
@@ -154,17 +168,17 @@ fn unfreeze_works() {
         System::set_block_number(1);
 
         // Admin freezes then unfreezes account 2
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), 2));
-        assert_eq!(CladToken::is_frozen(&2), true);
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_eq!(CladToken::is_frozen(ASSET, &2), true);
 
-        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), 2));
-        assert_eq!(CladToken::is_frozen(&2), false);
+        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_eq!(CladToken::is_frozen(ASSET, &2), false);
 
         // Check event emitted
-        System::assert_last_event(Event::Unfrozen { account: 2 }.into());
+        System::assert_last_event(Event::Unfrozen { asset_id: ASSET, account: 2 }.into());
 
         // Account 2 can transfer again
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 3, 10_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 10_000));
     });
 }
 
@@ -173,7 +187,7 @@ fn unfreeze_fails_for_non_admin() {
     new_test_ext().execute_with(|| {
         // Non-admin cannot unfreeze
         assert_noop!(
-            CladToken::unfreeze(RuntimeOrigin::signed(2), 3),
+            CladToken::unfreeze(RuntimeOrigin::signed(2), ASSET, 3),
             sp_runtime::DispatchError::BadOrigin
         );
     });
@@ -185,13 +199,15 @@ fn add_to_whitelist_works() {
         System::set_block_number(1);
 
         // Admin adds account 5 to whitelist
-        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), 5));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
 
         // Check account is whitelisted
-        assert_eq!(CladToken::whitelist(&5), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &5), true);
 
         // Check event emitted
-        System::assert_last_event(Event::Whitelisted { account: 5 }.into());
+        System::assert_last_event(
+            Event::Whitelisted { asset_id: ASSET, account: 5, expires_at: None }.into(),
+        );
     });
 }
 
@@ -200,7 +216,7 @@ fn add_to_whitelist_fails_for_non_admin() {
     new_test_ext().execute_with(|| {
         // Non-admin cannot whitelist
         assert_noop!(
-            CladToken::add_to_whitelist(RuntimeOrigin::signed(2), 5),
+            CladToken::add_to_whitelist(RuntimeOrigin::signed(2), ASSET, 5, None, None),
             sp_runtime::DispatchError::BadOrigin
         );
     });
@@ -212,17 +228,19 @@ fn remove_from_whitelist_works() {
         System::set_block_number(1);
 
         // Admin removes account 2 from whitelist
-        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), 2));
+        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), ASSET, 2));
 
         // Check account is not whitelisted
-        assert_eq!(CladToken::whitelist(&2), false);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), false);
 
         // Check event emitted
-        System::assert_last_event(Event::RemovedFromWhitelist { account: 2 }.into());
+        System::assert_last_event(
+            Event::RemovedFromWhitelist { asset_id: ASSET, account: 2 }.into(),
+        );
 
         // Account 2 can no longer transfer
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(2), 3, 5_000),
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 5_000),
             Error::<Test>::NotWhitelisted
         );
     });
@@ -233,7 +251,7 @@ fn remove_from_whitelist_fails_for_non_admin() {
     new_test_ext().execute_with(|| {
         // Non-admin cannot remove from whitelist
         assert_noop!(
-            CladToken::remove_from_whitelist(RuntimeOrigin::signed(2), 3),
+            CladToken::remove_from_whitelist(RuntimeOrigin::signed(2), ASSET, 3),
             sp_runtime::DispatchError::BadOrigin
         );
     });
@@ -243,17 +261,17 @@ fn remove_from_whitelist_fails_for_non_admin() {
 fn whitelisted_account_can_transfer_after_being_added() {
     new_test_ext().execute_with(|| {
         // Mint tokens to account 5 (not whitelisted yet)
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 5, 50_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 50_000));
 
         // Add accounts 5 and 6 to whitelist
-        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), 5));
-        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), 6));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 6, None, None));
 
         // Now account 5 can transfer to account 6
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(5), 6, 10_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 6, 10_000));
 
-        assert_eq!(CladToken::balance_of(&5), 40_000);
-        assert_eq!(CladToken::balance_of(&6), 10_000);
+        assert_eq!(CladToken::balance_of(ASSET, &5), 40_000);
+        assert_eq!(CladToken::balance_of(ASSET, &6), 10_000);
     });
 }
 
@@ -261,12 +279,12 @@ fn whitelisted_account_can_transfer_after_being_added() {
 fn account_can_receive_transfer_when_frozen() {
     new_test_ext().execute_with(|| {
         // Freeze account 3
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), 3));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 3));
 
         // Account 2 can still send to frozen account 3
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 3, 10_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 10_000));
 
-        assert_eq!(CladToken::balance_of(&3), 510_000);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 510_000);
     });
 }
 
@@ -274,27 +292,27 @@ fn account_can_receive_transfer_when_frozen() {
 fn multiple_transfers_work_correctly() {
     new_test_ext().execute_with(|| {
         // Multiple transfers
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 3, 100_000));
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(3), 2, 50_000));
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 3, 25_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 2, 50_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 25_000));
 
         // Final balances
-        assert_eq!(CladToken::balance_of(&2), 925_000);
-        assert_eq!(CladToken::balance_of(&3), 575_000);
-        assert_eq!(CladToken::total_supply(), 1_500_000); // Total unchanged
+        assert_eq!(CladToken::balance_of(ASSET, &2), 925_000);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 575_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_000); // Total unchanged
     });
 }
 
 #[test]
 fn minting_increases_total_supply() {
     new_test_ext().execute_with(|| {
-        let initial_supply = CladToken::total_supply();
+        let initial_supply = CladToken::total_supply(ASSET);
 
         // Mint multiple times
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 5, 100_000));
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 6, 200_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 100_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 6, 200_000));
 
-        assert_eq!(CladToken::total_supply(), initial_supply + 300_000);
+        assert_eq!(CladToken::total_supply(ASSET), initial_supply + 300_000);
     });
 }
 
@@ -314,18 +332,18 @@ fn mint_zero_amount_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let initial_supply = CladToken::total_supply();
-        let initial_balance = CladToken::balance_of(&5);
+        let initial_supply = CladToken::total_supply(ASSET);
+        let initial_balance = CladToken::balance_of(ASSET, &5);
 
         // Mint zero tokens
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 5, 0));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 0));
 
         // Supply and balance should be unchanged
-        assert_eq!(CladToken::total_supply(), initial_supply);
-        assert_eq!(CladToken::balance_of(&5), initial_balance);
+        assert_eq!(CladToken::total_supply(ASSET), initial_supply);
+        assert_eq!(CladToken::balance_of(ASSET, &5), initial_balance);
 
         // Event should still be emitted
-        System::assert_last_event(Event::Minted { to: 5, amount: 0 }.into());
+        System::assert_last_event(Event::Minted { asset_id: ASSET, to: 5, amount: 0 }.into());
     });
 }
 
@@ -337,15 +355,15 @@ fn freeze_already_frozen_account_works() {
         System::set_block_number(1);
 
         // Freeze account 2
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), 2));
-        assert_eq!(CladToken::is_frozen(&2), true);
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_eq!(CladToken::is_frozen(ASSET, &2), true);
 
         // Freeze again - should succeed
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), 2));
-        assert_eq!(CladToken::is_frozen(&2), true);
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_eq!(CladToken::is_frozen(ASSET, &2), true);
 
         // Event should be emitted for second freeze too
-        System::assert_last_event(Event::Frozen { account: 2 }.into());
+        System::assert_last_event(Event::Frozen { asset_id: ASSET, account: 2 }.into());
     });
 }
 
@@ -357,14 +375,14 @@ fn unfreeze_non_frozen_account_works() {
         System::set_block_number(1);
 
         // Account 2 is not frozen initially
-        assert_eq!(CladToken::is_frozen(&2), false);
+        assert_eq!(CladToken::is_frozen(ASSET, &2), false);
 
         // Unfreeze anyway - should succeed
-        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), 2));
-        assert_eq!(CladToken::is_frozen(&2), false);
+        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_eq!(CladToken::is_frozen(ASSET, &2), false);
 
         // Event should be emitted
-        System::assert_last_event(Event::Unfrozen { account: 2 }.into());
+        System::assert_last_event(Event::Unfrozen { asset_id: ASSET, account: 2 }.into());
     });
 }
 
@@ -376,14 +394,16 @@ fn whitelist_already_whitelisted_account_works() {
         System::set_block_number(1);
 
         // Account 2 is already whitelisted in genesis
-        assert_eq!(CladToken::whitelist(&2), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), true);
 
         // Whitelist again - should succeed
-        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), 2));
-        assert_eq!(CladToken::whitelist(&2), true);
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 2, None, None));
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), true);
 
         // Event should be emitted
-        System::assert_last_event(Event::Whitelisted { account: 2 }.into());
+        System::assert_last_event(
+            Event::Whitelisted { asset_id: ASSET, account: 2, expires_at: None }.into(),
+        );
     });
 }
 
@@ -395,14 +415,16 @@ fn remove_non_whitelisted_account_works() {
         System::set_block_number(1);
 
         // Account 5 is not whitelisted
-        assert_eq!(CladToken::whitelist(&5), false);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &5), false);
 
         // Remove anyway - should succeed
-        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), 5));
-        assert_eq!(CladToken::whitelist(&5), false);
+        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), ASSET, 5));
+        assert_eq!(CladToken::is_whitelisted(ASSET, &5), false);
 
         // Event should be emitted
-        System::assert_last_event(Event::RemovedFromWhitelist { account: 5 }.into());
+        System::assert_last_event(
+            Event::RemovedFromWhitelist { asset_id: ASSET, account: 5 }.into(),
+        );
     });
 }
 
@@ -418,18 +440,21 @@ fn transfer_zero_amount_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let initial_sender_balance = CladToken::balance_of(&2);
-        let initial_receiver_balance = CladToken::balance_of(&3);
+        let initial_sender_balance = CladToken::balance_of(ASSET, &2);
+        let initial_receiver_balance = CladToken::balance_of(ASSET, &3);
 
         // Transfer zero tokens
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 3, 0));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 0));
 
         // Balances should be unchanged
-        assert_eq!(CladToken::balance_of(&2), initial_sender_balance);
-        assert_eq!(CladToken::balance_of(&3), initial_receiver_balance);
+        assert_eq!(CladToken::balance_of(ASSET, &2), initial_sender_balance);
+        assert_eq!(CladToken::balance_of(ASSET, &3), initial_receiver_balance);
 
         // Event should be emitted
-        System::assert_last_event(Event::Transferred { from: 2, to: 3, amount: 0 }.into());
+        System::assert_last_event(
+            Event::Transferred { asset_id: ASSET, from: 2, to: 3, amount: 0, reference: None }
+                .into(),
+        );
     });
 }
 
@@ -440,16 +465,25 @@ fn self_transfer_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let initial_balance = CladToken::balance_of(&2);
+        let initial_balance = CladToken::balance_of(ASSET, &2);
 
         // Transfer to self
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 2, 100_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 2, 100_000));
 
         // Balance should be unchanged (sent and received same amount)
-        assert_eq!(CladToken::balance_of(&2), initial_balance);
+        assert_eq!(CladToken::balance_of(ASSET, &2), initial_balance);
 
         // Event should be emitted
-        System::assert_last_event(Event::Transferred { from: 2, to: 2, amount: 100_000 }.into());
+        System::assert_last_event(
+            Event::Transferred {
+                asset_id: ASSET,
+                from: 2,
+                to: 2,
+                amount: 100_000,
+                reference: None,
+            }
+            .into(),
+        );
     });
 }
 
@@ -459,11 +493,11 @@ fn self_transfer_works() {
 fn self_transfer_fails_when_frozen() {
     new_test_ext().execute_with(|| {
         // Freeze account 2
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), 2));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
 
         // Self-transfer should fail because account is frozen
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(2), 2, 100_000),
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 2, 100_000),
             Error::<Test>::AccountFrozen
         );
     });
@@ -475,14 +509,14 @@ fn transfer_exact_balance_works() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let exact_balance = CladToken::balance_of(&2);
+        let exact_balance = CladToken::balance_of(ASSET, &2);
 
         // Transfer exact balance
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 3, exact_balance));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, exact_balance));
 
         // Sender should have zero balance
-        assert_eq!(CladToken::balance_of(&2), 0);
-        assert_eq!(CladToken::balance_of(&3), 500_000 + exact_balance);
+        assert_eq!(CladToken::balance_of(ASSET, &2), 0);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 500_000 + exact_balance);
     });
 }
 
@@ -491,11 +525,11 @@ fn transfer_exact_balance_works() {
 #[test]
 fn transfer_fails_when_amount_exceeds_balance_by_one() {
     new_test_ext().execute_with(|| {
-        let balance = CladToken::balance_of(&2);
+        let balance = CladToken::balance_of(ASSET, &2);
 
         // Try to transfer balance + 1
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(2), 3, balance + 1),
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, balance + 1),
             Error::<Test>::InsufficientBalance
         );
     });
@@ -513,35 +547,35 @@ fn integration_full_token_lifecycle() {
         System::set_block_number(1);
 
         // Step 1: Mint tokens to a new account (account 10)
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 10, 500_000));
-        assert_eq!(CladToken::balance_of(&10), 500_000);
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 10, 500_000));
+        assert_eq!(CladToken::balance_of(ASSET, &10), 500_000);
 
         // Step 2: Whitelist the new account and a recipient
-        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), 10));
-        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), 11));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 10, None, None));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 11, None, None));
 
         // Step 3: Transfer from account 10 to account 11
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(10), 11, 200_000));
-        assert_eq!(CladToken::balance_of(&10), 300_000);
-        assert_eq!(CladToken::balance_of(&11), 200_000);
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(10), ASSET, 11, 200_000));
+        assert_eq!(CladToken::balance_of(ASSET, &10), 300_000);
+        assert_eq!(CladToken::balance_of(ASSET, &11), 200_000);
 
         // Step 4: Freeze account 10
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), 10));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 10));
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(10), 11, 100_000),
+            CladToken::transfer(RuntimeOrigin::signed(10), ASSET, 11, 100_000),
             Error::<Test>::AccountFrozen
         );
 
         // Step 5: Unfreeze and transfer again
-        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), 10));
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(10), 11, 100_000));
-        assert_eq!(CladToken::balance_of(&10), 200_000);
-        assert_eq!(CladToken::balance_of(&11), 300_000);
+        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), ASSET, 10));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(10), ASSET, 11, 100_000));
+        assert_eq!(CladToken::balance_of(ASSET, &10), 200_000);
+        assert_eq!(CladToken::balance_of(ASSET, &11), 300_000);
 
         // Step 6: Remove from whitelist - transfers should fail
-        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), 10));
+        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), ASSET, 10));
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(10), 11, 50_000),
+            CladToken::transfer(RuntimeOrigin::signed(10), ASSET, 11, 50_000),
             Error::<Test>::NotWhitelisted
         );
     });
@@ -555,25 +589,25 @@ fn integration_multi_party_transfers() {
         System::set_block_number(1);
 
         // Setup: Create and whitelist accounts 10, 11, 12
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 10, 1_000_000));
-        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), 10));
-        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), 11));
-        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), 12));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 10, 1_000_000));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 10, None, None));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 11, None, None));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 12, None, None));
 
         // Transfers: 10 -> 11 -> 12 -> 10 (circular)
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(10), 11, 400_000));
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(11), 12, 300_000));
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(12), 10, 100_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(10), ASSET, 11, 400_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(11), ASSET, 12, 300_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(12), ASSET, 10, 100_000));
 
         // Verify final balances
-        assert_eq!(CladToken::balance_of(&10), 700_000); // 1_000_000 - 400_000 + 100_000
-        assert_eq!(CladToken::balance_of(&11), 100_000); // 0 + 400_000 - 300_000
-        assert_eq!(CladToken::balance_of(&12), 200_000); // 0 + 300_000 - 100_000
+        assert_eq!(CladToken::balance_of(ASSET, &10), 700_000); // 1_000_000 - 400_000 + 100_000
+        assert_eq!(CladToken::balance_of(ASSET, &11), 100_000); // 0 + 400_000 - 300_000
+        assert_eq!(CladToken::balance_of(ASSET, &12), 200_000); // 0 + 300_000 - 100_000
 
         // Total supply should remain unchanged
         let initial_supply = 1_500_000; // From genesis
         let minted = 1_000_000;
-        assert_eq!(CladToken::total_supply(), initial_supply + minted);
+        assert_eq!(CladToken::total_supply(ASSET), initial_supply + minted);
     });
 }
 
@@ -587,21 +621,27 @@ fn integration_admin_operations_sequence() {
         let account = 20u64;
 
         // Whitelist -> Freeze -> Unfreeze -> Remove from whitelist
-        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), account));
-        assert_eq!(CladToken::whitelist(&account), true);
-        assert_eq!(CladToken::is_frozen(&account), false);
-
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), account));
-        assert_eq!(CladToken::whitelist(&account), true);
-        assert_eq!(CladToken::is_frozen(&account), true);
-
-        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), account));
-        assert_eq!(CladToken::whitelist(&account), true);
-        assert_eq!(CladToken::is_frozen(&account), false);
-
-        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), account));
-        assert_eq!(CladToken::whitelist(&account), false);
-        assert_eq!(CladToken::is_frozen(&account), false);
+        assert_ok!(CladToken::add_to_whitelist(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            account,
+            None,
+            None
+        ));
+        assert_eq!(CladToken::is_whitelisted(ASSET, &account), true);
+        assert_eq!(CladToken::is_frozen(ASSET, &account), false);
+
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, account));
+        assert_eq!(CladToken::is_whitelisted(ASSET, &account), true);
+        assert_eq!(CladToken::is_frozen(ASSET, &account), true);
+
+        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), ASSET, account));
+        assert_eq!(CladToken::is_whitelisted(ASSET, &account), true);
+        assert_eq!(CladToken::is_frozen(ASSET, &account), false);
+
+        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), ASSET, account));
+        assert_eq!(CladToken::is_whitelisted(ASSET, &account), false);
+        assert_eq!(CladToken::is_frozen(ASSET, &account), false);
     });
 }
 
@@ -610,23 +650,23 @@ fn integration_admin_operations_sequence() {
 fn frozen_and_whitelist_status_are_independent() {
     new_test_ext().execute_with(|| {
         // Account 2 is whitelisted but not frozen
-        assert_eq!(CladToken::whitelist(&2), true);
-        assert_eq!(CladToken::is_frozen(&2), false);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), true);
+        assert_eq!(CladToken::is_frozen(ASSET, &2), false);
 
         // Freeze without affecting whitelist
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), 2));
-        assert_eq!(CladToken::whitelist(&2), true);
-        assert_eq!(CladToken::is_frozen(&2), true);
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), true);
+        assert_eq!(CladToken::is_frozen(ASSET, &2), true);
 
         // Remove from whitelist without affecting frozen status
-        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), 2));
-        assert_eq!(CladToken::whitelist(&2), false);
-        assert_eq!(CladToken::is_frozen(&2), true);
+        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), false);
+        assert_eq!(CladToken::is_frozen(ASSET, &2), true);
 
         // Unfreeze without affecting whitelist
-        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), 2));
-        assert_eq!(CladToken::whitelist(&2), false);
-        assert_eq!(CladToken::is_frozen(&2), false);
+        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), false);
+        assert_eq!(CladToken::is_frozen(ASSET, &2), false);
     });
 }
 
@@ -636,13 +676,13 @@ fn mint_to_existing_account_adds_balance() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let initial_balance = CladToken::balance_of(&2);
+        let initial_balance = CladToken::balance_of(ASSET, &2);
 
         // Mint additional tokens to account 2
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 2, 250_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 250_000));
 
         // Balance should be added, not replaced
-        assert_eq!(CladToken::balance_of(&2), initial_balance + 250_000);
+        assert_eq!(CladToken::balance_of(ASSET, &2), initial_balance + 250_000);
     });
 }
 
@@ -653,15 +693,15 @@ fn frozen_account_can_receive_but_not_send() {
         System::set_block_number(1);
 
         // Freeze account 3
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), 3));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 3));
 
         // Account 3 can still receive
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 3, 50_000));
-        assert_eq!(CladToken::balance_of(&3), 550_000);
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 50_000));
+        assert_eq!(CladToken::balance_of(ASSET, &3), 550_000);
 
         // Account 3 cannot send
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(3), 2, 10_000),
+            CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 2, 10_000),
             Error::<Test>::AccountFrozen
         );
     });
@@ -677,23 +717,23 @@ fn all_admin_functions_reject_non_admin() {
     new_test_ext().execute_with(|| {
         // Non-admin account (2) tries all admin functions
         assert_noop!(
-            CladToken::mint(RuntimeOrigin::signed(2), 5, 1000),
+            CladToken::mint(RuntimeOrigin::signed(2), ASSET, 5, 1000),
             sp_runtime::DispatchError::BadOrigin
         );
         assert_noop!(
-            CladToken::freeze(RuntimeOrigin::signed(2), 3),
+            CladToken::freeze(RuntimeOrigin::signed(2), ASSET, 3),
             sp_runtime::DispatchError::BadOrigin
         );
         assert_noop!(
-            CladToken::unfreeze(RuntimeOrigin::signed(2), 3),
+            CladToken::unfreeze(RuntimeOrigin::signed(2), ASSET, 3),
             sp_runtime::DispatchError::BadOrigin
         );
         assert_noop!(
-            CladToken::add_to_whitelist(RuntimeOrigin::signed(2), 5),
+            CladToken::add_to_whitelist(RuntimeOrigin::signed(2), ASSET, 5, None, None),
             sp_runtime::DispatchError::BadOrigin
         );
         assert_noop!(
-            CladToken::remove_from_whitelist(RuntimeOrigin::signed(2), 3),
+            CladToken::remove_from_whitelist(RuntimeOrigin::signed(2), ASSET, 3),
             sp_runtime::DispatchError::BadOrigin
         );
     });
@@ -704,7 +744,7 @@ fn all_admin_functions_reject_non_admin() {
 fn transfer_is_user_callable() {
     new_test_ext().execute_with(|| {
         // Non-admin account (2) can call transfer
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 3, 1000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 1000));
     });
 }
 
@@ -716,9 +756,9 @@ fn transfer_is_user_callable() {
 #[test]
 fn genesis_config_sets_token_metadata() {
     new_test_ext().execute_with(|| {
-        assert_eq!(CladToken::token_name(), b"Test Token".to_vec());
-        assert_eq!(CladToken::token_symbol(), b"TST".to_vec());
-        assert_eq!(CladToken::decimals(), 6);
+        assert_eq!(CladToken::token_name(ASSET), b"Test Token".to_vec());
+        assert_eq!(CladToken::token_symbol(ASSET), b"TST".to_vec());
+        assert_eq!(CladToken::decimals(ASSET), 6);
     });
 }
 
@@ -727,7 +767,7 @@ fn genesis_config_sets_token_metadata() {
 fn genesis_config_calculates_total_supply() {
     new_test_ext().execute_with(|| {
         // Genesis has (2, 1_000_000) and (3, 500_000)
-        assert_eq!(CladToken::total_supply(), 1_500_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_000);
     });
 }
 
@@ -736,7 +776,7 @@ fn genesis_config_calculates_total_supply() {
 fn genesis_config_whitelists_admin() {
     new_test_ext().execute_with(|| {
         // Admin (account 1) should be whitelisted
-        assert_eq!(CladToken::whitelist(&1), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &1), true);
     });
 }
 
@@ -745,9 +785,9 @@ fn genesis_config_whitelists_admin() {
 fn non_genesis_accounts_have_default_values() {
     new_test_ext().execute_with(|| {
         // Account 99 was never configured
-        assert_eq!(CladToken::balance_of(&99), 0);
-        assert_eq!(CladToken::whitelist(&99), false);
-        assert_eq!(CladToken::is_frozen(&99), false);
+        assert_eq!(CladToken::balance_of(ASSET, &99), 0);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &99), false);
+        assert_eq!(CladToken::is_frozen(ASSET, &99), false);
     });
 }
 
@@ -760,14 +800,14 @@ fn non_genesis_accounts_have_default_values() {
 fn storage_getters_work_correctly() {
     new_test_ext().execute_with(|| {
         // Test all getter functions
-        assert_eq!(CladToken::total_supply(), 1_500_000);
-        assert_eq!(CladToken::balance_of(&2), 1_000_000);
-        assert_eq!(CladToken::balance_of(&3), 500_000);
-        assert_eq!(CladToken::is_frozen(&2), false);
-        assert_eq!(CladToken::whitelist(&2), true);
-        assert_eq!(CladToken::token_name(), b"Test Token".to_vec());
-        assert_eq!(CladToken::token_symbol(), b"TST".to_vec());
-        assert_eq!(CladToken::decimals(), 6);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_000);
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_000);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 500_000);
+        assert_eq!(CladToken::is_frozen(ASSET, &2), false);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), true);
+        assert_eq!(CladToken::token_name(ASSET), b"Test Token".to_vec());
+        assert_eq!(CladToken::token_symbol(ASSET), b"TST".to_vec());
+        assert_eq!(CladToken::decimals(ASSET), 6);
     });
 }
 
@@ -775,9 +815,9 @@ fn storage_getters_work_correctly() {
 #[test]
 fn balance_updates_reflect_immediately() {
     new_test_ext().execute_with(|| {
-        let initial = CladToken::balance_of(&2);
-        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), 3, 100));
-        assert_eq!(CladToken::balance_of(&2), initial - 100);
+        let initial = CladToken::balance_of(ASSET, &2);
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100));
+        assert_eq!(CladToken::balance_of(ASSET, &2), initial - 100);
     });
 }
 
@@ -792,14 +832,14 @@ fn mint_fails_on_total_supply_overflow() {
     new_test_ext().execute_with(|| {
         // First mint a large amount close to u128::MAX
         // Account 5 starts with 0 balance
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 5, u128::MAX - 2_000_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, u128::MAX - 2_000_000));
 
         // Now try to mint more than remaining capacity
         // Total supply is now: 1_500_000 (genesis) + (u128::MAX - 2_000_000)
         // Which is u128::MAX - 500_000
         // Trying to mint 1_000_000 should overflow
         assert_noop!(
-            CladToken::mint(RuntimeOrigin::signed(1), 6, 1_000_000),
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 6, 1_000_000),
             Error::<Test>::Overflow
         );
     });
@@ -811,10 +851,13 @@ fn mint_fails_on_total_supply_overflow() {
 fn mint_fails_on_balance_overflow() {
     new_test_ext().execute_with(|| {
         // Mint max to account 5
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 5, u128::MAX - 1_500_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, u128::MAX - 1_500_000));
 
         // Try to mint 1 more to the same account - balance would overflow
-        assert_noop!(CladToken::mint(RuntimeOrigin::signed(1), 5, 1), Error::<Test>::Overflow);
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 1),
+            Error::<Test>::Overflow
+        );
     });
 }
 
@@ -831,12 +874,12 @@ fn transfer_fails_on_receiver_balance_overflow() {
     new_test_ext().execute_with(|| {
         // Directly set account 10's balance to near u128::MAX to simulate edge case
         // This bypasses mint's overflow check - simulating a theoretical scenario
-        crate::Balances::<Test>::insert(10, u128::MAX - 100);
-        crate::Whitelist::<Test>::insert(10, true);
+        crate::Balances::<Test>::insert(ASSET, 10, u128::MAX - 100);
+        crate::Whitelist::<Test>::insert(ASSET, 10, WhitelistEntry::permanent());
 
         // Account 2 tries to transfer to account 10 - would overflow account 10's balance
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(2), 10, 1000),
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 10, 1000),
             Error::<Test>::Overflow
         );
     });
@@ -852,16 +895,16 @@ fn multiple_sequential_mints_accumulate_correctly() {
         let num_mints = 10;
 
         for i in 0..num_mints {
-            assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), account, mint_amount));
-            assert_eq!(CladToken::balance_of(&account), mint_amount * (i + 1));
+            assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, account, mint_amount));
+            assert_eq!(CladToken::balance_of(ASSET, &account), mint_amount * (i + 1));
         }
 
         // Final balance check
-        assert_eq!(CladToken::balance_of(&account), mint_amount * num_mints);
+        assert_eq!(CladToken::balance_of(ASSET, &account), mint_amount * num_mints);
 
         // Total supply should include all mints
         let initial_supply = 1_500_000u128; // From genesis
-        assert_eq!(CladToken::total_supply(), initial_supply + (mint_amount * num_mints));
+        assert_eq!(CladToken::total_supply(ASSET), initial_supply + (mint_amount * num_mints));
     });
 }
 
@@ -876,17 +919,17 @@ fn transfer_fails_when_only_receiver_not_whitelisted() {
     new_test_ext().execute_with(|| {
         // Account 2 is whitelisted (from genesis)
         // Account 99 is NOT whitelisted
-        assert_eq!(CladToken::whitelist(&2), true);
-        assert_eq!(CladToken::whitelist(&99), false);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &99), false);
 
         // Transfer should fail due to receiver not being whitelisted
         assert_noop!(
-            CladToken::transfer(RuntimeOrigin::signed(2), 99, 1000),
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 99, 1000),
             Error::<Test>::NotWhitelisted
         );
 
         // Verify sender's balance is unchanged
-        assert_eq!(CladToken::balance_of(&2), 1_000_000);
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_000);
     });
 }
 
@@ -907,7 +950,7 @@ fn set_admin_works() {
         assert_eq!(CladToken::admin(), Some(50));
 
         // Verify new admin was auto-whitelisted
-        assert_eq!(CladToken::whitelist(&50), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &50), true);
 
         // Check AdminChanged event was emitted (old_admin is 1 from genesis)
         System::assert_has_event(
@@ -915,7 +958,9 @@ fn set_admin_works() {
         );
 
         // Check Whitelisted event was emitted for new admin
-        System::assert_last_event(Event::Whitelisted { account: 50 }.into());
+        System::assert_last_event(
+            Event::Whitelisted { asset_id: ASSET, account: 50, expires_at: None }.into(),
+        );
     });
 }
 
@@ -964,13 +1009,13 @@ fn set_admin_auto_whitelists_new_admin() {
         System::set_block_number(1);
 
         // Account 99 is not whitelisted initially
-        assert_eq!(CladToken::whitelist(&99), false);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &99), false);
 
         // Set account 99 as admin
         assert_ok!(CladToken::set_admin(RuntimeOrigin::signed(1), 99));
 
         // Account 99 should now be whitelisted
-        assert_eq!(CladToken::whitelist(&99), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &99), true);
     });
 }
 
@@ -982,7 +1027,7 @@ fn set_admin_to_whitelisted_account_works() {
         System::set_block_number(1);
 
         // Account 2 is already whitelisted from genesis
-        assert_eq!(CladToken::whitelist(&2), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), true);
 
         // Set account 2 as admin
         assert_ok!(CladToken::set_admin(RuntimeOrigin::signed(1), 2));
@@ -991,7 +1036,7 @@ fn set_admin_to_whitelisted_account_works() {
         assert_eq!(CladToken::admin(), Some(2));
 
         // Account 2 should still be whitelisted
-        assert_eq!(CladToken::whitelist(&2), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), true);
     });
 }
 
@@ -1030,22 +1075,26 @@ fn integration_admin_rotation_workflow() {
         // Step 2: Genesis admin (account 1) sets new admin (multi-sig placeholder: 100)
         assert_ok!(CladToken::set_admin(RuntimeOrigin::signed(1), 100));
         assert_eq!(CladToken::admin(), Some(100));
-        assert_eq!(CladToken::whitelist(&100), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &100), true);
 
-        // Step 3: Verify new admin can perform admin operations via root
-        // (In mock, admin 1 can still call admin functions via EnsureRoot)
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), 200, 1_000_000));
-        assert_eq!(CladToken::balance_of(&200), 1_000_000);
+        // Step 3: Roles stay where they were granted - account 1 still mints,
+        // the new admin needs a role admin to grant it the minter role first
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 200, 1_000_000));
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(100), ASSET, 200, 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_eq!(CladToken::balance_of(ASSET, &200), 1_000_000);
 
         // Step 4: Rotate to new admin (simulating committee change: 100 -> 101)
         System::reset_events();
         System::set_block_number(2);
         assert_ok!(CladToken::set_admin(RuntimeOrigin::signed(1), 101));
         assert_eq!(CladToken::admin(), Some(101));
-        assert_eq!(CladToken::whitelist(&101), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &101), true);
 
         // Old admin (100) remains whitelisted (can still hold tokens)
-        assert_eq!(CladToken::whitelist(&100), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &100), true);
 
         // Step 5: Verify events for audit trail
         System::assert_has_event(
@@ -1062,3 +1111,3738 @@ fn admin_storage_set_from_genesis() {
         assert_eq!(CladToken::admin(), Some(AdminAccount::get()));
     });
 }
+
+// ============================================================================
+// Instrument Registry Tests
+// ============================================================================
+
+fn bounded<S: frame_support::traits::Get<u32>>(bytes: &[u8]) -> frame_support::BoundedVec<u8, S> {
+    bytes.to_vec().try_into().unwrap()
+}
+
+/// Creates a second instrument ("Test Bond 2035") and returns its id.
+fn create_second_instrument() -> AssetId {
+    let asset_id = CladToken::next_asset_id();
+    assert_ok!(CladToken::create_instrument(
+        RuntimeOrigin::signed(1),
+        bounded(b"Test Bond 2035"),
+        bounded(b"TB35"),
+        2,
+        None,
+    ));
+    asset_id
+}
+
+/// Tests that genesis registers exactly one instrument.
+#[test]
+fn genesis_registers_single_instrument() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(CladToken::next_asset_id(), 1);
+        assert_eq!(ASSET, 0);
+    });
+}
+
+/// Tests that create_instrument assigns a sequential id and stores metadata.
+#[test]
+fn create_instrument_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let asset_id = create_second_instrument();
+        assert_eq!(asset_id, 1);
+        assert_eq!(CladToken::next_asset_id(), 2);
+
+        assert_eq!(CladToken::token_name(asset_id), b"Test Bond 2035".to_vec());
+        assert_eq!(CladToken::token_symbol(asset_id), b"TB35".to_vec());
+        assert_eq!(CladToken::decimals(asset_id), 2);
+        assert_eq!(CladToken::total_supply(asset_id), 0);
+
+        // Admin is auto-whitelisted on the new instrument
+        assert_eq!(CladToken::is_whitelisted(asset_id, &1), true);
+
+        System::assert_has_event(
+            Event::InstrumentCreated {
+                asset_id,
+                symbol: bounded(b"TB35"),
+                decimals: 2,
+                max_supply: None,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::Whitelisted { asset_id, account: 1, expires_at: None }.into(),
+        );
+
+        // Genesis instrument is untouched
+        assert_eq!(CladToken::token_symbol(ASSET), b"TST".to_vec());
+    });
+}
+
+/// Tests that create_instrument fails when called by non-admin.
+#[test]
+fn create_instrument_fails_for_non_admin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::create_instrument(
+                RuntimeOrigin::signed(2),
+                bounded(b"Rogue"),
+                bounded(b"RGE"),
+                6,
+                None
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+/// Tests that create_instrument respects MaxInstruments (4 in the mock).
+#[test]
+fn create_instrument_fails_when_limit_reached() {
+    new_test_ext().execute_with(|| {
+        // Genesis instrument + 3 more reaches the limit
+        for _ in 0..3 {
+            create_second_instrument();
+        }
+        assert_eq!(CladToken::next_asset_id(), 4);
+
+        assert_noop!(
+            CladToken::create_instrument(
+                RuntimeOrigin::signed(1),
+                bounded(b"One Too Many"),
+                bounded(b"OTM"),
+                6,
+                None
+            ),
+            Error::<Test>::TooManyInstruments
+        );
+    });
+}
+
+/// Tests that every asset-scoped call rejects an unregistered asset id.
+#[test]
+fn calls_fail_for_unknown_instrument() {
+    new_test_ext().execute_with(|| {
+        let unknown: AssetId = 1;
+
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), unknown, 2, 100),
+            Error::<Test>::UnknownInstrument
+        );
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), unknown, 3, 100),
+            Error::<Test>::UnknownInstrument
+        );
+        assert_noop!(
+            CladToken::freeze(RuntimeOrigin::signed(1), unknown, 2),
+            Error::<Test>::UnknownInstrument
+        );
+        assert_noop!(
+            CladToken::unfreeze(RuntimeOrigin::signed(1), unknown, 2),
+            Error::<Test>::UnknownInstrument
+        );
+        assert_noop!(
+            CladToken::add_to_whitelist(RuntimeOrigin::signed(1), unknown, 2, None, None),
+            Error::<Test>::UnknownInstrument
+        );
+        assert_noop!(
+            CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), unknown, 2),
+            Error::<Test>::UnknownInstrument
+        );
+    });
+}
+
+/// Tests that balances, supply, whitelist and freeze state are isolated per instrument.
+#[test]
+fn instruments_are_isolated() {
+    new_test_ext().execute_with(|| {
+        let bond = create_second_instrument();
+
+        // Account 2 is whitelisted on the genesis asset only
+        assert_eq!(CladToken::is_whitelisted(ASSET, &2), true);
+        assert_eq!(CladToken::is_whitelisted(bond, &2), false);
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), bond, 3, 0),
+            Error::<Test>::NotWhitelisted
+        );
+
+        // Whitelist and mint on the bond
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), bond, 2, None, None));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), bond, 3, None, None));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), bond, 2, 700));
+        assert_eq!(CladToken::balance_of(bond, &2), 700);
+        assert_eq!(CladToken::total_supply(bond), 700);
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_000);
+
+        // Freezing on the genesis asset does not block the bond
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100),
+            Error::<Test>::AccountFrozen
+        );
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), bond, 3, 200));
+        assert_eq!(CladToken::balance_of(bond, &2), 500);
+        assert_eq!(CladToken::balance_of(bond, &3), 200);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 500_000);
+    });
+}
+
+/// Tests that set_admin whitelists the new admin on every instrument.
+#[test]
+fn set_admin_whitelists_on_all_instruments() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let bond = create_second_instrument();
+
+        assert_ok!(CladToken::set_admin(RuntimeOrigin::signed(1), 50));
+
+        assert_eq!(CladToken::is_whitelisted(ASSET, &50), true);
+        assert_eq!(CladToken::is_whitelisted(bond, &50), true);
+        System::assert_has_event(
+            Event::Whitelisted { asset_id: ASSET, account: 50, expires_at: None }.into(),
+        );
+        System::assert_last_event(
+            Event::Whitelisted { asset_id: bond, account: 50, expires_at: None }.into(),
+        );
+    });
+}
+
+// ============================================================================
+// Burn and Redeem Tests
+// ============================================================================
+
+/// Tests that admin burn lowers balance and total supply.
+#[test]
+fn burn_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CladToken::burn(RuntimeOrigin::signed(1), ASSET, 2, 400_000));
+
+        assert_eq!(CladToken::balance_of(ASSET, &2), 600_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_100_000);
+        System::assert_last_event(
+            Event::Burned { asset_id: ASSET, from: 2, amount: 400_000 }.into(),
+        );
+    });
+}
+
+/// Tests that burn fails when called by non-admin.
+#[test]
+fn burn_fails_for_non_admin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::burn(RuntimeOrigin::signed(2), ASSET, 2, 100),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+/// Tests that burn respects balance, whitelist and freeze checks.
+#[test]
+fn burn_respects_compliance_checks() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::burn(RuntimeOrigin::signed(1), ASSET, 3, 500_001),
+            Error::<Test>::InsufficientBalance
+        );
+
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 3));
+        assert_noop!(
+            CladToken::burn(RuntimeOrigin::signed(1), ASSET, 3, 100),
+            Error::<Test>::AccountFrozen
+        );
+
+        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_noop!(
+            CladToken::burn(RuntimeOrigin::signed(1), ASSET, 2, 100),
+            Error::<Test>::NotWhitelisted
+        );
+
+        assert_noop!(
+            CladToken::burn(RuntimeOrigin::signed(1), 1, 2, 100),
+            Error::<Test>::UnknownInstrument
+        );
+    });
+}
+
+/// Tests that a holder can redeem their own tokens.
+#[test]
+fn redeem_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CladToken::redeem(RuntimeOrigin::signed(3), ASSET, 500_000));
+
+        assert_eq!(CladToken::balance_of(ASSET, &3), 0);
+        assert_eq!(CladToken::total_supply(ASSET), 1_000_000);
+        System::assert_last_event(
+            Event::Redeemed { asset_id: ASSET, who: 3, amount: 500_000 }.into(),
+        );
+    });
+}
+
+/// Tests that redeem is blocked for frozen and non-whitelisted holders.
+#[test]
+fn redeem_respects_compliance_checks() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::redeem(RuntimeOrigin::signed(2), ASSET, 1_000_001),
+            Error::<Test>::InsufficientBalance
+        );
+
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_noop!(
+            CladToken::redeem(RuntimeOrigin::signed(2), ASSET, 100),
+            Error::<Test>::AccountFrozen
+        );
+
+        // Account 10 holds tokens but is not whitelisted
+        crate::Balances::<Test>::insert(ASSET, 10, 100);
+        assert_noop!(
+            CladToken::redeem(RuntimeOrigin::signed(10), ASSET, 100),
+            Error::<Test>::NotWhitelisted
+        );
+    });
+}
+
+/// Tests that mint followed by burn/redeem keeps supply equal to the sum of balances.
+#[test]
+fn mint_burn_redeem_keeps_supply_invariant() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 250_000));
+        assert_ok!(CladToken::burn(RuntimeOrigin::signed(1), ASSET, 2, 50_000));
+        assert_ok!(CladToken::redeem(RuntimeOrigin::signed(3), ASSET, 100_000));
+
+        let sum: u128 = crate::Balances::<Test>::iter_prefix_values(ASSET).sum();
+        assert_eq!(CladToken::total_supply(ASSET), sum);
+        assert_eq!(sum, 1_600_000);
+    });
+}
+
+// ============================================================================
+// Forced Transfer Tests
+// ============================================================================
+
+fn court_order() -> ForcedTransferReason {
+    ForcedTransferReason {
+        code: ForcedTransferCode::CourtOrder,
+        reference: b"ALM-2025-CV-0142".to_vec().try_into().unwrap(),
+    }
+}
+
+/// Tests that admin can force a transfer and the reason is recorded.
+#[test]
+fn forced_transfer_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CladToken::forced_transfer(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            2,
+            3,
+            250_000,
+            court_order()
+        ));
+
+        assert_eq!(CladToken::balance_of(ASSET, &2), 750_000);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 750_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_000);
+        System::assert_last_event(
+            Event::ForcedTransfer {
+                asset_id: ASSET,
+                from: 2,
+                to: 3,
+                amount: 250_000,
+                reason: court_order(),
+            }
+            .into(),
+        );
+    });
+}
+
+/// Tests that forced transfer moves tokens out of frozen, de-whitelisted accounts.
+#[test]
+fn forced_transfer_ignores_sender_restrictions() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), ASSET, 2));
+
+        assert_ok!(CladToken::forced_transfer(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            2,
+            3,
+            1_000_000,
+            ForcedTransferReason {
+                code: ForcedTransferCode::Inheritance,
+                reference: b"PROBATE-77".to_vec().try_into().unwrap(),
+            }
+        ));
+
+        assert_eq!(CladToken::balance_of(ASSET, &2), 0);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 1_500_000);
+        // Sender stays frozen
+        assert_eq!(CladToken::is_frozen(ASSET, &2), true);
+    });
+}
+
+/// Tests that forced transfer still requires a whitelisted receiver and enough balance.
+#[test]
+fn forced_transfer_checks_receiver_and_balance() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::forced_transfer(RuntimeOrigin::signed(1), ASSET, 2, 99, 100, court_order()),
+            Error::<Test>::NotWhitelisted
+        );
+        assert_noop!(
+            CladToken::forced_transfer(
+                RuntimeOrigin::signed(1),
+                ASSET,
+                3,
+                2,
+                500_001,
+                court_order()
+            ),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_noop!(
+            CladToken::forced_transfer(RuntimeOrigin::signed(1), 1, 2, 3, 100, court_order()),
+            Error::<Test>::UnknownInstrument
+        );
+    });
+}
+
+/// Tests that forced transfer applies the receiver's country, holder-count and
+/// holding-limit checks.
+#[test]
+fn forced_transfer_respects_receiver_limits() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, Some(US)));
+        assert_ok!(CladToken::set_blocked_countries(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            countries(&[US])
+        ));
+        assert_noop!(
+            CladToken::forced_transfer(RuntimeOrigin::signed(1), ASSET, 2, 5, 100, court_order()),
+            Error::<Test>::CountryBlocked
+        );
+        assert_ok!(CladToken::set_blocked_countries(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            countries(&[])
+        ));
+
+        // Accounts 2 and 3 fill both slots
+        assert_ok!(CladToken::set_max_holders(RuntimeOrigin::signed(1), ASSET, Some(2)));
+        assert_noop!(
+            CladToken::forced_transfer(RuntimeOrigin::signed(1), ASSET, 2, 5, 100, court_order()),
+            Error::<Test>::TooManyHolders
+        );
+        assert_ok!(CladToken::set_max_holders(RuntimeOrigin::signed(1), ASSET, None));
+
+        assert_ok!(CladToken::set_max_balance_per_holder(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            Some(HoldingLimit::Absolute(1_000))
+        ));
+        assert_noop!(
+            CladToken::forced_transfer(RuntimeOrigin::signed(1), ASSET, 2, 5, 1_001, court_order()),
+            Error::<Test>::HoldingLimitExceeded
+        );
+        assert_ok!(CladToken::forced_transfer(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            2,
+            5,
+            1_000,
+            court_order()
+        ));
+        assert_eq!(CladToken::balance_of(ASSET, &5), 1_000);
+    });
+}
+
+/// Tests that forced transfer fails when called by non-admin.
+#[test]
+fn forced_transfer_fails_for_non_admin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::forced_transfer(RuntimeOrigin::signed(3), ASSET, 2, 3, 100, court_order()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+// ============================================================================
+// Partial Freeze Tests
+// ============================================================================
+
+/// Tests that freeze_partial locks only the requested amount.
+#[test]
+fn freeze_partial_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 2, 600_000));
+        assert_eq!(CladToken::frozen_amount(ASSET, &2), 600_000);
+        assert_eq!(CladToken::free_balance(ASSET, &2), 400_000);
+        System::assert_last_event(
+            Event::TokensFrozen { asset_id: ASSET, account: 2, amount: 600_000 }.into(),
+        );
+
+        // Freezes accumulate
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 2, 100_000));
+        assert_eq!(CladToken::frozen_amount(ASSET, &2), 700_000);
+    });
+}
+
+/// Tests that freeze_partial cannot lock more than the free balance.
+#[test]
+fn freeze_partial_fails_above_free_balance() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 3, 500_001),
+            Error::<Test>::InsufficientBalance
+        );
+
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 3, 400_000));
+        assert_noop!(
+            CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 3, 100_001),
+            Error::<Test>::InsufficientUnfrozenBalance
+        );
+    });
+}
+
+/// Tests that transfer may only spend the non-frozen balance.
+#[test]
+fn transfer_respects_partial_freeze() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 2, 600_000));
+
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 400_001),
+            Error::<Test>::InsufficientUnfrozenBalance
+        );
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 400_000));
+        assert_eq!(CladToken::balance_of(ASSET, &2), 600_000);
+        assert_eq!(CladToken::free_balance(ASSET, &2), 0);
+
+        // Receiving is unaffected by a partial freeze
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 2, 50_000));
+        assert_eq!(CladToken::free_balance(ASSET, &2), 50_000);
+    });
+}
+
+/// Tests that redeem and burn may only spend the non-frozen balance.
+#[test]
+fn burn_and_redeem_respect_partial_freeze() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 3, 300_000));
+
+        assert_noop!(
+            CladToken::redeem(RuntimeOrigin::signed(3), ASSET, 200_001),
+            Error::<Test>::InsufficientUnfrozenBalance
+        );
+        assert_noop!(
+            CladToken::burn(RuntimeOrigin::signed(1), ASSET, 3, 200_001),
+            Error::<Test>::InsufficientUnfrozenBalance
+        );
+        assert_ok!(CladToken::redeem(RuntimeOrigin::signed(3), ASSET, 200_000));
+    });
+}
+
+/// Tests that unfreeze_partial releases tokens and removes empty entries.
+#[test]
+fn unfreeze_partial_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 2, 600_000));
+
+        assert_noop!(
+            CladToken::unfreeze_partial(RuntimeOrigin::signed(1), ASSET, 2, 600_001),
+            Error::<Test>::InsufficientFrozenBalance
+        );
+
+        assert_ok!(CladToken::unfreeze_partial(RuntimeOrigin::signed(1), ASSET, 2, 200_000));
+        assert_eq!(CladToken::frozen_amount(ASSET, &2), 400_000);
+        System::assert_last_event(
+            Event::TokensUnfrozen { asset_id: ASSET, account: 2, amount: 200_000 }.into(),
+        );
+
+        assert_ok!(CladToken::unfreeze_partial(RuntimeOrigin::signed(1), ASSET, 2, 400_000));
+        assert!(!crate::FrozenAmount::<Test>::contains_key(ASSET, 2));
+    });
+}
+
+/// Tests that partial freeze calls are admin-only.
+#[test]
+fn partial_freeze_fails_for_non_admin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::freeze_partial(RuntimeOrigin::signed(2), ASSET, 2, 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::unfreeze_partial(RuntimeOrigin::signed(2), ASSET, 2, 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+/// Tests that forced transfer can move frozen tokens and reduces the frozen amount.
+#[test]
+fn forced_transfer_reduces_frozen_amount() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 3, 400_000));
+
+        // Moves 100_000 free + 300_000 frozen tokens
+        assert_ok!(CladToken::forced_transfer(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            3,
+            2,
+            400_000,
+            court_order()
+        ));
+
+        assert_eq!(CladToken::balance_of(ASSET, &3), 100_000);
+        assert_eq!(CladToken::frozen_amount(ASSET, &3), 100_000);
+        System::assert_has_event(
+            Event::TokensUnfrozen { asset_id: ASSET, account: 3, amount: 300_000 }.into(),
+        );
+    });
+}
+
+// ============================================================================
+// Pause Tests
+// ============================================================================
+
+/// Tests that pausing blocks transfers and redemptions for every holder.
+#[test]
+fn pause_blocks_transfer_and_redeem() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::pause(RuntimeOrigin::signed(1), ASSET));
+        assert!(CladToken::is_paused(ASSET));
+        System::assert_last_event(Event::Paused { asset_id: ASSET }.into());
+
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100),
+            Error::<Test>::TokenPaused
+        );
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 2, 100),
+            Error::<Test>::TokenPaused
+        );
+        assert_noop!(
+            CladToken::redeem(RuntimeOrigin::signed(2), ASSET, 100),
+            Error::<Test>::TokenPaused
+        );
+    });
+}
+
+/// Tests that unpausing restores transfers.
+#[test]
+fn unpause_restores_transfers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::pause(RuntimeOrigin::signed(1), ASSET));
+        assert_ok!(CladToken::unpause(RuntimeOrigin::signed(1), ASSET));
+        assert!(!CladToken::is_paused(ASSET));
+        assert!(!crate::Paused::<Test>::contains_key(ASSET));
+        System::assert_last_event(Event::Unpaused { asset_id: ASSET }.into());
+
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100));
+        assert_eq!(CladToken::balance_of(ASSET, &3), 500_100);
+    });
+}
+
+/// Tests that minting is blocked while paused when `PauseBlocksMinting` is set.
+#[test]
+fn pause_blocks_mint_when_configured() {
+    new_test_ext().execute_with(|| {
+        PauseBlocksMinting::set(true);
+        assert_ok!(CladToken::pause(RuntimeOrigin::signed(1), ASSET));
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 100),
+            Error::<Test>::TokenPaused
+        );
+    });
+}
+
+/// Tests that minting stays available while paused when `PauseBlocksMinting` is unset.
+#[test]
+fn pause_allows_mint_when_not_configured() {
+    new_test_ext().execute_with(|| {
+        PauseBlocksMinting::set(false);
+        assert_ok!(CladToken::pause(RuntimeOrigin::signed(1), ASSET));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 100));
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_100);
+    });
+}
+
+/// Tests that admin remediation keeps working while the token is paused.
+#[test]
+fn pause_allows_admin_remediation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::pause(RuntimeOrigin::signed(1), ASSET));
+        assert_ok!(CladToken::forced_transfer(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            2,
+            3,
+            100,
+            court_order()
+        ));
+        assert_ok!(CladToken::burn(RuntimeOrigin::signed(1), ASSET, 3, 100));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
+    });
+}
+
+/// Tests that pausing one instrument leaves the others unaffected.
+#[test]
+fn pause_is_per_instrument() {
+    new_test_ext().execute_with(|| {
+        let other = create_second_instrument();
+        assert_ok!(CladToken::pause(RuntimeOrigin::signed(1), ASSET));
+        assert!(!CladToken::is_paused(other));
+    });
+}
+
+/// Tests that pause and unpause are admin-only and require a known instrument.
+#[test]
+fn pause_fails_for_non_admin_or_unknown_instrument() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::pause(RuntimeOrigin::signed(2), ASSET),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::unpause(RuntimeOrigin::signed(2), ASSET),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::pause(RuntimeOrigin::signed(1), 99),
+            Error::<Test>::UnknownInstrument
+        );
+    });
+}
+
+// ============================================================================
+// Compliance Module Tests
+// ============================================================================
+
+/// Tests that a compliance module can reject a transfer that passes built-in checks.
+#[test]
+fn compliance_can_reject_transfer() {
+    new_test_ext().execute_with(|| {
+        TransferLimit::set(1_000);
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 1_001),
+            sp_runtime::DispatchError::Other("TransferLimitExceeded")
+        );
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 1_000));
+    });
+}
+
+/// Tests that built-in checks run before the compliance module.
+#[test]
+fn compliance_runs_after_builtin_checks() {
+    new_test_ext().execute_with(|| {
+        TransferLimit::set(1_000);
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 4, 1_001),
+            Error::<Test>::NotWhitelisted
+        );
+    });
+}
+
+/// Tests that every module in the tuple is notified of mint, transfer and burn.
+#[test]
+fn compliance_hooks_are_notified() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 100));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 50));
+        assert_ok!(CladToken::redeem(RuntimeOrigin::signed(3), ASSET, 20));
+        assert_ok!(CladToken::burn(RuntimeOrigin::signed(1), ASSET, 2, 10));
+        assert_ok!(CladToken::forced_transfer(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            3,
+            2,
+            5,
+            court_order()
+        ));
+
+        assert_eq!(
+            ComplianceCalls::get(),
+            vec![
+                ComplianceCall::Created { asset_id: ASSET, to: 2, amount: 100 },
+                ComplianceCall::Transferred { asset_id: ASSET, from: 2, to: 3, amount: 50 },
+                ComplianceCall::Destroyed { asset_id: ASSET, from: 3, amount: 20 },
+                ComplianceCall::Destroyed { asset_id: ASSET, from: 2, amount: 10 },
+                ComplianceCall::Transferred { asset_id: ASSET, from: 3, to: 2, amount: 5 },
+            ]
+        );
+    });
+}
+
+/// Tests that forced transfers bypass `can_transfer`.
+#[test]
+fn forced_transfer_bypasses_compliance_check() {
+    new_test_ext().execute_with(|| {
+        TransferLimit::set(0);
+        assert_ok!(CladToken::forced_transfer(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            2,
+            3,
+            1_000,
+            court_order()
+        ));
+    });
+}
+
+/// Tests that a rejected transfer does not notify the compliance modules.
+#[test]
+fn rejected_transfer_is_not_recorded() {
+    new_test_ext().execute_with(|| {
+        TransferLimit::set(0);
+        assert!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 1).is_err());
+        assert!(ComplianceCalls::get().is_empty());
+    });
+}
+
+// ============================================================================
+// Identity Registry Tests
+// ============================================================================
+
+const ISSUER: u64 = 10;
+const KYC: ClaimTopic = 1;
+const ACCREDITED: ClaimTopic = 2;
+
+fn topics(topics: &[ClaimTopic]) -> ClaimTopicsOf<Test> {
+    topics.to_vec().try_into().unwrap()
+}
+
+/// Trust [`ISSUER`] for KYC and accreditation, and require KYC on [`ASSET`].
+fn setup_identity() {
+    assert_ok!(CladToken::add_trusted_issuer(
+        RuntimeOrigin::signed(1),
+        ISSUER,
+        topics(&[KYC, ACCREDITED])
+    ));
+    assert_ok!(CladToken::set_required_claim_topics(
+        RuntimeOrigin::signed(1),
+        ASSET,
+        topics(&[KYC])
+    ));
+}
+
+/// Tests that the admin can register and remove trusted issuers.
+#[test]
+fn trusted_issuer_lifecycle_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::add_trusted_issuer(RuntimeOrigin::signed(1), ISSUER, topics(&[KYC])));
+        assert_eq!(CladToken::trusted_issuer_topics(ISSUER), topics(&[KYC]));
+        System::assert_last_event(
+            Event::TrustedIssuerAdded { issuer: ISSUER, topics: topics(&[KYC]) }.into(),
+        );
+
+        assert_ok!(CladToken::remove_trusted_issuer(RuntimeOrigin::signed(1), ISSUER));
+        assert!(CladToken::trusted_issuer_topics(ISSUER).is_empty());
+        System::assert_last_event(Event::TrustedIssuerRemoved { issuer: ISSUER }.into());
+
+        assert_noop!(
+            CladToken::remove_trusted_issuer(RuntimeOrigin::signed(1), ISSUER),
+            Error::<Test>::NotTrustedIssuer
+        );
+    });
+}
+
+/// Tests that identity registry administration is admin-only.
+#[test]
+fn identity_admin_calls_fail_for_non_admin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::add_trusted_issuer(RuntimeOrigin::signed(2), ISSUER, topics(&[KYC])),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::set_required_claim_topics(RuntimeOrigin::signed(2), ASSET, topics(&[KYC])),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::set_required_claim_topics(RuntimeOrigin::signed(1), 99, topics(&[KYC])),
+            Error::<Test>::UnknownInstrument
+        );
+    });
+}
+
+/// Tests that only issuers trusted for the topic can attest claims.
+#[test]
+fn add_claim_requires_trusted_issuer_for_topic() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::add_trusted_issuer(RuntimeOrigin::signed(1), ISSUER, topics(&[KYC])));
+
+        assert_noop!(
+            CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 2, ACCREDITED, 100),
+            Error::<Test>::NotTrustedIssuer
+        );
+        assert_noop!(
+            CladToken::add_claim(RuntimeOrigin::signed(11), 2, KYC, 100),
+            Error::<Test>::NotTrustedIssuer
+        );
+
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 2, KYC, 100));
+        assert_eq!(CladToken::claim(2, KYC), Some(Claim { issuer: ISSUER, expires_at: 100 }));
+        System::assert_last_event(
+            Event::ClaimAdded { subject: 2, topic: KYC, issuer: ISSUER, expires_at: 100 }.into(),
+        );
+    });
+}
+
+/// Tests that a claim must expire in the future.
+#[test]
+fn add_claim_rejects_past_expiry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(10);
+        setup_identity();
+        assert_noop!(
+            CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 2, KYC, 10),
+            Error::<Test>::InvalidClaimExpiry
+        );
+    });
+}
+
+/// Tests that transfers require the claims the instrument asks for.
+#[test]
+fn transfer_requires_claims_for_required_topics() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_identity();
+        assert!(!CladToken::is_verified(ASSET, &2));
+
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100),
+            Error::<Test>::MissingClaim
+        );
+
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 2, KYC, 100));
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100),
+            Error::<Test>::MissingClaim
+        );
+
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 3, KYC, 100));
+        assert!(CladToken::is_verified(ASSET, &2));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100));
+    });
+}
+
+/// Tests that the whitelist is still required on top of claims.
+#[test]
+fn claims_do_not_replace_whitelist_registration() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_identity();
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 2, KYC, 100));
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 4, KYC, 100));
+
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 4, 100),
+            Error::<Test>::NotWhitelisted
+        );
+    });
+}
+
+/// Tests that an expired claim no longer verifies the account.
+#[test]
+fn expired_claim_blocks_transfer() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_identity();
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 2, KYC, 50));
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 3, KYC, 100));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100));
+
+        System::set_block_number(50);
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100),
+            Error::<Test>::MissingClaim
+        );
+
+        // Renewing the claim restores the account
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 2, KYC, 200));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100));
+    });
+}
+
+/// Tests that revoking an issuer invalidates the claims it attested.
+#[test]
+fn removing_issuer_invalidates_claims() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_identity();
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 2, KYC, 100));
+        assert!(CladToken::is_verified(ASSET, &2));
+
+        assert_ok!(CladToken::remove_trusted_issuer(RuntimeOrigin::signed(1), ISSUER));
+        assert!(!CladToken::is_verified(ASSET, &2));
+    });
+}
+
+/// Tests that claims can be revoked by their issuer or the admin only.
+#[test]
+fn remove_claim_by_issuer_or_admin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_identity();
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 2, KYC, 100));
+        assert_ok!(CladToken::add_claim(RuntimeOrigin::signed(ISSUER), 3, KYC, 100));
+
+        assert_noop!(
+            CladToken::remove_claim(RuntimeOrigin::signed(2), 2, KYC),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(CladToken::remove_claim(RuntimeOrigin::signed(ISSUER), 2, KYC));
+        assert_eq!(CladToken::claim(2, KYC), None);
+        System::assert_last_event(Event::ClaimRemoved { subject: 2, topic: KYC }.into());
+
+        assert_ok!(CladToken::remove_claim(RuntimeOrigin::signed(1), 3, KYC));
+        assert_noop!(
+            CladToken::remove_claim(RuntimeOrigin::signed(1), 3, KYC),
+            Error::<Test>::ClaimNotFound
+        );
+    });
+}
+
+/// Tests that clearing the required topics falls back to the whitelist alone.
+#[test]
+fn empty_required_topics_means_whitelist_only() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_identity();
+        assert!(!CladToken::is_verified(ASSET, &2));
+
+        assert_ok!(CladToken::set_required_claim_topics(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            topics(&[])
+        ));
+        assert!(!crate::RequiredClaimTopics::<Test>::contains_key(ASSET));
+        assert!(CladToken::is_verified(ASSET, &2));
+    });
+}
+
+/// Tests that required topics are per instrument.
+#[test]
+fn required_topics_are_per_instrument() {
+    new_test_ext().execute_with(|| {
+        let other = create_second_instrument();
+        setup_identity();
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), other, 2, None, None));
+
+        assert!(!CladToken::is_verified(ASSET, &2));
+        assert!(CladToken::is_verified(other, &2));
+    });
+}
+
+// ============================================================================
+// KYC Expiry Tests
+// ============================================================================
+
+/// Run the `on_idle` hook with unlimited weight.
+fn run_idle() {
+    use frame_support::traits::Hooks;
+    CladToken::on_idle(System::block_number(), frame_support::weights::Weight::MAX);
+}
+
+/// Tests that a whitelist entry records its expiry.
+#[test]
+fn add_to_whitelist_with_expiry_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::add_to_whitelist(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            5,
+            Some(100),
+            None
+        ));
+
+        assert_eq!(
+            CladToken::whitelist_entry(ASSET, 5),
+            Some(WhitelistEntry { expires_at: Some(100) })
+        );
+        assert!(CladToken::is_whitelisted(ASSET, &5));
+        System::assert_last_event(
+            Event::Whitelisted { asset_id: ASSET, account: 5, expires_at: Some(100) }.into(),
+        );
+    });
+}
+
+/// Tests that the expiry must lie in the future.
+#[test]
+fn add_to_whitelist_rejects_past_expiry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(10);
+        assert_noop!(
+            CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, Some(10), None),
+            Error::<Test>::InvalidWhitelistExpiry
+        );
+    });
+}
+
+/// Tests that transfers from and to an account with a lapsed approval fail.
+#[test]
+fn transfer_fails_after_kyc_expiry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 3, Some(50), None));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100));
+
+        System::set_block_number(50);
+        assert!(!CladToken::is_whitelisted(ASSET, &3));
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100),
+            Error::<Test>::KycExpired
+        );
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 2, 100),
+            Error::<Test>::KycExpired
+        );
+    });
+}
+
+/// Tests that re-whitelisting renews a lapsed approval.
+#[test]
+fn kyc_renewal_restores_transfers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 3, Some(50), None));
+        System::set_block_number(60);
+
+        assert_ok!(CladToken::add_to_whitelist(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            3,
+            Some(500),
+            None
+        ));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 2, 100));
+    });
+}
+
+/// Tests that the idle sweep removes lapsed approvals and emits events.
+#[test]
+fn on_idle_sweeps_expired_entries() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, Some(10), None));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 6, Some(10), None));
+        assert_ok!(CladToken::add_to_whitelist(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            7,
+            Some(100),
+            None
+        ));
+
+        System::set_block_number(10);
+        // Six entries, two per block: three blocks to walk the map, one to notice the end
+        for _ in 0..4 {
+            run_idle();
+        }
+        assert!(crate::WhitelistSweepCursor::<Test>::get().is_none());
+
+        assert_eq!(CladToken::whitelist_entry(ASSET, 5), None);
+        assert_eq!(CladToken::whitelist_entry(ASSET, 6), None);
+        assert!(CladToken::is_whitelisted(ASSET, &7));
+        assert!(CladToken::is_whitelisted(ASSET, &2));
+        System::assert_has_event(Event::WhitelistExpired { asset_id: ASSET, account: 5 }.into());
+        System::assert_has_event(Event::WhitelistExpired { asset_id: ASSET, account: 6 }.into());
+    });
+}
+
+/// Tests that the sweep resumes from its cursor instead of restarting.
+#[test]
+fn on_idle_sweep_is_bounded_and_resumes() {
+    new_test_ext().execute_with(|| {
+        run_idle();
+        let cursor = crate::WhitelistSweepCursor::<Test>::get();
+        assert!(cursor.is_some());
+
+        run_idle();
+        assert_ne!(crate::WhitelistSweepCursor::<Test>::get(), cursor);
+    });
+}
+
+/// Tests that the sweep does nothing when no weight is left.
+#[test]
+fn on_idle_without_weight_does_nothing() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::Hooks;
+        let used = CladToken::on_idle(1, frame_support::weights::Weight::zero());
+        assert_eq!(used, frame_support::weights::Weight::zero());
+        assert!(crate::WhitelistSweepCursor::<Test>::get().is_none());
+    });
+}
+
+// ============================================================================
+// Jurisdiction Tests
+// ============================================================================
+
+const KZ: CountryCode = 398;
+const MY: CountryCode = 458;
+const US: CountryCode = 840;
+
+fn countries(codes: &[CountryCode]) -> CountriesOf<Test> {
+    codes.to_vec().try_into().unwrap()
+}
+
+/// Tests that whitelisting records the investor's country and emits an event.
+#[test]
+fn add_to_whitelist_records_country() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, Some(KZ)));
+
+        assert_eq!(CladToken::investor_country(ASSET, 5), Some(KZ));
+        System::assert_has_event(
+            Event::InvestorCountrySet { asset_id: ASSET, account: 5, country: Some(KZ) }.into(),
+        );
+
+        // Re-whitelisting without a country clears it
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
+        assert_eq!(CladToken::investor_country(ASSET, 5), None);
+    });
+}
+
+/// Tests that transfers into a blocked jurisdiction fail with CountryBlocked.
+#[test]
+fn transfer_to_blocked_country_fails() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 3, None, Some(US)));
+        assert_ok!(CladToken::set_blocked_countries(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            countries(&[US])
+        ));
+
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100),
+            Error::<Test>::CountryBlocked
+        );
+
+        // Holders in a blocked jurisdiction can still sell
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 2, 100));
+    });
+}
+
+/// Tests that a non-empty allow list rejects other and unrecorded countries.
+#[test]
+fn transfer_outside_allowed_countries_fails() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 3, None, Some(MY)));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
+        assert_ok!(CladToken::set_allowed_countries(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            countries(&[KZ])
+        ));
+
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100),
+            Error::<Test>::CountryNotAllowed
+        );
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 5, 100),
+            Error::<Test>::CountryNotAllowed
+        );
+
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 3, None, Some(KZ)));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100));
+
+        // Clearing the allow list lifts the restriction
+        assert_ok!(CladToken::set_allowed_countries(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            countries(&[])
+        ));
+        assert_eq!(CladToken::allowed_countries(ASSET), countries(&[]));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 5, 100));
+    });
+}
+
+/// Tests that the per-country holder count follows mints, transfers and burns.
+#[test]
+fn holders_per_country_tracks_balances() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, Some(KZ)));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 6, None, Some(KZ)));
+        assert_eq!(CladToken::holders_per_country(ASSET, KZ), 0);
+
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 1_000));
+        assert_eq!(CladToken::holders_per_country(ASSET, KZ), 1);
+
+        // Partial transfer: both hold
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 6, 400));
+        assert_eq!(CladToken::holders_per_country(ASSET, KZ), 2);
+
+        // Full transfer: sender leaves
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 6, 600));
+        assert_eq!(CladToken::holders_per_country(ASSET, KZ), 1);
+
+        assert_ok!(CladToken::burn(RuntimeOrigin::signed(1), ASSET, 6, 1_000));
+        assert_eq!(CladToken::holders_per_country(ASSET, KZ), 0);
+    });
+}
+
+/// Tests that changing a holder's country moves them between counts.
+#[test]
+fn changing_country_moves_holder() {
+    new_test_ext().execute_with(|| {
+        // Account 2 holds genesis tokens but has no country yet
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 2, None, Some(KZ)));
+        assert_eq!(CladToken::holders_per_country(ASSET, KZ), 1);
+
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 2, None, Some(MY)));
+        assert_eq!(CladToken::holders_per_country(ASSET, KZ), 0);
+        assert_eq!(CladToken::holders_per_country(ASSET, MY), 1);
+
+        // Removing from the whitelist keeps the holder counted
+        assert_ok!(CladToken::remove_from_whitelist(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_eq!(CladToken::holders_per_country(ASSET, MY), 1);
+    });
+}
+
+/// Tests that country lists are admin-only, per instrument and emit events.
+#[test]
+fn set_country_lists_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            CladToken::set_blocked_countries(RuntimeOrigin::signed(2), ASSET, countries(&[US])),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::set_allowed_countries(RuntimeOrigin::signed(1), 99, countries(&[KZ])),
+            Error::<Test>::UnknownInstrument
+        );
+
+        assert_ok!(CladToken::set_blocked_countries(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            countries(&[US])
+        ));
+        assert_eq!(CladToken::blocked_countries(ASSET), countries(&[US]));
+        System::assert_last_event(
+            Event::BlockedCountriesSet { asset_id: ASSET, countries: countries(&[US]) }.into(),
+        );
+
+        assert_ok!(CladToken::set_allowed_countries(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            countries(&[KZ, MY])
+        ));
+        System::assert_last_event(
+            Event::AllowedCountriesSet { asset_id: ASSET, countries: countries(&[KZ, MY]) }.into(),
+        );
+    });
+}
+
+// ============================================================================
+// Holder Count Tests
+// ============================================================================
+
+/// Tests that the holder count follows balances moving to and from zero.
+#[test]
+fn holder_count_tracks_balances() {
+    new_test_ext().execute_with(|| {
+        // Genesis balances for accounts 2 and 3
+        assert_eq!(CladToken::holder_count(ASSET), 2);
+
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 100));
+        assert_eq!(CladToken::holder_count(ASSET), 3);
+
+        // Topping up an existing holder does not count twice
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 100));
+        assert_eq!(CladToken::holder_count(ASSET), 3);
+
+        // Emptying a balance removes the holder
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 2, 500_000));
+        assert_eq!(CladToken::holder_count(ASSET), 2);
+
+        assert_ok!(CladToken::burn(RuntimeOrigin::signed(1), ASSET, 5, 200));
+        assert_eq!(CladToken::holder_count(ASSET), 1);
+    });
+}
+
+/// Tests that minting to a new holder fails once the cap is reached.
+#[test]
+fn mint_respects_max_holders() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
+        assert_ok!(CladToken::set_max_holders(RuntimeOrigin::signed(1), ASSET, Some(2)));
+
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 100),
+            Error::<Test>::TooManyHolders
+        );
+
+        // Existing holders can still receive new issuance
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 100));
+    });
+}
+
+/// Tests that transfers to new holders fail at the cap unless the sender exits.
+#[test]
+fn transfer_respects_max_holders() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
+        assert_ok!(CladToken::set_max_holders(RuntimeOrigin::signed(1), ASSET, Some(2)));
+
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 5, 100),
+            Error::<Test>::TooManyHolders
+        );
+
+        // Transfers between existing holders are unaffected
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100));
+
+        // A holder selling out frees the slot for the buyer
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 5, 500_100));
+        assert_eq!(CladToken::holder_count(ASSET), 2);
+        assert_eq!(CladToken::balance_of(ASSET, &5), 500_100);
+    });
+}
+
+/// Tests that the holder cap is admin-only, removable and emits events.
+#[test]
+fn set_max_holders_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            CladToken::set_max_holders(RuntimeOrigin::signed(2), ASSET, Some(10)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::set_max_holders(RuntimeOrigin::signed(1), 99, Some(10)),
+            Error::<Test>::UnknownInstrument
+        );
+
+        // A cap below the current count is accepted
+        assert_ok!(CladToken::set_max_holders(RuntimeOrigin::signed(1), ASSET, Some(1)));
+        assert_eq!(CladToken::max_holders(ASSET), Some(1));
+        System::assert_last_event(
+            Event::MaxHoldersSet { asset_id: ASSET, max_holders: Some(1) }.into(),
+        );
+
+        assert_ok!(CladToken::set_max_holders(RuntimeOrigin::signed(1), ASSET, None));
+        assert_eq!(CladToken::max_holders(ASSET), None);
+    });
+}
+
+// ============================================================================
+// Holding Limit Tests
+// ============================================================================
+
+/// Tests that an absolute per-holder limit caps transfers and mints.
+#[test]
+fn absolute_holding_limit_is_enforced() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
+        assert_ok!(CladToken::set_max_balance_per_holder(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            Some(HoldingLimit::Absolute(1_000))
+        ));
+
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 5, 600));
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 5, 401),
+            Error::<Test>::HoldingLimitExceeded
+        );
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 401),
+            Error::<Test>::HoldingLimitExceeded
+        );
+
+        // Exactly at the limit is fine
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 400));
+        assert_eq!(CladToken::balance_of(ASSET, &5), 1_000);
+    });
+}
+
+/// Tests that a share-of-supply limit is evaluated against the supply after
+/// the operation.
+#[test]
+fn share_of_supply_holding_limit_is_enforced() {
+    new_test_ext().execute_with(|| {
+        // Supply is 1_500_000: 2% is 30_000
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
+        assert_ok!(CladToken::set_max_balance_per_holder(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            Some(HoldingLimit::ShareOfSupply(Perbill::from_percent(2)))
+        ));
+
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 5, 30_001),
+            Error::<Test>::HoldingLimitExceeded
+        );
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 5, 30_000));
+
+        // Minting grows the supply, but not enough to cover the minted amount
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 1_000),
+            Error::<Test>::HoldingLimitExceeded
+        );
+    });
+}
+
+/// Tests that an account override replaces the instrument-wide limit.
+#[test]
+fn account_holding_limit_overrides_instrument_limit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
+        assert_ok!(CladToken::set_max_balance_per_holder(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            Some(HoldingLimit::Absolute(1_000))
+        ));
+
+        // Anchor investor with a larger allocation
+        assert_ok!(CladToken::set_account_holding_limit(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            5,
+            Some(HoldingLimit::Absolute(10_000))
+        ));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 10_000));
+
+        // Removing the override restores the instrument limit
+        assert_ok!(CladToken::set_account_holding_limit(RuntimeOrigin::signed(1), ASSET, 5, None));
+        assert_eq!(CladToken::account_holding_limit(ASSET, 5), None);
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 5, 1),
+            Error::<Test>::HoldingLimitExceeded
+        );
+
+        // Holders above the limit can still sell
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 6, None, None));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 6, 1_000));
+    });
+}
+
+/// Tests that holding limits are admin-only and emit events.
+#[test]
+fn set_holding_limits_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let limit = HoldingLimit::ShareOfSupply(Perbill::from_percent(2));
+        assert_noop!(
+            CladToken::set_max_balance_per_holder(RuntimeOrigin::signed(2), ASSET, Some(limit)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::set_account_holding_limit(RuntimeOrigin::signed(1), 99, 5, Some(limit)),
+            Error::<Test>::UnknownInstrument
+        );
+
+        assert_ok!(CladToken::set_max_balance_per_holder(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            Some(limit)
+        ));
+        assert_eq!(CladToken::max_balance_per_holder(ASSET), Some(limit));
+        System::assert_last_event(
+            Event::MaxBalancePerHolderSet { asset_id: ASSET, limit: Some(limit) }.into(),
+        );
+
+        assert_ok!(CladToken::set_account_holding_limit(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            5,
+            Some(limit)
+        ));
+        System::assert_last_event(
+            Event::AccountHoldingLimitSet { asset_id: ASSET, account: 5, limit: Some(limit) }
+                .into(),
+        );
+    });
+}
+
+// ============================================================================
+// Vesting Tests
+// ============================================================================
+
+/// Schedule with release accruing over blocks 100..1100 and a cliff at 300.
+fn schedule() -> VestingSchedule<u64> {
+    VestingSchedule { start: 100, cliff: 300, end: 1_100 }
+}
+
+/// Whitelist account 5 and mint it 1_000 tokens under [`schedule`].
+fn setup_vesting() {
+    System::set_block_number(1);
+    assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 5, None, None));
+    assert_ok!(CladToken::mint_with_schedule(
+        RuntimeOrigin::signed(1),
+        ASSET,
+        5,
+        1_000,
+        schedule()
+    ));
+}
+
+/// Tests that scheduled tokens are minted, recorded and locked before the cliff.
+#[test]
+fn mint_with_schedule_locks_tokens() {
+    new_test_ext().execute_with(|| {
+        setup_vesting();
+
+        assert_eq!(CladToken::balance_of(ASSET, &5), 1_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_501_000);
+        assert_eq!(CladToken::vesting_balance(ASSET, &5), (1_000, 0));
+        System::assert_has_event(Event::Minted { asset_id: ASSET, to: 5, amount: 1_000 }.into());
+        System::assert_last_event(
+            Event::VestingScheduleAdded {
+                asset_id: ASSET,
+                account: 5,
+                amount: 1_000,
+                schedule: schedule(),
+            }
+            .into(),
+        );
+
+        // Accrued but before the cliff: still fully locked
+        System::set_block_number(299);
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 2, 1),
+            Error::<Test>::InsufficientVestedBalance
+        );
+    });
+}
+
+/// Tests that tokens release linearly after the cliff and fully at the end.
+#[test]
+fn vesting_releases_linearly() {
+    new_test_ext().execute_with(|| {
+        setup_vesting();
+
+        // At the cliff, the release accrued since `start` unlocks at once
+        System::set_block_number(300);
+        assert_eq!(CladToken::vesting_balance(ASSET, &5), (800, 200));
+
+        System::set_block_number(600);
+        assert_eq!(CladToken::vesting_balance(ASSET, &5), (500, 500));
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 2, 501),
+            Error::<Test>::InsufficientVestedBalance
+        );
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 2, 500));
+
+        System::set_block_number(1_100);
+        assert_eq!(CladToken::vesting_balance(ASSET, &5), (0, 500));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 2, 500));
+    });
+}
+
+/// Tests that locked tokens cannot be redeemed or burned.
+#[test]
+fn vesting_blocks_redeem_and_burn() {
+    new_test_ext().execute_with(|| {
+        setup_vesting();
+
+        assert_noop!(
+            CladToken::redeem(RuntimeOrigin::signed(5), ASSET, 1),
+            Error::<Test>::InsufficientVestedBalance
+        );
+        assert_noop!(
+            CladToken::burn(RuntimeOrigin::signed(1), ASSET, 5, 1),
+            Error::<Test>::InsufficientVestedBalance
+        );
+    });
+}
+
+/// Tests that vesting-locked tokens can be partially frozen, and that the
+/// freeze outlasts the lock.
+#[test]
+fn freeze_partial_covers_vesting_locked_tokens() {
+    new_test_ext().execute_with(|| {
+        setup_vesting();
+        assert_eq!(CladToken::vesting_balance(ASSET, &5), (1_000, 0));
+
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 5, 1_000));
+        assert_eq!(CladToken::frozen_amount(ASSET, &5), 1_000);
+        assert_noop!(
+            CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 5, 1),
+            Error::<Test>::InsufficientBalance
+        );
+
+        // Fully vested, but still frozen
+        System::set_block_number(1_100);
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 2, 1),
+            Error::<Test>::InsufficientUnfrozenBalance
+        );
+        assert_ok!(CladToken::unfreeze_partial(RuntimeOrigin::signed(1), ASSET, 5, 1_000));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(5), ASSET, 2, 1_000));
+    });
+}
+
+/// Tests that the number of locking schedules is bounded and vested ones are pruned.
+#[test]
+fn vesting_schedules_are_bounded() {
+    new_test_ext().execute_with(|| {
+        setup_vesting();
+        assert_ok!(CladToken::mint_with_schedule(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            5,
+            1_000,
+            schedule()
+        ));
+
+        // MaxVestingSchedules = 2
+        assert_noop!(
+            CladToken::mint_with_schedule(RuntimeOrigin::signed(1), ASSET, 5, 1_000, schedule()),
+            Error::<Test>::TooManyVestingSchedules
+        );
+
+        // Once vested, old schedules make room for new ones
+        System::set_block_number(1_100);
+        assert_ok!(CladToken::mint_with_schedule(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            5,
+            1_000,
+            VestingSchedule { start: 1_100, cliff: 1_100, end: 2_100 }
+        ));
+        assert_eq!(CladToken::vesting(ASSET, 5).len(), 1);
+        assert_eq!(CladToken::vesting_balance(ASSET, &5), (1_000, 2_000));
+    });
+}
+
+/// Tests that out-of-order schedules are rejected.
+#[test]
+fn mint_with_schedule_rejects_invalid_schedule() {
+    new_test_ext().execute_with(|| {
+        let cliff_before_start = VestingSchedule { start: 100, cliff: 50, end: 200 };
+        let end_before_cliff = VestingSchedule { start: 100, cliff: 300, end: 200 };
+        for schedule in [cliff_before_start, end_before_cliff] {
+            assert_noop!(
+                CladToken::mint_with_schedule(RuntimeOrigin::signed(1), ASSET, 2, 1_000, schedule),
+                Error::<Test>::InvalidVestingSchedule
+            );
+        }
+        assert_noop!(
+            CladToken::mint_with_schedule(RuntimeOrigin::signed(2), ASSET, 2, 1_000, schedule()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+// ============================================================================
+// Bond Lifecycle Tests
+// ============================================================================
+
+/// 1% of a face value of 10 per token on blocks 100 and 200, maturing at 200.
+///
+/// Account 2 (1_000_000 tokens) is owed 100_000 per coupon, account 3
+/// (500_000 tokens) 50_000.
+fn bond_terms() -> BondTermsOf<Test> {
+    BondTerms {
+        face_value: 10,
+        coupon_rate: Perbill::from_percent(1),
+        coupon_dates: vec![100, 200].try_into().unwrap(),
+        maturity: 200,
+    }
+}
+
+/// Set [`bond_terms`] on the genesis instrument and fund its pot with `funds`.
+fn setup_bond(funds: u64) {
+    System::set_block_number(1);
+    assert_ok!(CladToken::set_bond_terms(RuntimeOrigin::signed(1), ASSET, bond_terms()));
+    Balances::set_balance(&CladToken::coupon_pot(ASSET), funds);
+}
+
+/// Tests that bond terms are stored and inconsistent terms are rejected.
+#[test]
+fn set_bond_terms_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::set_bond_terms(RuntimeOrigin::signed(1), ASSET, bond_terms()));
+        assert_eq!(CladToken::bond_terms(ASSET), Some(bond_terms()));
+        System::assert_last_event(
+            Event::BondTermsSet { asset_id: ASSET, terms: bond_terms() }.into(),
+        );
+
+        let unordered =
+            BondTerms { coupon_dates: vec![200, 100].try_into().unwrap(), ..bond_terms() };
+        let after_maturity = BondTerms { maturity: 150, ..bond_terms() };
+        for terms in [unordered, after_maturity] {
+            assert_noop!(
+                CladToken::set_bond_terms(RuntimeOrigin::signed(1), ASSET, terms),
+                Error::<Test>::InvalidBondTerms
+            );
+        }
+        assert_noop!(
+            CladToken::set_bond_terms(RuntimeOrigin::signed(1), 99, bond_terms()),
+            Error::<Test>::UnknownInstrument
+        );
+        assert_noop!(
+            CladToken::set_bond_terms(RuntimeOrigin::signed(2), ASSET, bond_terms()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+/// Tests that a due coupon is paid to every holder in proportion to their balance.
+#[test]
+fn distribute_coupon_pays_holders_pro_rata() {
+    new_test_ext().execute_with(|| {
+        setup_bond(150_000);
+        System::set_block_number(100);
+
+        // Anyone can trigger the payment
+        assert_ok!(CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET));
+
+        assert_eq!(Balances::free_balance(2), 100_000);
+        assert_eq!(Balances::free_balance(3), 50_000);
+        assert_eq!(Balances::free_balance(CladToken::coupon_pot(ASSET)), 0);
+        assert_eq!(CladToken::next_coupon(ASSET), 1);
+        assert_eq!(CladToken::coupon_distribution(ASSET), None);
+        System::assert_has_event(
+            Event::CouponDistributionStarted { asset_id: ASSET, coupon_index: 0, total: 150_000 }
+                .into(),
+        );
+        System::assert_has_event(
+            Event::CouponPaid { asset_id: ASSET, coupon_index: 0, account: 2, amount: 100_000 }
+                .into(),
+        );
+        System::assert_last_event(
+            Event::CouponDistributed { asset_id: ASSET, coupon_index: 0, total_paid: 150_000 }
+                .into(),
+        );
+    });
+}
+
+/// Tests that a coupon is only paid once due, funded and not yet paid.
+#[test]
+fn distribute_coupon_checks_schedule_and_funds() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(100);
+        assert_noop!(
+            CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET),
+            Error::<Test>::NoBondTerms
+        );
+
+        setup_bond(149_999);
+        assert_noop!(
+            CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET),
+            Error::<Test>::CouponNotDue
+        );
+
+        System::set_block_number(100);
+        assert_noop!(
+            CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET),
+            Error::<Test>::InsufficientCouponFunds
+        );
+
+        Balances::set_balance(&CladToken::coupon_pot(ASSET), 300_000);
+        assert_ok!(CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET));
+        // The second coupon falls due at block 200
+        assert_noop!(
+            CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET),
+            Error::<Test>::CouponNotDue
+        );
+
+        System::set_block_number(200);
+        assert_ok!(CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET));
+        assert_eq!(Balances::free_balance(2), 200_000);
+        assert_noop!(
+            CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET),
+            Error::<Test>::NoCouponDue
+        );
+    });
+}
+
+/// Tests that large holder sets are paid over several calls, with balances fixed
+/// in between.
+#[test]
+fn coupon_distribution_is_paged() {
+    new_test_ext().execute_with(|| {
+        setup_bond(160_000);
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 4, None, None));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 4, 100_000));
+        System::set_block_number(100);
+
+        // MaxHoldersPerPage = 2, so three holders take two calls
+        assert_ok!(CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET));
+        let progress = CladToken::coupon_distribution(ASSET).unwrap();
+        assert_eq!(progress.coupon_index, 0);
+        assert_eq!(CladToken::next_coupon(ASSET), 0);
+
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 1),
+            Error::<Test>::CouponDistributionInProgress
+        );
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 1),
+            Error::<Test>::CouponDistributionInProgress
+        );
+        assert_noop!(
+            CladToken::redeem(RuntimeOrigin::signed(2), ASSET, 1),
+            Error::<Test>::CouponDistributionInProgress
+        );
+        assert_noop!(
+            CladToken::set_bond_terms(RuntimeOrigin::signed(1), ASSET, bond_terms()),
+            Error::<Test>::CouponDistributionInProgress
+        );
+
+        assert_ok!(CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET));
+        assert_eq!(CladToken::coupon_distribution(ASSET), None);
+        assert_eq!(CladToken::next_coupon(ASSET), 1);
+        assert_eq!(Balances::free_balance(2), 100_000);
+        assert_eq!(Balances::free_balance(3), 50_000);
+        assert_eq!(Balances::free_balance(4), 10_000);
+        System::assert_last_event(
+            Event::CouponDistributed { asset_id: ASSET, coupon_index: 0, total_paid: 160_000 }
+                .into(),
+        );
+
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 1));
+    });
+}
+
+/// Tests that a coupon that cannot be transferred is recorded, kept out of the
+/// next coupon's funds, and paid out by a claim.
+#[test]
+fn unpaid_coupon_is_recorded_and_claimed() {
+    new_test_ext().execute_with(|| {
+        setup_bond(210_000);
+        // Account 3's 50_000 coupon is below the existential deposit
+        ExistentialDeposit::set(60_000);
+        System::set_block_number(100);
+
+        assert_ok!(CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET));
+        System::assert_has_event(
+            Event::CouponPaymentFailed {
+                asset_id: ASSET,
+                coupon_index: 0,
+                account: 3,
+                amount: 50_000,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(2), 100_000);
+        assert_eq!(Balances::free_balance(3), 0);
+        assert_eq!(CladToken::unpaid_coupon((ASSET, 0, 3)), Some(50_000));
+        assert_eq!(crate::UnpaidCouponTotal::<Test>::get(ASSET), 50_000);
+        assert_eq!(CladToken::next_coupon(ASSET), 1);
+
+        // The unpaid coupon is not available for the next one
+        System::set_block_number(200);
+        Balances::set_balance(&CladToken::coupon_pot(ASSET), 199_999);
+        assert_noop!(
+            CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET),
+            Error::<Test>::InsufficientCouponFunds
+        );
+
+        // Still below the existential deposit, the claim fails and is kept
+        assert_noop!(
+            CladToken::claim_coupon(RuntimeOrigin::signed(5), ASSET, 0, 3),
+            sp_runtime::TokenError::BelowMinimum
+        );
+        Balances::set_balance(&3, 60_000);
+        assert_ok!(CladToken::claim_coupon(RuntimeOrigin::signed(5), ASSET, 0, 3));
+        System::assert_last_event(
+            Event::CouponClaimed {
+                asset_id: ASSET,
+                coupon_index: 0,
+                account: 3,
+                payee: 3,
+                amount: 50_000,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(3), 110_000);
+        assert_eq!(Balances::free_balance(CladToken::coupon_pot(ASSET)), 149_999);
+        assert_eq!(CladToken::unpaid_coupon((ASSET, 0, 3)), None);
+        assert_eq!(crate::UnpaidCouponTotal::<Test>::get(ASSET), 0);
+    });
+}
+
+/// Tests that only recorded coupons can be claimed, and only once.
+#[test]
+fn claim_coupon_requires_unpaid_coupon() {
+    new_test_ext().execute_with(|| {
+        setup_bond(150_000);
+        System::set_block_number(100);
+        assert_ok!(CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET));
+
+        assert_noop!(
+            CladToken::claim_coupon(RuntimeOrigin::signed(5), ASSET, 0, 2),
+            Error::<Test>::NoUnpaidCoupon
+        );
+        assert_noop!(
+            CladToken::claim_coupon(RuntimeOrigin::signed(5), 99, 0, 2),
+            Error::<Test>::UnknownInstrument
+        );
+        assert_noop!(
+            CladToken::claim_coupon(RuntimeOrigin::none(), ASSET, 0, 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+// ============================================================================
+// Maturity Redemption Tests
+// ============================================================================
+
+/// Zero-coupon version of [`bond_terms`], maturing at block 200.
+///
+/// Account 2 (1_000_000 tokens) is owed 10_000_000 of principal, account 3
+/// (500_000 tokens) 5_000_000.
+fn zero_coupon_terms() -> BondTermsOf<Test> {
+    BondTerms { coupon_dates: Default::default(), ..bond_terms() }
+}
+
+/// Set [`zero_coupon_terms`] on the genesis instrument and fund its redemption
+/// pot with `funds`.
+fn setup_maturity(funds: u64) {
+    System::set_block_number(1);
+    assert_ok!(CladToken::set_bond_terms(RuntimeOrigin::signed(1), ASSET, zero_coupon_terms()));
+    Balances::set_balance(&CladToken::redemption_pot(ASSET), funds);
+}
+
+/// Tests that holders redeem at maturity and the instrument matures with the last one.
+#[test]
+fn holders_redeem_at_maturity() {
+    new_test_ext().execute_with(|| {
+        setup_maturity(15_000_000);
+        assert_noop!(
+            CladToken::redeem_at_maturity(RuntimeOrigin::signed(2), ASSET),
+            Error::<Test>::NotMatured
+        );
+
+        System::set_block_number(200);
+        assert_ok!(CladToken::redeem_at_maturity(RuntimeOrigin::signed(2), ASSET));
+        assert_eq!(CladToken::balance_of(ASSET, &2), 0);
+        assert_eq!(CladToken::total_supply(ASSET), 500_000);
+        assert_eq!(Balances::free_balance(2), 10_000_000);
+        System::assert_last_event(
+            Event::MaturityRedeemed {
+                asset_id: ASSET,
+                account: 2,
+                amount: 1_000_000,
+                payout: 10_000_000,
+            }
+            .into(),
+        );
+        assert_eq!(CladToken::is_matured(ASSET), false);
+        assert_noop!(
+            CladToken::redeem_at_maturity(RuntimeOrigin::signed(2), ASSET),
+            Error::<Test>::InsufficientBalance
+        );
+
+        assert_ok!(CladToken::redeem_at_maturity(RuntimeOrigin::signed(3), ASSET));
+        assert_eq!(Balances::free_balance(3), 5_000_000);
+        assert_eq!(CladToken::is_matured(ASSET), true);
+        System::assert_last_event(Event::InstrumentMatured { asset_id: ASSET }.into());
+
+        // A matured instrument no longer moves
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 2, 0),
+            Error::<Test>::InstrumentMatured
+        );
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 1),
+            Error::<Test>::InstrumentMatured
+        );
+        assert_noop!(
+            CladToken::redeem_at_maturity(RuntimeOrigin::signed(1), ASSET),
+            Error::<Test>::InstrumentMatured
+        );
+    });
+}
+
+/// Tests that the admin redeems holders in pages and escrows the principal of
+/// restricted holders, so the instrument still matures.
+#[test]
+fn admin_redeems_holders_in_pages() {
+    new_test_ext().execute_with(|| {
+        setup_maturity(16_000_000);
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 4, None, None));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 4, 100_000));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 3));
+        System::set_block_number(200);
+
+        // MaxHoldersPerPage = 2, so three holders take two calls
+        assert_ok!(CladToken::redeem_at_maturity(RuntimeOrigin::signed(1), ASSET));
+        assert!(crate::RedemptionCursor::<Test>::get(ASSET).is_some());
+        assert_ok!(CladToken::redeem_at_maturity(RuntimeOrigin::signed(1), ASSET));
+        assert!(crate::RedemptionCursor::<Test>::get(ASSET).is_none());
+
+        assert_eq!(Balances::free_balance(2), 10_000_000);
+        assert_eq!(Balances::free_balance(4), 1_000_000);
+        // The frozen holder is burned too, but its principal stays in the pot
+        assert_eq!(CladToken::balance_of(ASSET, &3), 0);
+        assert_eq!(Balances::free_balance(3), 0);
+        assert_eq!(CladToken::maturity_escrow(ASSET, 3), Some(5_000_000));
+        System::assert_has_event(
+            Event::MaturityEscrowed {
+                asset_id: ASSET,
+                account: 3,
+                amount: 500_000,
+                payout: 5_000_000,
+            }
+            .into(),
+        );
+        assert_eq!(CladToken::total_supply(ASSET), 0);
+        assert_eq!(CladToken::is_matured(ASSET), true);
+        System::assert_last_event(Event::InstrumentMatured { asset_id: ASSET }.into());
+
+        assert_noop!(
+            CladToken::claim_matured_principal(RuntimeOrigin::signed(5), ASSET, 3),
+            Error::<Test>::AccountFrozen
+        );
+        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), ASSET, 3));
+        assert_ok!(CladToken::claim_matured_principal(RuntimeOrigin::signed(5), ASSET, 3));
+        assert_eq!(Balances::free_balance(3), 5_000_000);
+        assert_eq!(CladToken::maturity_escrow(ASSET, 3), None);
+        System::assert_last_event(
+            Event::MaturityEscrowReleased {
+                asset_id: ASSET,
+                account: 3,
+                payee: 3,
+                payout: 5_000_000,
+            }
+            .into(),
+        );
+        assert_noop!(
+            CladToken::claim_matured_principal(RuntimeOrigin::signed(5), ASSET, 3),
+            Error::<Test>::NothingEscrowed
+        );
+    });
+}
+
+/// Tests that a partially frozen holder's principal is held until the freeze is
+/// lifted, and is not available to other holders meanwhile.
+#[test]
+fn escrowed_principal_waits_for_partial_freeze() {
+    new_test_ext().execute_with(|| {
+        setup_maturity(15_000_000);
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 2, 1_000));
+        System::set_block_number(200);
+
+        // Only holder 3 is paid; holder 2's principal stays escrowed
+        assert_ok!(CladToken::redeem_at_maturity(RuntimeOrigin::signed(1), ASSET));
+        assert_eq!(CladToken::is_matured(ASSET), true);
+        assert_eq!(Balances::free_balance(3), 5_000_000);
+        assert_eq!(CladToken::maturity_escrow(ASSET, 2), Some(10_000_000));
+        assert_eq!(Balances::free_balance(CladToken::redemption_pot(ASSET)), 10_000_000);
+
+        assert_noop!(
+            CladToken::claim_matured_principal(RuntimeOrigin::signed(2), ASSET, 2),
+            Error::<Test>::InsufficientUnfrozenBalance
+        );
+        assert_ok!(CladToken::unfreeze_partial(RuntimeOrigin::signed(1), ASSET, 2, 1_000));
+        assert_ok!(CladToken::claim_matured_principal(RuntimeOrigin::signed(2), ASSET, 2));
+        assert_eq!(Balances::free_balance(2), 10_000_000);
+    });
+}
+
+/// Tests that a holder whose KYC approval has expired can still redeem at maturity.
+#[test]
+fn expired_holder_redeems_at_maturity() {
+    new_test_ext().execute_with(|| {
+        setup_maturity(15_000_000);
+        assert_ok!(CladToken::add_to_whitelist(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            2,
+            Some(100),
+            None
+        ));
+        System::set_block_number(200);
+        assert!(!CladToken::is_whitelisted(ASSET, &2));
+
+        assert_ok!(CladToken::redeem_at_maturity(RuntimeOrigin::signed(2), ASSET));
+        assert_eq!(CladToken::balance_of(ASSET, &2), 0);
+        assert_eq!(Balances::free_balance(2), 10_000_000);
+    });
+}
+
+/// Tests that redemption waits for every coupon and a funded pot.
+#[test]
+fn redeem_at_maturity_requires_coupons_and_funds() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(200);
+        assert_noop!(
+            CladToken::redeem_at_maturity(RuntimeOrigin::signed(2), ASSET),
+            Error::<Test>::NoBondTerms
+        );
+
+        // In the mock, the coupon and redemption pots are the same account
+        setup_bond(300_000);
+        System::set_block_number(200);
+        assert_noop!(
+            CladToken::redeem_at_maturity(RuntimeOrigin::signed(2), ASSET),
+            Error::<Test>::CouponsOutstanding
+        );
+
+        assert_ok!(CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET));
+        assert_ok!(CladToken::distribute_coupon(RuntimeOrigin::signed(5), ASSET));
+        assert_noop!(
+            CladToken::redeem_at_maturity(RuntimeOrigin::signed(2), ASSET),
+            Error::<Test>::InsufficientRedemptionFunds
+        );
+        assert_noop!(
+            CladToken::redeem_at_maturity(RuntimeOrigin::signed(1), ASSET),
+            Error::<Test>::InsufficientRedemptionFunds
+        );
+    });
+}
+
+// ============================================================================
+// Snapshot Tests
+// ============================================================================
+
+/// Tests that only the admin can take snapshots and that ids count up from one.
+#[test]
+fn snapshot_assigns_increasing_ids() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::snapshot(RuntimeOrigin::signed(2), ASSET),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::snapshot(RuntimeOrigin::signed(1), ASSET + 1),
+            Error::<Test>::UnknownInstrument
+        );
+        assert_eq!(CladToken::current_snapshot_id(ASSET), 0);
+
+        assert_ok!(CladToken::snapshot(RuntimeOrigin::signed(1), ASSET));
+        System::assert_last_event(Event::SnapshotTaken { asset_id: ASSET, snapshot_id: 1 }.into());
+        assert_ok!(CladToken::snapshot(RuntimeOrigin::signed(1), ASSET));
+        assert_eq!(CladToken::current_snapshot_id(ASSET), 2);
+    });
+}
+
+/// Tests that transfers, mints and burns after a snapshot leave the
+/// recorded balances and supply unchanged.
+#[test]
+fn balance_at_returns_balances_at_the_record_date() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(CladToken::balance_at(ASSET, &2, 1), None);
+        assert_ok!(CladToken::snapshot(RuntimeOrigin::signed(1), ASSET));
+        // Unchanged balances read through to the live value
+        assert_eq!(CladToken::balance_at(ASSET, &2, 1), Some(1_000_000));
+
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 50_000));
+        assert_ok!(CladToken::burn(RuntimeOrigin::signed(1), ASSET, 3, 20_000));
+
+        assert_eq!(CladToken::balance_at(ASSET, &2, 1), Some(1_000_000));
+        assert_eq!(CladToken::balance_at(ASSET, &3, 1), Some(500_000));
+        assert_eq!(CladToken::total_supply_at(ASSET, 1), Some(1_500_000));
+        assert_eq!(CladToken::balance_of(ASSET, &2), 950_000);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 580_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_530_000);
+        assert_eq!(CladToken::balance_at(ASSET, &2, 2), None);
+    });
+}
+
+/// Tests that each of several snapshots reports the balance of its own
+/// record date.
+#[test]
+fn balance_at_distinguishes_snapshots() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::snapshot(RuntimeOrigin::signed(1), ASSET));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100_000));
+        // No change between snapshots 2 and 3
+        assert_ok!(CladToken::snapshot(RuntimeOrigin::signed(1), ASSET));
+        assert_ok!(CladToken::snapshot(RuntimeOrigin::signed(1), ASSET));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 100_000));
+        assert_ok!(CladToken::burn(RuntimeOrigin::signed(1), ASSET, 3, 200_000));
+
+        assert_eq!(CladToken::balance_at(ASSET, &2, 1), Some(1_000_000));
+        assert_eq!(CladToken::balance_at(ASSET, &2, 2), Some(900_000));
+        assert_eq!(CladToken::balance_at(ASSET, &2, 3), Some(900_000));
+        assert_eq!(CladToken::balance_at(ASSET, &3, 3), Some(600_000));
+        assert_eq!(CladToken::total_supply_at(ASSET, 1), Some(1_500_000));
+        assert_eq!(CladToken::total_supply_at(ASSET, 3), Some(1_500_000));
+        assert_eq!(CladToken::total_supply(ASSET), 1_300_000);
+        // Accounts that never held tokens had nothing at any snapshot
+        assert_eq!(CladToken::balance_at(ASSET, &9, 2), Some(0));
+    });
+}
+
+// ============================================================================
+// Dividend Tests
+// ============================================================================
+
+/// Fund the admin, snapshot the genesis holders plus account 4 (100,000 tokens)
+/// and declare a dividend of `amount` claimable until block 100.
+///
+/// Supply at the snapshot is 1,600,000: account 2 holds 62.5%, account 3
+/// 31.25% and account 4 6.25%.
+fn setup_dividend(amount: u128) {
+    System::set_block_number(1);
+    Balances::set_balance(&1, 10_000_000);
+    assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 4, None, None));
+    assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 4, 100_000));
+    assert_ok!(CladToken::snapshot(RuntimeOrigin::signed(1), ASSET));
+    assert_ok!(CladToken::declare_dividend(RuntimeOrigin::signed(1), ASSET, 1, amount, 100, 1));
+}
+
+/// Tests that declaring a dividend requires a snapshot and moves the funds
+/// into the dividend pot.
+#[test]
+fn declare_dividend_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::set_balance(&1, 10_000_000);
+        assert_noop!(
+            CladToken::declare_dividend(RuntimeOrigin::signed(1), ASSET, 1, 150_000, 100, 1),
+            Error::<Test>::UnknownSnapshot
+        );
+        assert_ok!(CladToken::snapshot(RuntimeOrigin::signed(1), ASSET));
+        assert_noop!(
+            CladToken::declare_dividend(RuntimeOrigin::signed(2), ASSET, 1, 150_000, 100, 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        // A signed admin cannot draw on another account
+        assert_noop!(
+            CladToken::declare_dividend(RuntimeOrigin::signed(1), ASSET, 1, 150_000, 100, 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::declare_dividend(RuntimeOrigin::signed(1), ASSET, 1, 0, 100, 1),
+            Error::<Test>::InvalidDividend
+        );
+        assert_noop!(
+            CladToken::declare_dividend(RuntimeOrigin::signed(1), ASSET, 1, 150_000, 1, 1),
+            Error::<Test>::InvalidDividend
+        );
+
+        assert_ok!(CladToken::declare_dividend(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            1,
+            150_000,
+            100,
+            1
+        ));
+        System::assert_last_event(
+            Event::DividendDeclared {
+                asset_id: ASSET,
+                dividend_id: 0,
+                issuer: 1,
+                snapshot_id: 1,
+                amount: 150_000,
+                supply: 1_500_000,
+                deadline: 100,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(CladToken::dividend_pot(ASSET, 0)), 150_000);
+        assert_eq!(Balances::free_balance(1), 9_850_000);
+        assert_eq!(CladToken::next_dividend_id(ASSET), 1);
+    });
+}
+
+/// Tests that an admin origin without a signer cannot fund a dividend from an
+/// unrelated account.
+#[test]
+fn declare_dividend_rejects_unsigned_admin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::set_balance(&2, 10_000_000);
+        assert_ok!(CladToken::snapshot(RuntimeOrigin::root(), ASSET));
+
+        assert_noop!(
+            CladToken::declare_dividend(RuntimeOrigin::root(), ASSET, 1, 150_000, 100, 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_eq!(Balances::free_balance(2), 10_000_000);
+        assert_eq!(Balances::free_balance(CladToken::dividend_pot(ASSET, 0)), 0);
+        assert_eq!(CladToken::next_dividend_id(ASSET), 0);
+    });
+}
+
+/// Tests that holders claim their share of the snapshot balance, once, until
+/// the deadline.
+#[test]
+fn claim_dividend_pays_share_at_snapshot() {
+    new_test_ext().execute_with(|| {
+        setup_dividend(160_000);
+        // Moving tokens after the record date does not change entitlements
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 500_000));
+
+        assert_ok!(CladToken::claim_dividend(RuntimeOrigin::signed(2), ASSET, 0));
+        System::assert_last_event(
+            Event::DividendPaid { asset_id: ASSET, dividend_id: 0, account: 2, amount: 100_000 }
+                .into(),
+        );
+        assert_ok!(CladToken::claim_dividend(RuntimeOrigin::signed(3), ASSET, 0));
+        assert_eq!(Balances::free_balance(2), 100_000);
+        assert_eq!(Balances::free_balance(3), 50_000);
+
+        assert_noop!(
+            CladToken::claim_dividend(RuntimeOrigin::signed(2), ASSET, 0),
+            Error::<Test>::DividendAlreadyClaimed
+        );
+        assert_noop!(
+            CladToken::claim_dividend(RuntimeOrigin::signed(5), ASSET, 0),
+            Error::<Test>::NoDividendShare
+        );
+        assert_noop!(
+            CladToken::claim_dividend(RuntimeOrigin::signed(2), ASSET, 1),
+            Error::<Test>::UnknownDividend
+        );
+
+        System::set_block_number(101);
+        assert_noop!(
+            CladToken::claim_dividend(RuntimeOrigin::signed(4), ASSET, 0),
+            Error::<Test>::DividendExpired
+        );
+        assert_eq!(CladToken::dividend(ASSET, 0).unwrap().paid, 150_000);
+    });
+}
+
+/// Tests that pushing a dividend pages through holders and escrows the share
+/// of a frozen holder until it is unfrozen.
+#[test]
+fn distribute_dividend_pays_holders_and_escrows_frozen() {
+    new_test_ext().execute_with(|| {
+        setup_dividend(160_000);
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 3));
+
+        // MaxHoldersPerPage = 2, so three holders take two calls
+        assert_ok!(CladToken::distribute_dividend(RuntimeOrigin::signed(5), ASSET, 0));
+        assert!(!CladToken::dividend(ASSET, 0).unwrap().distributed);
+        assert_ok!(CladToken::distribute_dividend(RuntimeOrigin::signed(5), ASSET, 0));
+        System::assert_has_event(
+            Event::DividendEscrowed { asset_id: ASSET, dividend_id: 0, account: 3, amount: 50_000 }
+                .into(),
+        );
+        System::assert_last_event(
+            Event::DividendDistributed {
+                asset_id: ASSET,
+                dividend_id: 0,
+                paid: 110_000,
+                escrowed: 50_000,
+            }
+            .into(),
+        );
+        assert_noop!(
+            CladToken::distribute_dividend(RuntimeOrigin::signed(5), ASSET, 0),
+            Error::<Test>::DividendAlreadyDistributed
+        );
+        assert_eq!(Balances::free_balance(2), 100_000);
+        assert_eq!(Balances::free_balance(4), 10_000);
+        assert_eq!(Balances::free_balance(3), 0);
+
+        assert_noop!(
+            CladToken::claim_dividend(RuntimeOrigin::signed(3), ASSET, 0),
+            Error::<Test>::AccountFrozen
+        );
+        // Escrowed shares survive the deadline
+        System::set_block_number(101);
+        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), ASSET, 3));
+        assert_ok!(CladToken::claim_dividend(RuntimeOrigin::signed(3), ASSET, 0));
+        assert_eq!(Balances::free_balance(3), 50_000);
+        assert_eq!(CladToken::dividend(ASSET, 0).unwrap().escrowed, 0);
+    });
+}
+
+/// Tests that the unclaimed remainder returns to the issuer after the
+/// deadline, while escrowed shares stay in the pot.
+#[test]
+fn reclaim_dividend_returns_unclaimed_funds() {
+    new_test_ext().execute_with(|| {
+        setup_dividend(160_000);
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 3));
+        assert_ok!(CladToken::claim_dividend(RuntimeOrigin::signed(2), ASSET, 0));
+        assert_noop!(
+            CladToken::reclaim_dividend(RuntimeOrigin::signed(5), ASSET, 0),
+            Error::<Test>::DividendNotExpired
+        );
+
+        System::set_block_number(101);
+        assert_noop!(
+            CladToken::reclaim_dividend(RuntimeOrigin::signed(5), ASSET, 0),
+            Error::<Test>::DividendDistributionIncomplete
+        );
+        // After the deadline the walk only escrows frozen holders
+        assert_ok!(CladToken::distribute_dividend(RuntimeOrigin::signed(5), ASSET, 0));
+        assert_ok!(CladToken::distribute_dividend(RuntimeOrigin::signed(5), ASSET, 0));
+        assert_eq!(Balances::free_balance(4), 0);
+
+        assert_ok!(CladToken::reclaim_dividend(RuntimeOrigin::signed(5), ASSET, 0));
+        System::assert_last_event(
+            Event::DividendReclaimed { asset_id: ASSET, dividend_id: 0, issuer: 1, amount: 10_000 }
+                .into(),
+        );
+        assert_eq!(Balances::free_balance(1), 9_850_000);
+        assert_eq!(Balances::free_balance(CladToken::dividend_pot(ASSET, 0)), 50_000);
+        assert_noop!(
+            CladToken::reclaim_dividend(RuntimeOrigin::signed(5), ASSET, 0),
+            Error::<Test>::DividendAlreadyReclaimed
+        );
+        assert_noop!(
+            CladToken::claim_dividend(RuntimeOrigin::signed(4), ASSET, 0),
+            Error::<Test>::DividendExpired
+        );
+    });
+}
+
+// ============================================================================
+// Split Tests
+// ============================================================================
+
+/// Tests that a split rescales every balance and the supply, emitting the
+/// corporate-action events.
+#[test]
+fn split_rescales_balances_and_supply() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        // 3-for-2 split
+        assert_ok!(CladToken::split(RuntimeOrigin::signed(1), ASSET, 3, 2));
+
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_500_000);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 750_000);
+        assert_eq!(CladToken::total_supply(ASSET), 2_250_000);
+        assert!(CladToken::split_in_progress(ASSET).is_none());
+        System::assert_has_event(
+            Event::SplitStarted { asset_id: ASSET, numerator: 3, denominator: 2 }.into(),
+        );
+        System::assert_has_event(
+            Event::BalanceSplit {
+                asset_id: ASSET,
+                account: 3,
+                old_balance: 500_000,
+                new_balance: 750_000,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::SplitCompleted {
+                asset_id: ASSET,
+                numerator: 3,
+                denominator: 2,
+                old_supply: 1_500_000,
+                new_supply: 2_250_000,
+            }
+            .into(),
+        );
+    });
+}
+
+/// Tests that a reverse split rounds every balance down, drops holders left
+/// with nothing, and takes the rounded balances as the new supply.
+#[test]
+fn reverse_split_rounds_down() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 4, None, None));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 4, 999));
+        assert_ok!(CladToken::snapshot(RuntimeOrigin::signed(1), ASSET));
+        assert_eq!(CladToken::holder_count(ASSET), 3);
+
+        // 1-for-1000; MaxHoldersPerPage = 2, so three holders take two calls
+        assert_ok!(CladToken::split(RuntimeOrigin::signed(1), ASSET, 1, 1_000));
+        assert!(CladToken::split_in_progress(ASSET).is_some());
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(2), ASSET, 3, 1),
+            Error::<Test>::SplitInProgress
+        );
+        assert_noop!(
+            CladToken::split(RuntimeOrigin::signed(1), ASSET, 2, 1),
+            Error::<Test>::SplitInProgress
+        );
+        assert_ok!(CladToken::continue_split(RuntimeOrigin::signed(5), ASSET));
+
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 500);
+        assert_eq!(CladToken::balance_of(ASSET, &4), 0);
+        // 1,500,999 old tokens become 1,500 new ones; the fractions are dropped
+        assert_eq!(CladToken::total_supply(ASSET), 1_500);
+        assert_eq!(CladToken::holder_count(ASSET), 2);
+        // The record date keeps pre-split amounts
+        assert_eq!(CladToken::balance_at(ASSET, &4, 1), Some(999));
+        assert_eq!(CladToken::total_supply_at(ASSET, 1), Some(1_500_999));
+        assert_noop!(
+            CladToken::continue_split(RuntimeOrigin::signed(5), ASSET),
+            Error::<Test>::NoSplitInProgress
+        );
+    });
+}
+
+/// Tests that partial freezes and vesting locks are rescaled with balances.
+#[test]
+fn split_rescales_frozen_and_vesting_amounts() {
+    new_test_ext().execute_with(|| {
+        setup_vesting();
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 3, 100_001));
+
+        assert_ok!(CladToken::split(RuntimeOrigin::signed(1), ASSET, 3, 2));
+        assert_noop!(
+            CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 3, 1),
+            Error::<Test>::SplitInProgress
+        );
+        assert_ok!(CladToken::continue_split(RuntimeOrigin::signed(5), ASSET));
+
+        assert_eq!(CladToken::frozen_amount(ASSET, &3), 150_001);
+        assert_eq!(CladToken::balance_of(ASSET, &5), 1_500);
+        assert_eq!(CladToken::vesting(ASSET, &5)[0].amount, 1_500);
+    });
+}
+
+/// Tests that absolute holding limits are rescaled with balances, including the
+/// limit of an account that never held the instrument, without making it a
+/// holder.
+#[test]
+fn split_rescales_absolute_holding_limits() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::set_max_balance_per_holder(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            Some(HoldingLimit::Absolute(1_000_000))
+        ));
+        assert_ok!(CladToken::set_account_holding_limit(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            3,
+            Some(HoldingLimit::Absolute(600_000))
+        ));
+        assert_ok!(CladToken::set_account_holding_limit(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            6,
+            Some(HoldingLimit::Absolute(1_001))
+        ));
+        assert!(!crate::Balances::<Test>::contains_key(ASSET, 6));
+
+        // Holders 2 and 3, then the overrides of 3 and 6, take two calls
+        assert_ok!(CladToken::split(RuntimeOrigin::signed(1), ASSET, 3, 2));
+        assert_eq!(CladToken::balance_of(ASSET, &3), 750_000);
+        assert_eq!(
+            CladToken::account_holding_limit(ASSET, &3),
+            Some(HoldingLimit::Absolute(600_000))
+        );
+        assert_noop!(
+            CladToken::set_account_holding_limit(RuntimeOrigin::signed(1), ASSET, 6, None),
+            Error::<Test>::SplitInProgress
+        );
+        assert_ok!(CladToken::continue_split(RuntimeOrigin::signed(5), ASSET));
+
+        assert_eq!(
+            CladToken::max_balance_per_holder(ASSET),
+            Some(HoldingLimit::Absolute(1_500_000))
+        );
+        assert_eq!(
+            CladToken::account_holding_limit(ASSET, &3),
+            Some(HoldingLimit::Absolute(900_000))
+        );
+        assert_eq!(
+            CladToken::account_holding_limit(ASSET, &6),
+            Some(HoldingLimit::Absolute(1_501))
+        );
+        assert!(!crate::Balances::<Test>::contains_key(ASSET, 6));
+        assert_eq!(CladToken::split_in_progress(ASSET), None);
+        // Account 2 is still exactly at its limit
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_500_000);
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(3), ASSET, 2, 1),
+            Error::<Test>::HoldingLimitExceeded
+        );
+    });
+}
+
+/// Tests that a split waits until no large mint of the instrument is queued.
+#[test]
+fn split_rejected_while_mints_pending() {
+    new_test_ext().execute_with(|| {
+        setup_time_lock();
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 3, 5_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 5_000));
+        assert_eq!(CladToken::pending_mint_count(ASSET), 2);
+
+        assert_noop!(
+            CladToken::split(RuntimeOrigin::signed(1), ASSET, 2, 1),
+            Error::<Test>::MintsPending
+        );
+        assert_ok!(CladToken::cancel_mint(RuntimeOrigin::signed(AuditOffice::get()), 0));
+        assert_noop!(
+            CladToken::split(RuntimeOrigin::signed(1), ASSET, 2, 1),
+            Error::<Test>::MintsPending
+        );
+
+        initialize_block(11);
+        assert_eq!(CladToken::pending_mint_count(ASSET), 0);
+        assert_ok!(CladToken::split(RuntimeOrigin::signed(1), ASSET, 2, 1));
+        assert_eq!(CladToken::balance_of(ASSET, &2), 2_010_000);
+    });
+}
+
+/// Tests that splits need the admin and a usable ratio.
+#[test]
+fn split_rejects_invalid_ratios() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::split(RuntimeOrigin::signed(2), ASSET, 2, 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        for (numerator, denominator) in [(0, 1), (1, 0), (5, 5)] {
+            assert_noop!(
+                CladToken::split(RuntimeOrigin::signed(1), ASSET, numerator, denominator),
+                Error::<Test>::InvalidSplitRatio
+            );
+        }
+        assert_noop!(
+            CladToken::continue_split(RuntimeOrigin::signed(5), ASSET),
+            Error::<Test>::NoSplitInProgress
+        );
+    });
+}
+
+/// Tests that an instrument with bond terms cannot be split, so coupons and
+/// redemptions keep paying the face value per token.
+#[test]
+fn split_rejects_bonds() {
+    new_test_ext().execute_with(|| {
+        setup_bond(0);
+        assert_noop!(
+            CladToken::split(RuntimeOrigin::signed(1), ASSET, 2, 1),
+            Error::<Test>::BondNotSplittable
+        );
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_000);
+    });
+}
+
+// ============================================================================
+// Account Recovery Tests
+// ============================================================================
+
+/// Tests that recovery moves the whole position and blocks the lost address.
+#[test]
+fn recover_account_moves_position() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::add_to_whitelist(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            3,
+            None,
+            Some(398)
+        ));
+        assert_ok!(CladToken::freeze_partial(RuntimeOrigin::signed(1), ASSET, 3, 100_000));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 3));
+        assert_ok!(CladToken::set_account_holding_limit(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            3,
+            Some(HoldingLimit::Absolute(600_000))
+        ));
+
+        assert_ok!(CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 3, 7));
+        System::assert_last_event(
+            Event::Recovered { asset_id: ASSET, lost: 3, new: 7, amount: 500_000 }.into(),
+        );
+
+        assert_eq!(CladToken::balance_of(ASSET, &3), 0);
+        assert_eq!(CladToken::balance_of(ASSET, &7), 500_000);
+        assert_eq!(CladToken::is_frozen(ASSET, &7), true);
+        assert_eq!(CladToken::is_frozen(ASSET, &3), false);
+        assert_eq!(CladToken::frozen_amount(ASSET, &7), 100_000);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &7), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &3), false);
+        assert_eq!(CladToken::investor_country(ASSET, &7), Some(398));
+        assert_eq!(
+            CladToken::account_holding_limit(ASSET, &7),
+            Some(HoldingLimit::Absolute(600_000))
+        );
+        assert_eq!(CladToken::holders_per_country(ASSET, 398), 1);
+        assert_eq!(CladToken::holder_count(ASSET), 2);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_000);
+        assert_eq!(CladToken::recovered_to(ASSET, &3), Some(7));
+
+        // The lost address is blocked for good
+        assert_noop!(
+            CladToken::add_to_whitelist(RuntimeOrigin::signed(1), ASSET, 3, None, None),
+            Error::<Test>::AccountRecovered
+        );
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 3, 100),
+            Error::<Test>::AccountRecovered
+        );
+        assert_noop!(
+            CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 3, 8),
+            Error::<Test>::AccountRecovered
+        );
+    });
+}
+
+/// Tests that no mint path credits a recovered address, while a mint queued
+/// before the recovery executes to the new address.
+#[test]
+fn recovered_account_cannot_be_minted_to() {
+    new_test_ext().execute_with(|| {
+        setup_time_lock();
+        // Queued before the recovery, due at block 11
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 3, 5_000));
+        assert_ok!(CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 3, 7));
+
+        let mints = vec![(2, 100), (3, 100)].try_into().unwrap();
+        assert_noop!(
+            CladToken::batch_mint(RuntimeOrigin::signed(1), ASSET, mints),
+            Error::<Test>::AccountRecovered
+        );
+        // Large mints are rejected before they are queued
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 3, 5_000),
+            Error::<Test>::AccountRecovered
+        );
+
+        // The mint queued before the recovery follows the position
+        initialize_block(11);
+        System::assert_last_event(Event::PendingMintExecuted { id: 0 }.into());
+        assert_eq!(CladToken::balance_of(ASSET, &3), 0);
+        assert_eq!(CladToken::balance_of(ASSET, &7), 505_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_505_000);
+    });
+}
+
+/// Tests that a pushed dividend share earned before a recovery is paid to the
+/// new address.
+#[test]
+fn distribute_dividend_pays_recovered_share_to_new_address() {
+    new_test_ext().execute_with(|| {
+        setup_dividend(160_000);
+        assert_ok!(CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 3, 7));
+
+        // Holders 2, 3, 4 and 7 take two calls
+        assert_ok!(CladToken::distribute_dividend(RuntimeOrigin::signed(5), ASSET, 0));
+        assert_ok!(CladToken::distribute_dividend(RuntimeOrigin::signed(5), ASSET, 0));
+        System::assert_has_event(
+            Event::DividendPaid { asset_id: ASSET, dividend_id: 0, account: 7, amount: 50_000 }
+                .into(),
+        );
+        assert!(CladToken::dividend(ASSET, 0).unwrap().distributed);
+        assert_eq!(Balances::free_balance(3), 0);
+        assert_eq!(Balances::free_balance(7), 50_000);
+        assert_noop!(
+            CladToken::claim_dividend(RuntimeOrigin::signed(3), ASSET, 0),
+            Error::<Test>::AccountRecovered
+        );
+    });
+}
+
+/// Tests that the new address claims the lost address's share, including one
+/// escrowed while it was frozen.
+#[test]
+fn recovered_share_is_claimed_by_new_address() {
+    new_test_ext().execute_with(|| {
+        setup_dividend(160_000);
+        // Second dividend on the same snapshot, claimed rather than pushed
+        assert_ok!(CladToken::declare_dividend(RuntimeOrigin::signed(1), ASSET, 1, 16_000, 100, 1));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 3));
+        assert_ok!(CladToken::distribute_dividend(RuntimeOrigin::signed(5), ASSET, 0));
+        assert_ok!(CladToken::distribute_dividend(RuntimeOrigin::signed(5), ASSET, 0));
+        assert_eq!(CladToken::dividend(ASSET, 0).unwrap().escrowed, 50_000);
+
+        assert_ok!(CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 3, 7));
+        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(1), ASSET, 7));
+        assert_noop!(
+            CladToken::claim_dividend(RuntimeOrigin::signed(3), ASSET, 0),
+            Error::<Test>::AccountRecovered
+        );
+
+        assert_ok!(CladToken::claim_dividend(RuntimeOrigin::signed(7), ASSET, 0));
+        System::assert_last_event(
+            Event::DividendPaid { asset_id: ASSET, dividend_id: 0, account: 7, amount: 50_000 }
+                .into(),
+        );
+        assert_eq!(CladToken::dividend(ASSET, 0).unwrap().escrowed, 0);
+        assert_ok!(CladToken::claim_dividend(RuntimeOrigin::signed(7), ASSET, 1));
+        assert_eq!(Balances::free_balance(7), 55_000);
+        assert_noop!(
+            CladToken::claim_dividend(RuntimeOrigin::signed(7), ASSET, 1),
+            Error::<Test>::NoDividendShare
+        );
+    });
+}
+
+/// Tests that a recovery target cannot take over a second position.
+#[test]
+fn recovery_target_cannot_be_reused() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 3, 7));
+        assert_ok!(CladToken::transfer(RuntimeOrigin::signed(7), ASSET, 2, 500_000));
+        assert_noop!(
+            CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 2, 7),
+            Error::<Test>::RecoveryTargetInUse
+        );
+    });
+}
+
+/// Tests that vesting locks follow the recovered position.
+#[test]
+fn recover_account_moves_vesting_locks() {
+    new_test_ext().execute_with(|| {
+        setup_vesting();
+        assert_ok!(CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 5, 6));
+
+        assert_eq!(CladToken::vesting(ASSET, &6).len(), 1);
+        assert!(CladToken::vesting(ASSET, &5).is_empty());
+        // Still locked before the cliff
+        assert_noop!(
+            CladToken::transfer(RuntimeOrigin::signed(6), ASSET, 2, 1),
+            Error::<Test>::InsufficientVestedBalance
+        );
+    });
+}
+
+/// Tests that recovery needs the admin, a fresh target and its claims.
+#[test]
+fn recover_account_rejects_invalid_targets() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CladToken::recover_account(RuntimeOrigin::signed(2), ASSET, 2, 7),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 2, 3),
+            Error::<Test>::RecoveryTargetInUse
+        );
+        assert_noop!(
+            CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 2, 2),
+            Error::<Test>::RecoveryTargetInUse
+        );
+
+        setup_identity();
+        assert_noop!(
+            CladToken::recover_account(RuntimeOrigin::signed(1), ASSET, 2, 7),
+            Error::<Test>::MissingClaim
+        );
+    });
+}
+
+// ============================================================================
+// Role Tests
+// ============================================================================
+
+/// Tests that the admin holds every role from genesis, acts only through those
+/// roles, and that the admin origin only manages role admins.
+#[test]
+fn admin_origin_does_not_pass_role_checks() {
+    new_test_ext().execute_with(|| {
+        for role in Role::ALL {
+            assert!(CladToken::has_role(role, 1));
+        }
+
+        // Without its minter role the admin cannot mint
+        assert_ok!(CladToken::revoke_role(RuntimeOrigin::signed(1), Role::Minter, 1));
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 100),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // Without its role admin role the admin can only manage role admins
+        assert_ok!(CladToken::revoke_role(RuntimeOrigin::signed(1), Role::RoleAdmin, 1));
+        assert_noop!(
+            CladToken::grant_role(RuntimeOrigin::signed(1), Role::Minter, 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(1), Role::RoleAdmin, 30));
+
+        // Only the admin appoints and removes role admins
+        assert_noop!(
+            CladToken::grant_role(RuntimeOrigin::signed(30), Role::RoleAdmin, 31),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(CladToken::revoke_role(RuntimeOrigin::signed(1), Role::RoleAdmin, 30));
+        assert_noop!(
+            CladToken::grant_role(RuntimeOrigin::signed(30), Role::Minter, 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+/// Tests that granted roles open exactly their own calls.
+#[test]
+fn roles_gate_their_calls() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(1), Role::RoleAdmin, 30));
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(30), Role::Minter, 20));
+        System::assert_last_event(Event::RoleGranted { role: Role::Minter, account: 20 }.into());
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(30), Role::Freezer, 21));
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(30), Role::ComplianceAgent, 22));
+
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(20), ASSET, 2, 100));
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(21), ASSET, 3));
+        assert_ok!(CladToken::add_to_whitelist(RuntimeOrigin::signed(22), ASSET, 4, None, None));
+
+        // A minter cannot freeze, a freezer cannot whitelist, an agent cannot mint
+        assert_noop!(
+            CladToken::freeze(RuntimeOrigin::signed(20), ASSET, 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::add_to_whitelist(RuntimeOrigin::signed(21), ASSET, 5, None, None),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(22), ASSET, 2, 100),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        // Roles never open admin-only calls
+        assert_noop!(
+            CladToken::pause(RuntimeOrigin::signed(20), ASSET),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+/// Tests that a role admin can delegate, and that revoking takes effect at once.
+#[test]
+fn role_admin_grants_and_revokes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            CladToken::grant_role(RuntimeOrigin::signed(30), Role::Minter, 20),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(1), Role::RoleAdmin, 30));
+
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(30), Role::Minter, 20));
+        assert_noop!(
+            CladToken::grant_role(RuntimeOrigin::signed(30), Role::Minter, 20),
+            Error::<Test>::RoleAlreadyGranted
+        );
+        assert_ok!(CladToken::burn(RuntimeOrigin::signed(20), ASSET, 2, 100));
+
+        assert_ok!(CladToken::revoke_role(RuntimeOrigin::signed(30), Role::Minter, 20));
+        System::assert_last_event(Event::RoleRevoked { role: Role::Minter, account: 20 }.into());
+        assert_noop!(
+            CladToken::revoke_role(RuntimeOrigin::signed(30), Role::Minter, 20),
+            Error::<Test>::RoleNotGranted
+        );
+        assert_noop!(
+            CladToken::burn(RuntimeOrigin::signed(20), ASSET, 2, 100),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+/// Tests that rotating the admin leaves every role with the account it was granted to.
+#[test]
+fn set_admin_leaves_roles() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::set_admin(RuntimeOrigin::signed(1), 50));
+
+        for role in Role::ALL {
+            assert!(CladToken::has_role(role, 1));
+            assert!(!CladToken::has_role(role, 50));
+        }
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::CladToken(Event::RoleRevoked { .. } | Event::RoleGranted { .. })
+        )));
+    });
+}
+
+// ============================================================================
+// Time-Locked Mint Tests
+// ============================================================================
+
+/// Set the time-lock threshold to 1_000 tokens and start at block 1.
+fn setup_time_lock() {
+    System::set_block_number(1);
+    LargeMintThreshold::set(1_000);
+}
+
+/// Run the `on_initialize` hook of block `n`.
+fn initialize_block(n: u64) {
+    use frame_support::traits::Hooks;
+    System::set_block_number(n);
+    CladToken::on_initialize(n);
+}
+
+/// Tests that mints below the threshold still execute immediately.
+#[test]
+fn small_mint_executes_immediately() {
+    new_test_ext().execute_with(|| {
+        setup_time_lock();
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 999));
+
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_999);
+        assert_eq!(CladToken::pending_mint(0), None);
+    });
+}
+
+/// Tests that a large mint waits out the delay and then executes.
+#[test]
+fn large_mint_executes_after_delay() {
+    new_test_ext().execute_with(|| {
+        setup_time_lock();
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 1_000));
+        System::assert_last_event(
+            Event::MintScheduled { id: 0, asset_id: ASSET, to: 2, amount: 1_000, execute_at: 11 }
+                .into(),
+        );
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_000);
+
+        initialize_block(10);
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_000);
+
+        initialize_block(11);
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_001_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_501_000);
+        assert_eq!(CladToken::pending_mint(0), None);
+        System::assert_has_event(Event::Minted { asset_id: ASSET, to: 2, amount: 1_000 }.into());
+        System::assert_last_event(Event::PendingMintExecuted { id: 0 }.into());
+    });
+}
+
+/// Tests that a time-locked mint with a schedule records the lock-up on execution.
+#[test]
+fn large_mint_with_schedule_locks_on_execution() {
+    new_test_ext().execute_with(|| {
+        setup_time_lock();
+        assert_ok!(CladToken::mint_with_schedule(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            5,
+            5_000,
+            schedule()
+        ));
+        assert!(CladToken::vesting(ASSET, &5).is_empty());
+
+        initialize_block(11);
+        assert_eq!(CladToken::balance_of(ASSET, &5), 5_000);
+        assert_eq!(CladToken::vesting_balance(ASSET, &5), (5_000, 0));
+    });
+}
+
+/// Tests that only the veto origin can cancel, and that a cancelled mint never runs.
+#[test]
+fn veto_origin_cancels_pending_mint() {
+    new_test_ext().execute_with(|| {
+        setup_time_lock();
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 50_000));
+
+        // The admin is not the veto origin
+        assert_noop!(
+            CladToken::cancel_mint(RuntimeOrigin::signed(1), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(CladToken::cancel_mint(RuntimeOrigin::signed(AuditOffice::get()), 0));
+        System::assert_last_event(Event::MintCancelled { id: 0 }.into());
+        assert_noop!(
+            CladToken::cancel_mint(RuntimeOrigin::signed(AuditOffice::get()), 0),
+            Error::<Test>::UnknownPendingMint
+        );
+
+        initialize_block(11);
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_000);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_000);
+    });
+}
+
+/// Tests that a due mint failing its checks is dropped without blocking the others.
+#[test]
+fn failing_pending_mint_is_dropped() {
+    new_test_ext().execute_with(|| {
+        setup_time_lock();
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 5, 1_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 1_000));
+        // The queue of block 11 is full
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 3, 1_000),
+            Error::<Test>::MintQueueFull
+        );
+        // Account 5 would be a third holder
+        assert_ok!(CladToken::set_max_holders(RuntimeOrigin::signed(1), ASSET, Some(2)));
+
+        initialize_block(11);
+        System::assert_has_event(
+            Event::PendingMintFailed { id: 0, error: Error::<Test>::TooManyHolders.into() }.into(),
+        );
+        System::assert_last_event(Event::PendingMintExecuted { id: 1 }.into());
+        assert_eq!(CladToken::balance_of(ASSET, &5), 0);
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_001_000);
+        assert_eq!(CladToken::pending_mint(0), None);
+    });
+}
+
+/// Tests that splitting a large issuance into small mints and batch entries does
+/// not avoid the time lock.
+#[test]
+fn split_up_large_mint_is_time_locked() {
+    new_test_ext().execute_with(|| {
+        setup_time_lock();
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 400));
+        assert_ok!(CladToken::mint_with_schedule(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            3,
+            400,
+            schedule()
+        ));
+        let mints = vec![(4, 100), (5, 100)].try_into().unwrap();
+        assert_ok!(CladToken::batch_mint(RuntimeOrigin::signed(1), ASSET, mints));
+
+        // The last entry takes the window to the threshold
+        assert_eq!(CladToken::balance_of(ASSET, &4), 100);
+        assert_eq!(CladToken::balance_of(ASSET, &5), 0);
+        System::assert_last_event(
+            Event::MintScheduled { id: 0, asset_id: ASSET, to: 5, amount: 100, execute_at: 11 }
+                .into(),
+        );
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 100));
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_400);
+        assert_eq!(CladToken::pending_mint(1).map(|pending| pending.amount), Some(100));
+
+        // A new window starts once MintDelay blocks have passed
+        initialize_block(11);
+        assert_eq!(CladToken::balance_of(ASSET, &5), 100);
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 400));
+        assert_eq!(CladToken::balance_of(ASSET, &2), 1_000_900);
+        assert_eq!(CladToken::recent_mints(ASSET).map(|window| window.minted), Some(400));
+    });
+}
+
+// ============================================================================
+// Supply Cap Tests
+// ============================================================================
+
+/// Creates a second instrument authorised for 10_000 tokens and returns its id.
+fn create_capped_instrument() -> AssetId {
+    let asset_id = CladToken::next_asset_id();
+    assert_ok!(CladToken::create_instrument(
+        RuntimeOrigin::signed(1),
+        bounded(b"Capped Bond 2035"),
+        bounded(b"CB35"),
+        6,
+        Some(10_000),
+    ));
+    asset_id
+}
+
+/// Tests that mints stop at the cap set at creation, and that burns free room.
+#[test]
+fn mint_respects_max_supply() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = create_capped_instrument();
+        assert_eq!(CladToken::max_supply(asset_id), Some(10_000));
+        System::assert_has_event(
+            Event::InstrumentCreated {
+                asset_id,
+                symbol: bounded(b"CB35"),
+                decimals: 6,
+                max_supply: Some(10_000),
+            }
+            .into(),
+        );
+
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), asset_id, 1, 10_000));
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), asset_id, 1, 1),
+            Error::<Test>::SupplyCapExceeded
+        );
+
+        assert_ok!(CladToken::burn(RuntimeOrigin::signed(1), asset_id, 1, 400));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), asset_id, 1, 400));
+        assert_eq!(CladToken::total_supply(asset_id), 10_000);
+
+        // The genesis instrument is uncapped
+        assert_eq!(CladToken::max_supply(ASSET), None);
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 1_000_000));
+    });
+}
+
+/// Tests that only the supply cap origin can raise a cap, and only upwards.
+#[test]
+fn raise_max_supply_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = create_capped_instrument();
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), asset_id, 1, 10_000));
+
+        // The admin is not the supply cap origin
+        assert_noop!(
+            CladToken::raise_max_supply(RuntimeOrigin::signed(1), asset_id, 20_000),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::raise_max_supply(RuntimeOrigin::root(), asset_id, 10_000),
+            Error::<Test>::InvalidSupplyCap
+        );
+        assert_noop!(
+            CladToken::raise_max_supply(RuntimeOrigin::root(), 7, 20_000),
+            Error::<Test>::UnknownInstrument
+        );
+
+        assert_ok!(CladToken::raise_max_supply(RuntimeOrigin::root(), asset_id, 15_000));
+        System::assert_last_event(
+            Event::MaxSupplyRaised {
+                asset_id,
+                old_max_supply: Some(10_000),
+                new_max_supply: 15_000,
+            }
+            .into(),
+        );
+        assert_eq!(CladToken::max_supply(asset_id), Some(15_000));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(1), asset_id, 1, 5_000));
+    });
+}
+
+/// Tests that an uncapped instrument can be capped at no less than its supply.
+#[test]
+fn raise_max_supply_caps_uncapped_instrument() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            CladToken::raise_max_supply(RuntimeOrigin::root(), ASSET, 1_499_999),
+            Error::<Test>::InvalidSupplyCap
+        );
+
+        // Exactly the supply leaves no room to mint
+        assert_ok!(CladToken::raise_max_supply(RuntimeOrigin::root(), ASSET, 1_500_000));
+        System::assert_last_event(
+            Event::MaxSupplyRaised {
+                asset_id: ASSET,
+                old_max_supply: None,
+                new_max_supply: 1_500_000,
+            }
+            .into(),
+        );
+        assert_noop!(
+            CladToken::mint(RuntimeOrigin::signed(1), ASSET, 2, 1),
+            Error::<Test>::SupplyCapExceeded
+        );
+        // From now on the cap can only go up
+        assert_noop!(
+            CladToken::raise_max_supply(RuntimeOrigin::root(), ASSET, 1_500_000),
+            Error::<Test>::InvalidSupplyCap
+        );
+    });
+}
+
+/// Tests that a split rescales the cap, rounding down.
+#[test]
+fn split_rescales_max_supply() {
+    new_test_ext().execute_with(|| {
+        crate::MaxSupply::<Test>::insert(ASSET, 1_500_009);
+
+        assert_ok!(CladToken::split(RuntimeOrigin::signed(1), ASSET, 1, 10));
+        assert_eq!(CladToken::max_supply(ASSET), Some(150_000));
+        assert_eq!(CladToken::total_supply(ASSET), 150_000);
+
+        assert_ok!(CladToken::split(RuntimeOrigin::signed(1), ASSET, 3, 1));
+        assert_eq!(CladToken::max_supply(ASSET), Some(450_000));
+    });
+}
+
+// ============================================================================
+// Instrument Metadata Tests
+// ============================================================================
+
+/// Identifiers of a fixed-rate bond denominated in tenge.
+fn bond_metadata() -> InstrumentMetadata {
+    InstrumentMetadata {
+        isin: Some(*b"KZ2C00012345"),
+        cfi: Some(*b"DBFTFR"),
+        issuer_lei: Some(*b"5493001KJTIIGC8Y1R12"),
+        currency: Some(*b"KZT"),
+        prospectus_hash: Some([7; 32]),
+        prospectus_uri: bounded(b"ipfs://prospectus"),
+    }
+}
+
+/// Tests that set_metadata replaces name, symbol and identifiers but not decimals.
+#[test]
+fn set_metadata_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(CladToken::metadata(ASSET), None);
+
+        assert_ok!(CladToken::set_metadata(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            bounded(b"Test Bond 2030"),
+            bounded(b"TB30"),
+            bond_metadata(),
+        ));
+        assert_eq!(CladToken::token_name(ASSET), b"Test Bond 2030".to_vec());
+        assert_eq!(CladToken::token_symbol(ASSET), b"TB30".to_vec());
+        assert_eq!(CladToken::decimals(ASSET), 6);
+        assert_eq!(CladToken::metadata(ASSET), Some(bond_metadata()));
+        System::assert_last_event(
+            Event::MetadataUpdated {
+                asset_id: ASSET,
+                name: bounded(b"Test Bond 2030"),
+                symbol: bounded(b"TB30"),
+                metadata: bond_metadata(),
+            }
+            .into(),
+        );
+
+        // Omitted fields are cleared
+        let metadata = InstrumentMetadata { isin: Some(*b"KZ2C00012345"), ..Default::default() };
+        assert_ok!(CladToken::set_metadata(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            bounded(b"Test Bond 2030"),
+            bounded(b"TB30"),
+            metadata.clone(),
+        ));
+        assert_eq!(CladToken::metadata(ASSET), Some(metadata));
+    });
+}
+
+/// Tests that set_metadata checks the origin, the instrument and the code formats.
+#[test]
+fn set_metadata_fails_for_invalid_input() {
+    new_test_ext().execute_with(|| {
+        let set = |origin: RuntimeOrigin, asset_id: AssetId, metadata: InstrumentMetadata| {
+            CladToken::set_metadata(origin, asset_id, bounded(b"Bond"), bounded(b"B"), metadata)
+        };
+
+        assert_noop!(
+            set(RuntimeOrigin::signed(2), ASSET, bond_metadata()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            set(RuntimeOrigin::signed(1), 7, bond_metadata()),
+            Error::<Test>::UnknownInstrument
+        );
+
+        let malformed = [
+            // Numeric country prefix
+            InstrumentMetadata { isin: Some(*b"1Z2C00012345"), ..bond_metadata() },
+            // Lower-case CFI code
+            InstrumentMetadata { cfi: Some(*b"dbftfr"), ..bond_metadata() },
+            // Punctuation in the LEI
+            InstrumentMetadata { issuer_lei: Some(*b"5493001KJTIIGC8Y1R-2"), ..bond_metadata() },
+            // Numeric currency code
+            InstrumentMetadata { currency: Some(*b"398"), ..bond_metadata() },
+        ];
+        for metadata in malformed {
+            assert_noop!(
+                set(RuntimeOrigin::signed(1), ASSET, metadata),
+                Error::<Test>::InvalidMetadata
+            );
+        }
+    });
+}
+
+// ============================================================================
+// Document Registry Tests
+// ============================================================================
+
+/// Tests that set_document records and replaces documents, stamping the block.
+#[test]
+fn set_document_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::set_document(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            bounded(b"offering-circular"),
+            bounded(b"ipfs://circular-v1"),
+            [1; 32],
+        ));
+        System::assert_last_event(
+            Event::DocumentUpdated {
+                asset_id: ASSET,
+                name: bounded(b"offering-circular"),
+                uri: bounded(b"ipfs://circular-v1"),
+                content_hash: [1; 32],
+            }
+            .into(),
+        );
+
+        System::set_block_number(5);
+        let name: DocumentNameOf = bounded(b"offering-circular");
+        assert_ok!(CladToken::set_document(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            name.clone(),
+            bounded(b"ipfs://circular-v2"),
+            [2; 32],
+        ));
+        assert_eq!(
+            CladToken::document(ASSET, name),
+            Some(Document {
+                uri: bounded(b"ipfs://circular-v2"),
+                content_hash: [2; 32],
+                last_modified: 5
+            })
+        );
+
+        assert_noop!(
+            CladToken::set_document(
+                RuntimeOrigin::signed(2),
+                ASSET,
+                bounded(b"fake"),
+                bounded(b"ipfs://fake"),
+                [0; 32]
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CladToken::set_document(
+                RuntimeOrigin::signed(1),
+                7,
+                bounded(b"offering-circular"),
+                bounded(b"ipfs://circular-v1"),
+                [1; 32]
+            ),
+            Error::<Test>::UnknownInstrument
+        );
+    });
+}
+
+/// Tests that documents are listed per instrument and can be withdrawn.
+#[test]
+fn remove_document_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let other = create_second_instrument();
+        let documents: [(AssetId, &[u8]); 3] =
+            [(ASSET, b"circular"), (ASSET, b"amendment-1"), (other, b"circular")];
+        for (asset_id, name) in documents {
+            assert_ok!(CladToken::set_document(
+                RuntimeOrigin::signed(1),
+                asset_id,
+                bounded(name),
+                bounded(b"ipfs://doc"),
+                [3; 32],
+            ));
+        }
+        assert_eq!(CladToken::documents(ASSET).len(), 2);
+        assert_eq!(CladToken::documents(other).len(), 1);
+
+        assert_ok!(CladToken::remove_document(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            bounded(b"circular")
+        ));
+        System::assert_last_event(
+            Event::DocumentRemoved {
+                asset_id: ASSET,
+                name: bounded(b"circular"),
+                uri: bounded(b"ipfs://doc"),
+                content_hash: [3; 32],
+            }
+            .into(),
+        );
+        let names: Vec<Vec<u8>> =
+            CladToken::documents(ASSET).into_iter().map(|(name, _)| name.into_inner()).collect();
+        assert_eq!(names, vec![b"amendment-1".to_vec()]);
+        assert_eq!(CladToken::documents(other).len(), 1);
+
+        assert_noop!(
+            CladToken::remove_document(RuntimeOrigin::signed(1), ASSET, bounded(b"circular")),
+            Error::<Test>::UnknownDocument
+        );
+        assert_noop!(
+            CladToken::remove_document(RuntimeOrigin::signed(2), ASSET, bounded(b"amendment-1")),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+// ============================================================================
+// Batch Tests
+// ============================================================================
+
+/// Tests that batch_mint mints small amounts at once and time-locks large ones.
+#[test]
+fn batch_mint_works() {
+    new_test_ext().execute_with(|| {
+        setup_time_lock();
+        let mints = vec![(4, 100), (5, 200), (6, 1_000)].try_into().unwrap();
+        assert_ok!(CladToken::batch_mint(RuntimeOrigin::signed(1), ASSET, mints));
+
+        assert_eq!(CladToken::balance_of(ASSET, &4), 100);
+        assert_eq!(CladToken::balance_of(ASSET, &5), 200);
+        assert_eq!(CladToken::balance_of(ASSET, &6), 0);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_300);
+        System::assert_has_event(Event::Minted { asset_id: ASSET, to: 4, amount: 100 }.into());
+        System::assert_last_event(
+            Event::MintScheduled { id: 0, asset_id: ASSET, to: 6, amount: 1_000, execute_at: 11 }
+                .into(),
+        );
+
+        // Minters may batch too, other accounts may not
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(1), Role::RoleAdmin, 30));
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(30), Role::Minter, 20));
+        let mints = vec![(4, 1)].try_into().unwrap();
+        assert_ok!(CladToken::batch_mint(RuntimeOrigin::signed(20), ASSET, mints));
+        assert_eq!(CladToken::balance_of(ASSET, &4), 101);
+        let mints = vec![(4, 1)].try_into().unwrap();
+        assert_noop!(
+            CladToken::batch_mint(RuntimeOrigin::signed(2), ASSET, mints),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+/// Tests that a failing entry rolls back the whole batch.
+#[test]
+fn batch_mint_is_atomic() {
+    new_test_ext().execute_with(|| {
+        // Account 4 becomes the third holder, account 5 would be a fourth
+        assert_ok!(CladToken::set_max_holders(RuntimeOrigin::signed(1), ASSET, Some(3)));
+        let mints = vec![(4, 100), (5, 100)].try_into().unwrap();
+        assert_noop!(
+            CladToken::batch_mint(RuntimeOrigin::signed(1), ASSET, mints),
+            Error::<Test>::TooManyHolders
+        );
+        assert_eq!(CladToken::balance_of(ASSET, &4), 0);
+        assert_eq!(CladToken::total_supply(ASSET), 1_500_000);
+    });
+}
+
+/// Tests that batch_whitelist whitelists every investor with its country.
+#[test]
+fn batch_whitelist_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let investors = vec![(4, Some(KZ)), (5, None)].try_into().unwrap();
+        assert_ok!(CladToken::batch_whitelist(
+            RuntimeOrigin::signed(1),
+            ASSET,
+            investors,
+            Some(100)
+        ));
+
+        assert_eq!(CladToken::is_whitelisted(ASSET, &4), true);
+        assert_eq!(CladToken::is_whitelisted(ASSET, &5), true);
+        assert_eq!(CladToken::investor_country(ASSET, 4), Some(KZ));
+        assert_eq!(CladToken::investor_country(ASSET, 5), None);
+        System::assert_last_event(
+            Event::Whitelisted { asset_id: ASSET, account: 5, expires_at: Some(100) }.into(),
+        );
+
+        let investors = vec![(6, None)].try_into().unwrap();
+        assert_noop!(
+            CladToken::batch_whitelist(RuntimeOrigin::signed(1), ASSET, investors, Some(1)),
+            Error::<Test>::InvalidWhitelistExpiry
+        );
+        let investors = vec![(6, None)].try_into().unwrap();
+        assert_noop!(
+            CladToken::batch_whitelist(RuntimeOrigin::signed(1), 7, investors, None),
+            Error::<Test>::UnknownInstrument
+        );
+        let investors = vec![(6, None)].try_into().unwrap();
+        assert_noop!(
+            CladToken::batch_whitelist(RuntimeOrigin::signed(2), ASSET, investors, None),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+/// Tests that batch_freeze freezes every account.
+#[test]
+fn batch_freeze_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(1), Role::RoleAdmin, 30));
+        assert_ok!(CladToken::grant_role(RuntimeOrigin::signed(30), Role::Freezer, 21));
+        let accounts = vec![2, 3].try_into().unwrap();
+        assert_ok!(CladToken::batch_freeze(RuntimeOrigin::signed(21), ASSET, accounts));
+
+        assert_eq!(CladToken::is_frozen(ASSET, &2), true);
+        assert_eq!(CladToken::is_frozen(ASSET, &3), true);
+        System::assert_has_event(Event::Frozen { asset_id: ASSET, account: 2 }.into());
+        System::assert_last_event(Event::Frozen { asset_id: ASSET, account: 3 }.into());
+
+        let accounts = vec![2].try_into().unwrap();
+        assert_noop!(
+            CladToken::batch_freeze(RuntimeOrigin::signed(2), ASSET, accounts),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+// ============================================================================
+// Payment Reference Tests
+// ============================================================================
+
+/// Tests that transfer_with_reference moves tokens and carries the reference.
+#[test]
+fn transfer_with_reference_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CladToken::transfer_with_reference(
+            RuntimeOrigin::signed(2),
+            ASSET,
+            3,
+            100_000,
+            bounded(b"E2E-20250114-000123"),
+        ));
+
+        assert_eq!(CladToken::balance_of(ASSET, &2), 900_000);
+        assert_eq!(CladToken::balance_of(ASSET, &3), 600_000);
+        System::assert_last_event(
+            Event::Transferred {
+                asset_id: ASSET,
+                from: 2,
+                to: 3,
+                amount: 100_000,
+                reference: Some(bounded(b"E2E-20250114-000123")),
+            }
+            .into(),
+        );
+    });
+}
+
+/// Tests that transfer_with_reference applies the same checks as transfer.
+#[test]
+fn transfer_with_reference_respects_compliance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(1), ASSET, 2));
+        assert_noop!(
+            CladToken::transfer_with_reference(
+                RuntimeOrigin::signed(2),
+                ASSET,
+                3,
+                100,
+                bounded(b"E2E-1")
+            ),
+            Error::<Test>::AccountFrozen
+        );
+        assert_noop!(
+            CladToken::transfer_with_reference(
+                RuntimeOrigin::signed(3),
+                ASSET,
+                5,
+                100,
+                bounded(b"E2E-2")
+            ),
+            Error::<Test>::NotWhitelisted
+        );
+        // References longer than an ISO 20022 end-to-end id do not fit
+        assert!(crate::TransferReferenceOf::try_from(vec![b'r'; 36]).is_err());
+    });
+}
@@ -40,176 +40,2740 @@ pub trait WeightInfo {
 	fn unfreeze() -> Weight;
 	fn add_to_whitelist() -> Weight;
 	fn remove_from_whitelist() -> Weight;
-	fn set_admin() -> Weight;
+	fn set_admin(n: u32, ) -> Weight;
+	fn create_instrument() -> Weight;
+	fn burn() -> Weight;
+	fn redeem() -> Weight;
+	fn forced_transfer() -> Weight;
+	fn freeze_partial() -> Weight;
+	fn unfreeze_partial() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn add_trusted_issuer() -> Weight;
+	fn remove_trusted_issuer() -> Weight;
+	fn add_claim() -> Weight;
+	fn remove_claim() -> Weight;
+	fn set_required_claim_topics() -> Weight;
+	fn sweep_whitelist(n: u32, ) -> Weight;
+	fn set_allowed_countries() -> Weight;
+	fn set_blocked_countries() -> Weight;
+	fn set_max_holders() -> Weight;
+	fn set_max_balance_per_holder() -> Weight;
+	fn set_account_holding_limit() -> Weight;
+	fn mint_with_schedule() -> Weight;
+	fn set_bond_terms() -> Weight;
+	fn distribute_coupon(n: u32, ) -> Weight;
+	fn redeem_at_maturity(n: u32, ) -> Weight;
+	fn snapshot() -> Weight;
+	fn declare_dividend() -> Weight;
+	fn claim_dividend() -> Weight;
+	fn distribute_dividend(n: u32, ) -> Weight;
+	fn reclaim_dividend() -> Weight;
+	fn split(n: u32, ) -> Weight;
+	fn continue_split(n: u32, ) -> Weight;
+	fn recover_account() -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn schedule_mint() -> Weight;
+	fn cancel_mint() -> Weight;
+	fn execute_pending_mints(n: u32, ) -> Weight;
+	fn raise_max_supply() -> Weight;
+	fn set_metadata() -> Weight;
+	fn set_document() -> Weight;
+	fn remove_document() -> Weight;
+	fn batch_mint(n: u32, ) -> Weight;
+	fn batch_whitelist(n: u32, ) -> Weight;
+	fn batch_freeze(n: u32, ) -> Weight;
+	fn transfer_with_reference() -> Weight;
+	fn claim_matured_principal() -> Weight;
+	fn claim_coupon() -> Weight;
 }
 
 /// Weights for `pallet_clad_token` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecentMints` (r:1 w:1)
+	/// Proof: `CladToken::RecentMints` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
-	/// Proof: `CladToken::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:1 w:0)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Balances` (r:1 w:1)
-	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:1 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:1 w:1)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `3529`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(7_000_000, 3529)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Measured:  `247`
+		//  Estimated: `13744`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 13744)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:2 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Whitelist` (r:2 w:0)
-	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:16 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Frozen` (r:1 w:0)
-	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:2 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::BlockedCountries` (r:1 w:0)
+	/// Proof: `CladToken::BlockedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AllowedCountries` (r:1 w:0)
+	/// Proof: `CladToken::AllowedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Balances` (r:2 w:2)
-	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:2 w:2)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:2 w:2)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `203`
-		//  Estimated: `6068`
-		// Minimum execution time: 17_000_000 picoseconds.
-		Weight::from_parts(18_000_000, 6068)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Measured:  `2865`
+		//  Estimated: `58613`
+		// Minimum execution time: 89_000_000 picoseconds.
+		Weight::from_parts(92_000_000, 58613)
+			.saturating_add(T::DbWeight::get().reads(45_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Frozen` (r:0 w:1)
-	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	fn freeze() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_000_000 picoseconds.
-		Weight::from_parts(5_000_000, 0)
+		//  Estimated: `3531`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 3531)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Frozen` (r:0 w:1)
-	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	fn unfreeze() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_000_000 picoseconds.
-		Weight::from_parts(4_000_000, 0)
+		//  Estimated: `3531`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 3531)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Whitelist` (r:0 w:1)
-	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:1 w:1)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:1 w:0)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:2 w:2)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	fn add_to_whitelist() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_000_000 picoseconds.
-		Weight::from_parts(5_000_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `178`
+		//  Estimated: `6124`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 6124)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Whitelist` (r:0 w:1)
-	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	fn remove_from_whitelist() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_000_000 picoseconds.
-		Weight::from_parts(5_000_000, 0)
+		//  Estimated: `3531`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 3531)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `CladToken::Admin` (r:1 w:1)
 	/// Proof: `CladToken::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:0 w:256)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn set_admin(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `3556`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3556)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(1_512_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:1)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Admin` (r:1 w:0)
+	/// Proof: `CladToken::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TokenName` (r:0 w:1)
+	/// Proof: `CladToken::TokenName` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TokenSymbol` (r:0 w:1)
+	/// Proof: `CladToken::TokenSymbol` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Decimals` (r:0 w:1)
+	/// Proof: `CladToken::Decimals` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:0 w:1)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Whitelist` (r:0 w:1)
-	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn set_admin() -> Weight {
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn create_instrument() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `1517`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:1 w:0)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:8 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:1 w:1)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:1 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:1 w:1)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn burn() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1054`
+		//  Estimated: `35721`
+		// Minimum execution time: 49_000_000 picoseconds.
+		Weight::from_parts(51_000_000, 35721)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:1 w:0)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:8 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:1 w:1)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:1 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:1 w:1)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1054`
+		//  Estimated: `35721`
+		// Minimum execution time: 50_000_000 picoseconds.
+		Weight::from_parts(52_000_000, 35721)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:1 w:0)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:8 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:2 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::BlockedCountries` (r:1 w:0)
+	/// Proof: `CladToken::BlockedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AllowedCountries` (r:1 w:0)
+	/// Proof: `CladToken::AllowedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:2 w:2)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:2 w:2)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:1)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:2 w:2)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn forced_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1987`
+		//  Estimated: `47116`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(64_000_000, 47116)
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:1 w:0)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:1)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn freeze_partial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3549`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:1)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn unfreeze_partial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3549`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:0 w:1)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:0 w:1)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn unpause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::TrustedIssuers` (r:0 w:1)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn add_trusted_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:1)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn remove_trusted_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `3546`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 3546)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:0 w:1)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn add_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `3546`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 3546)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Admin` (r:1 w:0)
+	/// Proof: `CladToken::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:1 w:1)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn remove_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `136`
+		//  Estimated: `3569`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:0 w:1)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn set_required_claim_topics() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::WhitelistSweepCursor` (r:1 w:1)
+	/// Proof: `CladToken::WhitelistSweepCursor` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:128 w:128)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 128]`.
+	fn sweep_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `35 + n * (68 ±0)`
+		//  Estimated: `1521 + n * (2548 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(4_000_000, 1521)
+			// Standard Error: 3_871
+			.saturating_add(Weight::from_parts(8_412_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2548).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AllowedCountries` (r:0 w:1)
+	/// Proof: `CladToken::AllowedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	fn set_allowed_countries() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::BlockedCountries` (r:0 w:1)
+	/// Proof: `CladToken::BlockedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	fn set_blocked_countries() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:0 w:1)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_max_holders() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:0 w:1)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn set_max_balance_per_holder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
 		// Minimum execution time: 5_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1517)
+		Weight::from_parts(6_000_000, 1489)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SplitInProgress` (r:1 w:0)
+	/// Proof: `CladToken::SplitInProgress` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:0 w:1)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn set_account_holding_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3558`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 3558)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecentMints` (r:1 w:1)
+	/// Proof: `CladToken::RecentMints` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:1)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:1 w:0)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:1 w:1)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:1 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:1 w:1)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn mint_with_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `506`
+		//  Estimated: `14942`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 14942)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextCoupon` (r:1 w:0)
+	/// Proof: `CladToken::NextCoupon` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Bonds` (r:0 w:1)
+	/// Proof: `CladToken::Bonds` (`max_values`: None, `max_size`: Some(302), added: 2777, mode: `MaxEncodedLen`)
+	fn set_bond_terms() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3538`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 3538)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Bonds` (r:1 w:0)
+	/// Proof: `CladToken::Bonds` (`max_values`: None, `max_size`: Some(302), added: 2777, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:1)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextCoupon` (r:1 w:1)
+	/// Proof: `CladToken::NextCoupon` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:257 w:257)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:257 w:0)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn distribute_coupon(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + n * (131 ±0)`
+		//  Estimated: `4277 + n * (2603 ±0)`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 4277)
+			// Standard Error: 18_342
+			.saturating_add(Weight::from_parts(41_268_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::Admin` (r:1 w:0)
+	/// Proof: `CladToken::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:1)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Bonds` (r:1 w:0)
+	/// Proof: `CladToken::Bonds` (`max_values`: None, `max_size`: Some(302), added: 2777, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextCoupon` (r:1 w:0)
+	/// Proof: `CladToken::NextCoupon` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:257 w:257)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RedemptionCursor` (r:1 w:1)
+	/// Proof: `CladToken::RedemptionCursor` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaturityEscrowTotal` (r:1 w:0)
+	/// Proof: `CladToken::MaturityEscrowTotal` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:257 w:256)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:256 w:256)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:256 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:256 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:256 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:256 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn redeem_at_maturity(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `518 + n * (213 ±0)`
+		//  Estimated: `4277 + n * (2768 ±0)`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(37_000_000, 4277)
+			// Standard Error: 23_114
+			.saturating_add(Weight::from_parts(61_402_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2768).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:1)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn snapshot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3489`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 3489)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextDividendId` (r:1 w:1)
+	/// Proof: `CladToken::NextDividendId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Dividends` (r:0 w:1)
+	/// Proof: `CladToken::Dividends` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	fn declare_dividend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `6196`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(50_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Dividends` (r:1 w:1)
+	/// Proof: `CladToken::Dividends` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::DividendClaims` (r:2 w:2)
+	/// Proof: `CladToken::DividendClaims` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:2 w:0)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredFrom` (r:2 w:0)
+	/// Proof: `CladToken::RecoveredFrom` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_dividend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `732`
+		//  Estimated: `7206`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 7206)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Dividends` (r:1 w:1)
+	/// Proof: `CladToken::Dividends` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:257 w:0)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::DividendClaims` (r:256 w:256)
+	/// Proof: `CladToken::DividendClaims` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:256 w:0)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:256 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:256 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:257 w:257)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn distribute_dividend(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498 + n * (236 ±0)`
+		//  Estimated: `4277 + n * (2603 ±0)`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 4277)
+			// Standard Error: 26_318
+			.saturating_add(Weight::from_parts(53_402_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Dividends` (r:1 w:1)
+	/// Proof: `CladToken::Dividends` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reclaim_dividend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `452`
+		//  Estimated: `6196`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(46_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Bonds` (r:1 w:0)
+	/// Proof: `CladToken::Bonds` (`max_values`: None, `max_size`: Some(302), added: 2777, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SplitInProgress` (r:1 w:1)
+	/// Proof: `CladToken::SplitInProgress` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMintCount` (r:1 w:0)
+	/// Proof: `CladToken::PendingMintCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:1 w:1)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:1)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecentMints` (r:1 w:1)
+	/// Proof: `CladToken::RecentMints` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:257 w:256)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:256 w:256)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:256 w:256)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:256 w:256)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:256 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:256 w:256)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn split(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402 + n * (376 ±0)`
+		//  Estimated: `4277 + n * (2768 ±0)`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 4277)
+			// Standard Error: 29_114
+			.saturating_add(Weight::from_parts(41_385_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2768).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SplitInProgress` (r:1 w:1)
+	/// Proof: `CladToken::SplitInProgress` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:0 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:257 w:256)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:256 w:256)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:256 w:256)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:256 w:256)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:256 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:256 w:256)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn continue_split(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `388 + n * (376 ±0)`
+		//  Estimated: `4277 + n * (2768 ±0)`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 4277)
+			// Standard Error: 28_731
+			.saturating_add(Weight::from_parts(41_172_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2768).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:2 w:1)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:2 w:2)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:2 w:2)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredFrom` (r:1 w:1)
+	/// Proof: `CladToken::RecoveredFrom` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SplitInProgress` (r:1 w:0)
+	/// Proof: `CladToken::SplitInProgress` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:8 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:2 w:2)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:2 w:2)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:2 w:2)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:1 w:2)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:2)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:2)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:2)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn recover_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1324`
+		//  Estimated: `29511`
+		// Minimum execution time: 81_000_000 picoseconds.
+		Weight::from_parts(84_000_000, 29511)
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:2 w:1)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	fn grant_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3531`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 3531)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:2 w:1)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `3531`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 3531)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecentMints` (r:1 w:0)
+	/// Proof: `CladToken::RecentMints` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SplitInProgress` (r:1 w:0)
+	/// Proof: `CladToken::SplitInProgress` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextPendingMintId` (r:1 w:1)
+	/// Proof: `CladToken::NextPendingMintId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MintQueue` (r:1 w:1)
+	/// Proof: `CladToken::MintQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMintCount` (r:1 w:1)
+	/// Proof: `CladToken::PendingMintCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMints` (r:0 w:1)
+	/// Proof: `CladToken::PendingMints` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	fn schedule_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3590`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3590)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CladToken::PendingMints` (r:1 w:1)
+	/// Proof: `CladToken::PendingMints` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MintQueue` (r:1 w:1)
+	/// Proof: `CladToken::MintQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMintCount` (r:1 w:1)
+	/// Proof: `CladToken::PendingMintCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn cancel_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `202`
+		//  Estimated: `3554`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3554)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CladToken::MintQueue` (r:1 w:1)
+	/// Proof: `CladToken::MintQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMints` (r:16 w:16)
+	/// Proof: `CladToken::PendingMints` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMintCount` (r:16 w:16)
+	/// Proof: `CladToken::PendingMintCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:16 w:16)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:32 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:16 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:16 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:16 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:16 w:16)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:16 w:0)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:16 w:16)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:16 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:16 w:16)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:16 w:16)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:16 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:16 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:16 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:16 w:16)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:16 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:16 w:16)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 16]`.
+	fn execute_pending_mints(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118 + n * (592 ±0)`
+		//  Estimated: `3554 + n * (14942 ±0)`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 3554)
+			// Standard Error: 43_208
+			.saturating_add(Weight::from_parts(36_529_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((20_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 14942).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:1 w:1)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn raise_max_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3526`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3526)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TokenName` (r:0 w:1)
+	/// Proof: `CladToken::TokenName` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TokenSymbol` (r:0 w:1)
+	/// Proof: `CladToken::TokenSymbol` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Metadata` (r:0 w:1)
+	/// Proof: `CladToken::Metadata` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Documents` (r:0 w:1)
+	/// Proof: `CladToken::Documents` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	fn set_document() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Documents` (r:1 w:1)
+	/// Proof: `CladToken::Documents` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	fn remove_document() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `354`
+		//  Estimated: `4828`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 4828)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecentMints` (r:128 w:128)
+	/// Proof: `CladToken::RecentMints` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:128 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:1 w:0)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:128 w:128)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:128 w:128)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:128 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:128 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:128 w:128)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 128]`.
+	fn batch_mint(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247 + n * (104 ±0)`
+		//  Estimated: `3531 + n * (2579 ±0)`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3531)
+			// Standard Error: 20_114
+			.saturating_add(Weight::from_parts(16_987_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2579).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:128 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:0 w:128)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:128 w:128)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:128 w:0)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 128]`.
+	fn batch_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (96 ±0)`
+		//  Estimated: `3531 + n * (2575 ±0)`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 3531)
+			// Standard Error: 8_216
+			.saturating_add(Weight::from_parts(9_318_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2575).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:0 w:128)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 128]`.
+	fn batch_freeze(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3531`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 3531)
+			// Standard Error: 1_972
+			.saturating_add(Weight::from_parts(2_846_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:2 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:2 w:0)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:16 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:2 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::BlockedCountries` (r:1 w:0)
+	/// Proof: `CladToken::BlockedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AllowedCountries` (r:1 w:0)
+	/// Proof: `CladToken::AllowedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:2 w:2)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:2 w:2)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:2 w:2)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn transfer_with_reference() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2865`
+		//  Estimated: `58613`
+		// Minimum execution time: 90_000_000 picoseconds.
+		Weight::from_parts(93_000_000, 58613)
+			.saturating_add(T::DbWeight::get().reads(45_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaturityEscrow` (r:1 w:1)
+	/// Proof: `CladToken::MaturityEscrow` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:2 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaturityEscrowTotal` (r:1 w:1)
+	/// Proof: `CladToken::MaturityEscrowTotal` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn claim_matured_principal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342`
+		//  Estimated: `8714`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 8714)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::UnpaidCoupons` (r:1 w:1)
+	/// Proof: `CladToken::UnpaidCoupons` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:2 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::UnpaidCouponTotal` (r:1 w:1)
+	/// Proof: `CladToken::UnpaidCouponTotal` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn claim_coupon() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1290`
+		//  Estimated: `8775`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(49_000_000, 8775)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecentMints` (r:1 w:1)
+	/// Proof: `CladToken::RecentMints` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
-	/// Proof: `CladToken::TotalSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:1 w:0)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Balances` (r:1 w:1)
-	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:1 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:1 w:1)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `3529`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(7_000_000, 3529)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `247`
+		//  Estimated: `13744`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 13744)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:2 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Whitelist` (r:2 w:0)
-	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:16 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Frozen` (r:1 w:0)
-	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:2 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::BlockedCountries` (r:1 w:0)
+	/// Proof: `CladToken::BlockedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AllowedCountries` (r:1 w:0)
+	/// Proof: `CladToken::AllowedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Balances` (r:2 w:2)
-	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:2 w:2)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:2 w:2)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `203`
-		//  Estimated: `6068`
-		// Minimum execution time: 17_000_000 picoseconds.
-		Weight::from_parts(18_000_000, 6068)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `2865`
+		//  Estimated: `58613`
+		// Minimum execution time: 89_000_000 picoseconds.
+		Weight::from_parts(92_000_000, 58613)
+			.saturating_add(RocksDbWeight::get().reads(45_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Frozen` (r:0 w:1)
-	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	fn freeze() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_000_000 picoseconds.
-		Weight::from_parts(5_000_000, 0)
+		//  Estimated: `3531`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 3531)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Frozen` (r:0 w:1)
-	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	fn unfreeze() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_000_000 picoseconds.
-		Weight::from_parts(4_000_000, 0)
+		//  Estimated: `3531`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 3531)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Whitelist` (r:0 w:1)
-	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:1 w:1)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:1 w:0)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:2 w:2)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	fn add_to_whitelist() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_000_000 picoseconds.
-		Weight::from_parts(5_000_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `178`
+		//  Estimated: `6124`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 6124)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Whitelist` (r:0 w:1)
-	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	fn remove_from_whitelist() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_000_000 picoseconds.
-		Weight::from_parts(5_000_000, 0)
+		//  Estimated: `3531`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 3531)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `CladToken::Admin` (r:1 w:1)
 	/// Proof: `CladToken::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:0 w:256)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn set_admin(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `3556`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3556)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(1_512_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:1)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Admin` (r:1 w:0)
+	/// Proof: `CladToken::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TokenName` (r:0 w:1)
+	/// Proof: `CladToken::TokenName` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TokenSymbol` (r:0 w:1)
+	/// Proof: `CladToken::TokenSymbol` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Decimals` (r:0 w:1)
+	/// Proof: `CladToken::Decimals` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:0 w:1)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `CladToken::Whitelist` (r:0 w:1)
-	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn set_admin() -> Weight {
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn create_instrument() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `1517`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:1 w:0)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:8 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:1 w:1)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:1 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:1 w:1)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn burn() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1054`
+		//  Estimated: `35721`
+		// Minimum execution time: 49_000_000 picoseconds.
+		Weight::from_parts(51_000_000, 35721)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:1 w:0)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:8 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:1 w:1)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:1 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:1 w:1)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1054`
+		//  Estimated: `35721`
+		// Minimum execution time: 50_000_000 picoseconds.
+		Weight::from_parts(52_000_000, 35721)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:1 w:0)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:8 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:2 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::BlockedCountries` (r:1 w:0)
+	/// Proof: `CladToken::BlockedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AllowedCountries` (r:1 w:0)
+	/// Proof: `CladToken::AllowedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:2 w:2)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:2 w:2)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:1)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:2 w:2)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn forced_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1987`
+		//  Estimated: `47116`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(64_000_000, 47116)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:1 w:0)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:1)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn freeze_partial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3549`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:1)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn unfreeze_partial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3549`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:0 w:1)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:0 w:1)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn unpause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::TrustedIssuers` (r:0 w:1)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn add_trusted_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:1)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn remove_trusted_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `3546`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 3546)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:0 w:1)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn add_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `3546`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 3546)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Admin` (r:1 w:0)
+	/// Proof: `CladToken::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:1 w:1)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn remove_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `136`
+		//  Estimated: `3569`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:0 w:1)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn set_required_claim_topics() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::WhitelistSweepCursor` (r:1 w:1)
+	/// Proof: `CladToken::WhitelistSweepCursor` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:128 w:128)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 128]`.
+	fn sweep_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `35 + n * (68 ±0)`
+		//  Estimated: `1521 + n * (2548 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(4_000_000, 1521)
+			// Standard Error: 3_871
+			.saturating_add(Weight::from_parts(8_412_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2548).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AllowedCountries` (r:0 w:1)
+	/// Proof: `CladToken::AllowedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	fn set_allowed_countries() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::BlockedCountries` (r:0 w:1)
+	/// Proof: `CladToken::BlockedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	fn set_blocked_countries() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:0 w:1)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_max_holders() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:0 w:1)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn set_max_balance_per_holder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
 		// Minimum execution time: 5_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1517)
+		Weight::from_parts(6_000_000, 1489)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SplitInProgress` (r:1 w:0)
+	/// Proof: `CladToken::SplitInProgress` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:0 w:1)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn set_account_holding_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3558`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 3558)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecentMints` (r:1 w:1)
+	/// Proof: `CladToken::RecentMints` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:1)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:1 w:0)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:1 w:1)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:1 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:1 w:1)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn mint_with_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `506`
+		//  Estimated: `14942`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 14942)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextCoupon` (r:1 w:0)
+	/// Proof: `CladToken::NextCoupon` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Bonds` (r:0 w:1)
+	/// Proof: `CladToken::Bonds` (`max_values`: None, `max_size`: Some(302), added: 2777, mode: `MaxEncodedLen`)
+	fn set_bond_terms() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3538`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 3538)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Bonds` (r:1 w:0)
+	/// Proof: `CladToken::Bonds` (`max_values`: None, `max_size`: Some(302), added: 2777, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:1)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextCoupon` (r:1 w:1)
+	/// Proof: `CladToken::NextCoupon` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:257 w:257)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:257 w:0)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn distribute_coupon(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + n * (131 ±0)`
+		//  Estimated: `4277 + n * (2603 ±0)`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 4277)
+			// Standard Error: 18_342
+			.saturating_add(Weight::from_parts(41_268_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::Admin` (r:1 w:0)
+	/// Proof: `CladToken::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:1)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Bonds` (r:1 w:0)
+	/// Proof: `CladToken::Bonds` (`max_values`: None, `max_size`: Some(302), added: 2777, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextCoupon` (r:1 w:0)
+	/// Proof: `CladToken::NextCoupon` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:257 w:257)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RedemptionCursor` (r:1 w:1)
+	/// Proof: `CladToken::RedemptionCursor` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaturityEscrowTotal` (r:1 w:0)
+	/// Proof: `CladToken::MaturityEscrowTotal` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:257 w:256)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:256 w:256)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:256 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:256 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:256 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:256 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn redeem_at_maturity(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `518 + n * (213 ±0)`
+		//  Estimated: `4277 + n * (2768 ±0)`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(37_000_000, 4277)
+			// Standard Error: 23_114
+			.saturating_add(Weight::from_parts(61_402_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2768).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:1)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn snapshot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3489`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 3489)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextDividendId` (r:1 w:1)
+	/// Proof: `CladToken::NextDividendId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Dividends` (r:0 w:1)
+	/// Proof: `CladToken::Dividends` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	fn declare_dividend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `6196`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(50_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Dividends` (r:1 w:1)
+	/// Proof: `CladToken::Dividends` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::DividendClaims` (r:2 w:2)
+	/// Proof: `CladToken::DividendClaims` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:2 w:0)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredFrom` (r:2 w:0)
+	/// Proof: `CladToken::RecoveredFrom` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_dividend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `732`
+		//  Estimated: `7206`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 7206)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Dividends` (r:1 w:1)
+	/// Proof: `CladToken::Dividends` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:257 w:0)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::DividendClaims` (r:256 w:256)
+	/// Proof: `CladToken::DividendClaims` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:256 w:0)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:256 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:256 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:257 w:257)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn distribute_dividend(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498 + n * (236 ±0)`
+		//  Estimated: `4277 + n * (2603 ±0)`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 4277)
+			// Standard Error: 26_318
+			.saturating_add(Weight::from_parts(53_402_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Dividends` (r:1 w:1)
+	/// Proof: `CladToken::Dividends` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reclaim_dividend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `452`
+		//  Estimated: `6196`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(46_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Bonds` (r:1 w:0)
+	/// Proof: `CladToken::Bonds` (`max_values`: None, `max_size`: Some(302), added: 2777, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SplitInProgress` (r:1 w:1)
+	/// Proof: `CladToken::SplitInProgress` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMintCount` (r:1 w:0)
+	/// Proof: `CladToken::PendingMintCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:1 w:1)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:1)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecentMints` (r:1 w:1)
+	/// Proof: `CladToken::RecentMints` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:257 w:256)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:256 w:256)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:256 w:256)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:256 w:256)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:256 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:256 w:256)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn split(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402 + n * (376 ±0)`
+		//  Estimated: `4277 + n * (2768 ±0)`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 4277)
+			// Standard Error: 29_114
+			.saturating_add(Weight::from_parts(41_385_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2768).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SplitInProgress` (r:1 w:1)
+	/// Proof: `CladToken::SplitInProgress` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:0 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:257 w:256)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:256 w:256)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:256 w:256)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:256 w:256)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:256 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:256 w:256)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 256]`.
+	fn continue_split(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `388 + n * (376 ±0)`
+		//  Estimated: `4277 + n * (2768 ±0)`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 4277)
+			// Standard Error: 28_731
+			.saturating_add(Weight::from_parts(41_172_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2768).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:2 w:1)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:2 w:2)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:2 w:2)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredFrom` (r:1 w:1)
+	/// Proof: `CladToken::RecoveredFrom` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SplitInProgress` (r:1 w:0)
+	/// Proof: `CladToken::SplitInProgress` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:8 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:2 w:2)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:2 w:2)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:2 w:2)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:1 w:2)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:2)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:2)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:2)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn recover_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1324`
+		//  Estimated: `29511`
+		// Minimum execution time: 81_000_000 picoseconds.
+		Weight::from_parts(84_000_000, 29511)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(23_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:2 w:1)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	fn grant_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3531`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 3531)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:2 w:1)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102`
+		//  Estimated: `3531`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 3531)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecentMints` (r:1 w:0)
+	/// Proof: `CladToken::RecentMints` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:1 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SplitInProgress` (r:1 w:0)
+	/// Proof: `CladToken::SplitInProgress` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextPendingMintId` (r:1 w:1)
+	/// Proof: `CladToken::NextPendingMintId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MintQueue` (r:1 w:1)
+	/// Proof: `CladToken::MintQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMintCount` (r:1 w:1)
+	/// Proof: `CladToken::PendingMintCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMints` (r:0 w:1)
+	/// Proof: `CladToken::PendingMints` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	fn schedule_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3590`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3590)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CladToken::PendingMints` (r:1 w:1)
+	/// Proof: `CladToken::PendingMints` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MintQueue` (r:1 w:1)
+	/// Proof: `CladToken::MintQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMintCount` (r:1 w:1)
+	/// Proof: `CladToken::PendingMintCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn cancel_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `202`
+		//  Estimated: `3554`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3554)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CladToken::MintQueue` (r:1 w:1)
+	/// Proof: `CladToken::MintQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMints` (r:16 w:16)
+	/// Proof: `CladToken::PendingMints` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::PendingMintCount` (r:16 w:16)
+	/// Proof: `CladToken::PendingMintCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:16 w:16)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:32 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:16 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:16 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:16 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:16 w:16)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:16 w:0)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:16 w:16)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:16 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:16 w:16)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:16 w:16)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:16 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:16 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:16 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:16 w:16)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:16 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:16 w:16)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 16]`.
+	fn execute_pending_mints(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118 + n * (592 ±0)`
+		//  Estimated: `3554 + n * (14942 ±0)`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 3554)
+			// Standard Error: 43_208
+			.saturating_add(Weight::from_parts(36_529_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((20_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((9_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 14942).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:1 w:1)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn raise_max_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3526`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3526)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TokenName` (r:0 w:1)
+	/// Proof: `CladToken::TokenName` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TokenSymbol` (r:0 w:1)
+	/// Proof: `CladToken::TokenSymbol` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Metadata` (r:0 w:1)
+	/// Proof: `CladToken::Metadata` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Documents` (r:0 w:1)
+	/// Proof: `CladToken::Documents` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	fn set_document() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Documents` (r:1 w:1)
+	/// Proof: `CladToken::Documents` (`max_values`: None, `max_size`: Some(363), added: 2838, mode: `MaxEncodedLen`)
+	fn remove_document() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `354`
+		//  Estimated: `4828`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 4828)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecentMints` (r:128 w:128)
+	/// Proof: `CladToken::RecentMints` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:128 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxSupply` (r:1 w:0)
+	/// Proof: `CladToken::MaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:128 w:128)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:128 w:128)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotTotalSupply` (r:1 w:1)
+	/// Proof: `CladToken::SnapshotTotalSupply` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:128 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:128 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:128 w:128)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 128]`.
+	fn batch_mint(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247 + n * (104 ±0)`
+		//  Estimated: `3531 + n * (2579 ±0)`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3531)
+			// Standard Error: 20_114
+			.saturating_add(Weight::from_parts(16_987_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2579).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:128 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:0 w:128)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:128 w:128)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:128 w:0)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 128]`.
+	fn batch_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (96 ±0)`
+		//  Estimated: `3531 + n * (2575 ±0)`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 3531)
+			// Standard Error: 8_216
+			.saturating_add(Weight::from_parts(9_318_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2575).saturating_mul(n.into()))
+	}
+	/// Storage: `CladToken::Roles` (r:1 w:0)
+	/// Proof: `CladToken::Roles` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:0 w:128)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 128]`.
+	fn batch_freeze(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3531`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 3531)
+			// Standard Error: 1_972
+			.saturating_add(Weight::from_parts(2_846_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:2 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Paused` (r:1 w:0)
+	/// Proof: `CladToken::Paused` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Matured` (r:1 w:0)
+	/// Proof: `CladToken::Matured` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CouponDistribution` (r:1 w:0)
+	/// Proof: `CladToken::CouponDistribution` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Whitelist` (r:2 w:0)
+	/// Proof: `CladToken::Whitelist` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RequiredClaimTopics` (r:1 w:0)
+	/// Proof: `CladToken::RequiredClaimTopics` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Claims` (r:16 w:0)
+	/// Proof: `CladToken::Claims` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TrustedIssuers` (r:1 w:0)
+	/// Proof: `CladToken::TrustedIssuers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::InvestorCountry` (r:2 w:0)
+	/// Proof: `CladToken::InvestorCountry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::BlockedCountries` (r:1 w:0)
+	/// Proof: `CladToken::BlockedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AllowedCountries` (r:1 w:0)
+	/// Proof: `CladToken::AllowedCountries` (`max_values`: None, `max_size`: Some(534), added: 3009, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Balances` (r:2 w:2)
+	/// Proof: `CladToken::Balances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::CurrentSnapshotId` (r:1 w:0)
+	/// Proof: `CladToken::CurrentSnapshotId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::SnapshotBalances` (r:2 w:2)
+	/// Proof: `CladToken::SnapshotBalances` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxHolders` (r:1 w:0)
+	/// Proof: `CladToken::MaxHolders` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::AccountHoldingLimit` (r:1 w:0)
+	/// Proof: `CladToken::AccountHoldingLimit` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaxBalancePerHolder` (r:1 w:0)
+	/// Proof: `CladToken::MaxBalancePerHolder` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::TotalSupply` (r:1 w:0)
+	/// Proof: `CladToken::TotalSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HolderCount` (r:1 w:1)
+	/// Proof: `CladToken::HolderCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::HoldersPerCountry` (r:2 w:2)
+	/// Proof: `CladToken::HoldersPerCountry` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn transfer_with_reference() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2865`
+		//  Estimated: `58613`
+		// Minimum execution time: 90_000_000 picoseconds.
+		Weight::from_parts(93_000_000, 58613)
+			.saturating_add(RocksDbWeight::get().reads(45_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaturityEscrow` (r:1 w:1)
+	/// Proof: `CladToken::MaturityEscrow` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Frozen` (r:1 w:0)
+	/// Proof: `CladToken::Frozen` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::FrozenAmount` (r:1 w:0)
+	/// Proof: `CladToken::FrozenAmount` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::Vesting` (r:1 w:0)
+	/// Proof: `CladToken::Vesting` (`max_values`: None, `max_size`: Some(293), added: 2768, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:2 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::MaturityEscrowTotal` (r:1 w:1)
+	/// Proof: `CladToken::MaturityEscrowTotal` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn claim_matured_principal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342`
+		//  Estimated: `8714`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 8714)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CladToken::NextAssetId` (r:1 w:0)
+	/// Proof: `CladToken::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::UnpaidCoupons` (r:1 w:1)
+	/// Proof: `CladToken::UnpaidCoupons` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::RecoveredAccounts` (r:2 w:0)
+	/// Proof: `CladToken::RecoveredAccounts` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CladToken::UnpaidCouponTotal` (r:1 w:1)
+	/// Proof: `CladToken::UnpaidCouponTotal` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn claim_coupon() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1290`
+		//  Estimated: `8775`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(49_000_000, 8775)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 }
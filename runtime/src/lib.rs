@@ -15,6 +15,7 @@ use frame_support::{
     construct_runtime, parameter_types,
     traits::{ConstU32, Everything, Get, OriginTrait},
     weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+    PalletId,
 };
 pub use pallet_clad_token;
 use sp_api::impl_runtime_apis;
@@ -69,10 +70,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: Cow::Borrowed("clad-runtime"),
     impl_name: Cow::Borrowed("clad-runtime"),
     authoring_version: 1,
-    spec_version: 1,
+    spec_version: 39,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 7,
     system_version: 1,
 };
 
@@ -160,8 +161,17 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    Migrations,
 >;
 
+/// Storage migrations applied on runtime upgrade, in order.
+type Migrations = (
+    pallet_clad_token::migrations::v2::MigrateToV2<Runtime>,
+    pallet_clad_token::migrations::v3::MigrateToV3<Runtime>,
+    pallet_clad_token::migrations::v4::MigrateToV4<Runtime>,
+    pallet_clad_token::migrations::v5::MigrateToV5<Runtime>,
+);
+
 // Configure FRAME pallets to include in runtime.
 
 impl frame_system::Config for Runtime {
@@ -392,8 +402,93 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureStorageAdmin {
 /// See ADR-004: docs/adr/004-production-runtime-configuration.md
 pub type CladTokenAdminOrigin = EnsureStorageAdmin;
 
+parameter_types! {
+    /// Maximum number of instruments (bond series, T-bills, equity classes) on this chain.
+    pub const MaxInstruments: u32 = 256;
+    /// A paused instrument also rejects new issuance until the incident is resolved.
+    pub const PauseBlocksMinting: bool = true;
+    /// Claim topics per instrument / per issuer (KYC, accreditation, residency, spare).
+    pub const MaxClaimTopics: u32 = 8;
+    /// Whitelist entries inspected per block when sweeping expired KYC approvals.
+    pub const MaxWhitelistSweep: u32 = 128;
+    /// Allowed / blocked jurisdictions per instrument (covers every ISO 3166-1 country).
+    pub const MaxCountries: u32 = 256;
+    /// Concurrent lock-ups per investor and instrument (employee grant, anchor allocation, top-ups).
+    pub const MaxVestingSchedules: u32 = 8;
+    /// Seed of the per-instrument coupon pot accounts.
+    pub const CladTokenPalletId: PalletId = PalletId(*b"cladtokn");
+    /// Coupon dates per instrument (30 years of semi-annual coupons, with room to spare).
+    pub const MaxCouponDates: u32 = 64;
+    /// Holders paid or rescaled per paged call (coupons, redemption, dividends, splits).
+    pub const MaxHoldersPerPage: u32 = 256;
+    /// Mints taking an instrument's issuance within a day to 10 million tokens (6 decimals)
+    /// or more are time-locked.
+    pub const LargeMintThreshold: u128 = 10_000_000_000_000;
+    /// A time-locked mint can be vetoed for a day before it executes.
+    pub const MintDelay: BlockNumber = DAYS;
+    /// Time-locked mints falling due in the same block.
+    pub const MaxPendingMints: u32 = 16;
+    /// Entries per batch mint, whitelist or freeze (one primary-market allocation).
+    pub const MaxBatchSize: u32 = 128;
+}
+
+// Account of the audit office reviewing large issuance.
+//
+// Dave's well-known development account stands in for it; a production chain
+// replaces it with the audit office's multi-sig account, outside the admin multi-sig.
+frame_support::ord_parameter_types! {
+    pub const AuditOffice: AccountId = AccountId::new([
+        // Dave's well-known account (5DAAnrj7VHTznn2AWBemMuyBwZWs6FNFjdyVXUeYum3PTXFy)
+        0x30, 0x67, 0x21, 0x21, 0x1d, 0x54, 0x04, 0xbd,
+        0x9d, 0xa8, 0x8e, 0x02, 0x04, 0x36, 0x0a, 0x1a,
+        0x9a, 0xb8, 0xb8, 0x7c, 0x66, 0xc1, 0xbc, 0x2f,
+        0xcd, 0xd3, 0x7f, 0x3c, 0x22, 0x22, 0xcc, 0x20,
+    ]);
+}
+
+/// Origin that can cancel a time-locked mint.
+///
+/// Signed by [`AuditOffice`], not by the admin.
+pub type CladTokenVetoOrigin = frame_system::EnsureSignedBy<AuditOffice, AccountId>;
+
+// Account of the body that authorises issuance (e.g. the budget committee).
+//
+// Ferdie's well-known development account stands in for it; a production chain
+// replaces it with the body's multi-sig account, outside the admin multi-sig.
+frame_support::ord_parameter_types! {
+    pub const IssuanceAuthority: AccountId = AccountId::new([
+        // Ferdie's well-known account (5CiPPseXPECbkjWCa6MnjNokrgYjMqmKndv2rSnekmSK2DjL)
+        0x1c, 0xbd, 0x2d, 0x43, 0x53, 0x0a, 0x44, 0x70,
+        0x5a, 0xd0, 0x88, 0xaf, 0x31, 0x3e, 0x18, 0xf8,
+        0x0b, 0x53, 0xef, 0x16, 0xb3, 0x61, 0x77, 0xcd,
+        0x4b, 0x77, 0xb8, 0x46, 0xf2, 0xa5, 0xf0, 0x7c,
+    ]);
+}
+
+/// Origin that can raise an instrument's authorised amount.
+///
+/// Signed by [`IssuanceAuthority`], not by the admin.
+pub type CladTokenSupplyCapOrigin = frame_system::EnsureSignedBy<IssuanceAuthority, AccountId>;
+
 impl pallet_clad_token::Config for Runtime {
     type AdminOrigin = CladTokenAdminOrigin;
+    type MaxInstruments = MaxInstruments;
+    type PauseBlocksMinting = PauseBlocksMinting;
+    type Compliance = ();
+    type MaxClaimTopics = MaxClaimTopics;
+    type MaxWhitelistSweep = MaxWhitelistSweep;
+    type MaxCountries = MaxCountries;
+    type MaxVestingSchedules = MaxVestingSchedules;
+    type Currency = Balances;
+    type PalletId = CladTokenPalletId;
+    type MaxCouponDates = MaxCouponDates;
+    type MaxHoldersPerPage = MaxHoldersPerPage;
+    type LargeMintThreshold = LargeMintThreshold;
+    type MintDelay = MintDelay;
+    type VetoOrigin = CladTokenVetoOrigin;
+    type MaxPendingMints = MaxPendingMints;
+    type SupplyCapOrigin = CladTokenSupplyCapOrigin;
+    type MaxBatchSize = MaxBatchSize;
     type WeightInfo = pallet_clad_token::weights::SubstrateWeight<Runtime>;
 }
 
@@ -492,6 +587,44 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_clad_token_runtime_api::CladTokenApi<Block, AccountId, BlockNumber> for Runtime {
+        fn holder_count(asset_id: pallet_clad_token::AssetId) -> u32 {
+            CladToken::holder_count(asset_id)
+        }
+
+        fn holders_per_country(
+            asset_id: pallet_clad_token::AssetId,
+            country: pallet_clad_token::CountryCode,
+        ) -> u32 {
+            CladToken::holders_per_country(asset_id, country)
+        }
+
+        fn vesting_balance(asset_id: pallet_clad_token::AssetId, who: AccountId) -> (u128, u128) {
+            CladToken::vesting_balance(asset_id, &who)
+        }
+
+        fn balance_at(
+            asset_id: pallet_clad_token::AssetId,
+            who: AccountId,
+            snapshot_id: pallet_clad_token::SnapshotId,
+        ) -> Option<u128> {
+            CladToken::balance_at(asset_id, &who, snapshot_id)
+        }
+
+        fn total_supply_at(
+            asset_id: pallet_clad_token::AssetId,
+            snapshot_id: pallet_clad_token::SnapshotId,
+        ) -> Option<u128> {
+            CladToken::total_supply_at(asset_id, snapshot_id)
+        }
+
+        fn documents(
+            asset_id: pallet_clad_token::AssetId,
+        ) -> Vec<(pallet_clad_token::DocumentNameOf, pallet_clad_token::Document<BlockNumber>)> {
+            CladToken::documents(asset_id)
+        }
+    }
+
     impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
         fn account_nonce(account: AccountId) -> Nonce {
             System::account_nonce(account)
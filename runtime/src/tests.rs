@@ -28,6 +28,9 @@ use sp_runtime::{traits::Hash, BuildStorage};
 /// Type alias for call hash used by pallet-multisig
 type CallHash = [u8; 32];
 
+/// The instrument configured at genesis
+const ASSET: pallet_clad_token::AssetId = pallet_clad_token::GENESIS_ASSET_ID;
+
 /// Standard test account balance (100 trillion units, enough for deposits and fees)
 const TEST_ACCOUNT_BALANCE: u128 = 100_000_000_000_000;
 
@@ -105,6 +108,7 @@ fn new_test_ext() -> sp_io::TestExternalities {
         decimals: 6,
         whitelisted_accounts: vec![],
         initial_balances: vec![],
+        max_supply: None,
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -145,6 +149,7 @@ fn new_test_ext_with_admin(admin: AccountId) -> sp_io::TestExternalities {
         decimals: 6,
         whitelisted_accounts: vec![],
         initial_balances: vec![],
+        max_supply: None,
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -252,8 +257,13 @@ fn multisig_proposal_reserves_deposit() {
         let initial_balance = Balances::free_balance(&alice);
 
         // Create a dummy call to test deposit
-        let call: RuntimeCall =
-            pallet_clad_token::Call::add_to_whitelist { account: alice.clone() }.into();
+        let call: RuntimeCall = pallet_clad_token::Call::add_to_whitelist {
+            asset_id: ASSET,
+            account: alice.clone(),
+            expires_at: None,
+            country: None,
+        }
+        .into();
 
         // Propose the multi-sig call (Alice is first signer)
         assert_ok!(Multisig::as_multi(
@@ -659,47 +669,66 @@ fn integration_full_ministry_workflow() {
 
         // Step 1: Whitelist treasury via multi-sig
         execute_2of3_multisig_call(
-            pallet_clad_token::Call::add_to_whitelist { account: treasury.clone() }.into(),
+            pallet_clad_token::Call::add_to_whitelist {
+                asset_id: ASSET,
+                account: treasury.clone(),
+                expires_at: None,
+                country: None,
+            }
+            .into(),
         );
-        assert!(CladToken::whitelist(&treasury));
+        assert!(CladToken::is_whitelisted(ASSET, &treasury));
 
         // Whitelist investor via multi-sig
         execute_2of3_multisig_call(
-            pallet_clad_token::Call::add_to_whitelist { account: investor.clone() }.into(),
+            pallet_clad_token::Call::add_to_whitelist {
+                asset_id: ASSET,
+                account: investor.clone(),
+                expires_at: None,
+                country: None,
+            }
+            .into(),
         );
-        assert!(CladToken::whitelist(&investor));
+        assert!(CladToken::is_whitelisted(ASSET, &investor));
 
         // Step 2: Mint bond tokens to treasury via multi-sig
         let bond_amount = 100_000_000_000_000u128; // $100M with 6 decimals
         execute_2of3_multisig_call(
-            pallet_clad_token::Call::mint { to: treasury.clone(), amount: bond_amount }.into(),
+            pallet_clad_token::Call::mint {
+                asset_id: ASSET,
+                to: treasury.clone(),
+                amount: bond_amount,
+            }
+            .into(),
         );
 
-        assert_eq!(CladToken::balance_of(&treasury), bond_amount);
-        assert_eq!(CladToken::total_supply(), bond_amount);
+        assert_eq!(CladToken::balance_of(ASSET, &treasury), bond_amount);
+        assert_eq!(CladToken::total_supply(ASSET), bond_amount);
 
         // Step 3: Treasury distributes to investor (regular transfer, not admin op)
         let investment_amount = 10_000_000_000_000u128; // $10M
         assert_ok!(CladToken::transfer(
             RuntimeOrigin::signed(treasury.clone()),
+            ASSET,
             investor.clone(),
             investment_amount,
         ));
 
-        assert_eq!(CladToken::balance_of(&treasury), bond_amount - investment_amount);
-        assert_eq!(CladToken::balance_of(&investor), investment_amount);
+        assert_eq!(CladToken::balance_of(ASSET, &treasury), bond_amount - investment_amount);
+        assert_eq!(CladToken::balance_of(ASSET, &investor), investment_amount);
 
         // Step 4: Compliance issue - freeze investor via multi-sig
         execute_2of3_multisig_call(
-            pallet_clad_token::Call::freeze { account: investor.clone() }.into(),
+            pallet_clad_token::Call::freeze { asset_id: ASSET, account: investor.clone() }.into(),
         );
 
-        assert!(CladToken::is_frozen(&investor));
+        assert!(CladToken::is_frozen(ASSET, &investor));
 
         // Investor cannot transfer while frozen
         assert_noop!(
             CladToken::transfer(
                 RuntimeOrigin::signed(investor.clone()),
+                ASSET,
                 treasury.clone(),
                 1_000_000,
             ),
@@ -708,18 +737,19 @@ fn integration_full_ministry_workflow() {
 
         // Step 5: Issue resolved - unfreeze via multi-sig
         execute_2of3_multisig_call(
-            pallet_clad_token::Call::unfreeze { account: investor.clone() }.into(),
+            pallet_clad_token::Call::unfreeze { asset_id: ASSET, account: investor.clone() }.into(),
         );
 
         // Investor can transfer again
         assert_ok!(CladToken::transfer(
             RuntimeOrigin::signed(investor.clone()),
+            ASSET,
             treasury.clone(),
             1_000_000,
         ));
 
         // Total supply unchanged throughout
-        assert_eq!(CladToken::total_supply(), bond_amount);
+        assert_eq!(CladToken::total_supply(ASSET), bond_amount);
     });
 }
 
@@ -743,25 +773,33 @@ fn storage_admin_can_perform_admin_operations() {
         // New admin can whitelist accounts directly
         assert_ok!(CladToken::add_to_whitelist(
             RuntimeOrigin::signed(new_admin.clone()),
+            ASSET,
             investor.clone(),
+            None,
+            None,
         ));
-        assert!(CladToken::whitelist(&investor));
+        assert!(CladToken::is_whitelisted(ASSET, &investor));
 
         // New admin can mint tokens directly
         assert_ok!(CladToken::mint(
             RuntimeOrigin::signed(new_admin.clone()),
+            ASSET,
             investor.clone(),
             1_000_000,
         ));
-        assert_eq!(CladToken::balance_of(&investor), 1_000_000);
+        assert_eq!(CladToken::balance_of(ASSET, &investor), 1_000_000);
 
         // New admin can freeze accounts directly
-        assert_ok!(CladToken::freeze(RuntimeOrigin::signed(new_admin.clone()), investor.clone(),));
-        assert!(CladToken::is_frozen(&investor));
+        assert_ok!(CladToken::freeze(
+            RuntimeOrigin::signed(new_admin.clone()),
+            ASSET,
+            investor.clone(),
+        ));
+        assert!(CladToken::is_frozen(ASSET, &investor));
 
         // New admin can unfreeze accounts directly
-        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(new_admin), investor.clone(),));
-        assert!(!CladToken::is_frozen(&investor));
+        assert_ok!(CladToken::unfreeze(RuntimeOrigin::signed(new_admin), ASSET, investor.clone(),));
+        assert!(!CladToken::is_frozen(ASSET, &investor));
     });
 }
 
@@ -786,9 +824,12 @@ fn admin_rotation_via_multisig_works() {
         // Step 2: New admin can perform operations directly
         assert_ok!(CladToken::add_to_whitelist(
             RuntimeOrigin::signed(new_admin.clone()),
+            ASSET,
             test_account.clone(),
+            None,
+            None,
         ));
-        assert!(CladToken::whitelist(&test_account));
+        assert!(CladToken::is_whitelisted(ASSET, &test_account));
 
         // Step 3: Old multi-sig can NO longer perform admin operations
         // (The multi-sig address is no longer the admin)
@@ -798,12 +839,12 @@ fn admin_rotation_via_multisig_works() {
         let old_multisig = derive_multisig_account(vec![alice, bob, charlie], 2);
 
         assert_noop!(
-            CladToken::mint(RuntimeOrigin::signed(old_multisig), test_account.clone(), 1000),
+            CladToken::mint(RuntimeOrigin::signed(old_multisig), ASSET, test_account.clone(), 1000),
             sp_runtime::DispatchError::BadOrigin
         );
 
         // New admin CAN mint
-        assert_ok!(CladToken::mint(RuntimeOrigin::signed(new_admin), test_account, 1000,));
+        assert_ok!(CladToken::mint(RuntimeOrigin::signed(new_admin), ASSET, test_account, 1000,));
     });
 }
 
@@ -848,10 +889,10 @@ fn multisig_admin_rotation_to_new_multisig() {
         assert_eq!(CladToken::admin(), Some(new_multisig.clone()));
 
         // Step 4: New multi-sig should be auto-whitelisted
-        assert!(CladToken::whitelist(&new_multisig));
+        assert!(CladToken::is_whitelisted(ASSET, &new_multisig));
 
         // Step 5: Old multi-sig remains whitelisted (can hold tokens)
-        assert!(CladToken::whitelist(&old_multisig));
+        assert!(CladToken::is_whitelisted(ASSET, &old_multisig));
     });
 }
 
@@ -866,20 +907,33 @@ fn non_admin_cannot_perform_admin_operations() {
         assert_noop!(
             CladToken::add_to_whitelist(
                 RuntimeOrigin::signed(non_admin.clone()),
-                test_account.clone()
+                ASSET,
+                test_account.clone(),
+                None,
+                None
             ),
             sp_runtime::DispatchError::BadOrigin
         );
 
         // Non-admin cannot mint
         assert_noop!(
-            CladToken::mint(RuntimeOrigin::signed(non_admin.clone()), test_account.clone(), 1000),
+            CladToken::mint(
+                RuntimeOrigin::signed(non_admin.clone()),
+                ASSET,
+                test_account.clone(),
+                1000
+            ),
             sp_runtime::DispatchError::BadOrigin
         );
 
         // Non-admin cannot freeze
         assert_noop!(
-            CladToken::freeze(RuntimeOrigin::signed(non_admin.clone()), test_account.clone()),
+            CladToken::freeze(
+                RuntimeOrigin::signed(non_admin.clone()),
+                ASSET,
+                test_account.clone(),
+                None
+            ),
             sp_runtime::DispatchError::BadOrigin
         );
 
@@ -934,3 +988,50 @@ fn admin_changed_event_tracks_history() {
         );
     });
 }
+
+// ============================================================================
+// Oversight Tests
+// ============================================================================
+
+/// Tests that the admin cannot veto its own large mints; only the audit office can.
+#[test]
+fn only_audit_office_can_veto_large_mints() {
+    let admin = AccountKeyring::Alice.to_account_id();
+    let auditor = crate::AuditOffice::get();
+    assert_eq!(auditor, AccountKeyring::Dave.to_account_id());
+    let investor = AccountKeyring::Eve.to_account_id();
+
+    new_test_ext_with_admin(admin.clone()).execute_with(|| {
+        assert_ok!(CladToken::mint(
+            RuntimeOrigin::signed(admin.clone()),
+            ASSET,
+            investor,
+            LargeMintThreshold::get(),
+        ));
+        assert!(CladToken::pending_mint(0).is_some());
+
+        assert_noop!(
+            CladToken::cancel_mint(RuntimeOrigin::signed(admin), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(CladToken::cancel_mint(RuntimeOrigin::signed(auditor), 0));
+        assert!(CladToken::pending_mint(0).is_none());
+    });
+}
+
+/// Tests that the admin cannot raise supply caps; only the issuance authority can.
+#[test]
+fn only_issuance_authority_can_raise_max_supply() {
+    let admin = AccountKeyring::Alice.to_account_id();
+    let authority = crate::IssuanceAuthority::get();
+    assert_eq!(authority, AccountKeyring::Ferdie.to_account_id());
+
+    new_test_ext_with_admin(admin.clone()).execute_with(|| {
+        assert_noop!(
+            CladToken::raise_max_supply(RuntimeOrigin::signed(admin), ASSET, 1_000_000),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(CladToken::raise_max_supply(RuntimeOrigin::signed(authority), ASSET, 1_000_000));
+        assert_eq!(CladToken::max_supply(ASSET), Some(1_000_000));
+    });
+}